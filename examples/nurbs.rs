@@ -1,17 +1,15 @@
 //! Let's create a unit circle, as a unit circle is difficult for non-rational curves (bsplines)
 //! but are surprisingly easy to do with NURBS.
 //!
-//! We use the data from the [wikipedia article](https://en.wikipedia.org/wiki/Non-uniform_rational_B-spline#Example:_a_circle) of NURBS,
+//! We first use the data from the [wikipedia article](https://en.wikipedia.org/wiki/Non-uniform_rational_B-spline#Example:_a_circle) of NURBS,
 //! only the knot vector is scaled such that the domain is from 0.0 to 4.0 insteaf of 0.0 to 2π.
+//! Afterwards we let the library create the same circle for us.
 
 use core::f64::consts::PI;
 use core::ops::{Add, Div, Mul, Sub};
-use enterpolation::{bspline::BSpline, Curve, Generator};
+use enterpolation::{bspline::BSpline, Components, Curve, Generator};
 // used to test equality of f64s
-use assert_float_eq::{
-    afe_abs, afe_absolute_error_msg, afe_is_absolute_eq, afe_is_f64_near, afe_near_error_msg,
-    assert_f64_near, assert_float_absolute_eq,
-};
+use assert_float_eq::{assert_f64_near, assert_float_absolute_eq};
 
 /// We create our own 2D Point
 #[derive(Debug, Copy, Clone)]
@@ -85,6 +83,22 @@ impl Div<f64> for Point {
     }
 }
 
+/// To create circles in the plane, we need to be able to access the components of the point.
+impl Components<f64> for Point {
+    fn dimension(&self) -> usize {
+        2
+    }
+    fn component(&self, index: usize) -> f64 {
+        [self.x, self.y][index]
+    }
+    fn set_component(&mut self, index: usize, value: f64) {
+        match index {
+            0 => self.x = value,
+            _ => self.y = value,
+        }
+    }
+}
+
 fn main() {
     let weight = 2.0f64.sqrt() / 2.0;
     let points_with_weights = [
@@ -119,5 +133,23 @@ fn main() {
         assert_float_absolute_eq!(nurbs.gen(val).dist(circle_point), 0.0);
     }
     println!("Successful creation of unit circle with a NURBS!");
+    // the same circle is created exactly by the library, only its domain is from 0.0 to 1.0
+    let circle = BSpline::circle(Point::new(0.0, 0.0), 1.0);
+    for val in [0.0f64, 0.3, 1.0, 2.5, 3.7, 4.0].iter().copied() {
+        assert_float_absolute_eq!(circle.gen(val / 4.0).dist(nurbs.gen(val)), 0.0);
+    }
+    // other circles, ellipses and arcs are created as easily
+    let arc = BSpline::arc(
+        Point::new(1.0, 1.0),
+        Point::new(2.0, 0.0),
+        Point::new(0.0, 2.0),
+        0.0,
+        0.75 * PI,
+    );
+    for val in arc.take(32) {
+        // remember that dist returns the squared distance
+        assert_float_absolute_eq!(val.dist(Point::new(1.0, 1.0)), 4.0);
+    }
+    println!("Successful creation of a circular arc with radius 2.0!");
     // but we can approximate it by linearizing.
 }
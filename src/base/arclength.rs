@@ -0,0 +1,207 @@
+//! Arc-length parameterization of curves.
+
+use super::{Curve, DiscreteGenerator, Generator, Sorted, SortedGenerator};
+use crate::utils::lerp;
+use core::fmt::Debug;
+use num_traits::real::Real;
+use num_traits::FromPrimitive;
+
+/// Number of equidistant segments the domain is split into before subdividing adaptively.
+const INITIAL_SEGMENTS: usize = 16;
+/// Maximal depth of the adaptive subdivision of every initial segment.
+const MAX_DEPTH: usize = 24;
+
+/// Curve reparameterized by its arc length.
+///
+/// The domain of this curve is `[0.0, total_length]`, such that equal steps of the input
+/// result in equal distances travelled along the curve.
+///
+/// This struct is created by [`Curve::arc_length()`] and [`Curve::arc_length_with()`].
+/// See their documentation for more information.
+///
+/// [`Curve::arc_length()`]: crate::Curve::arc_length()
+/// [`Curve::arc_length_with()`]: crate::Curve::arc_length_with()
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ArcLength<C, R> {
+    curve: C,
+    parameters: Vec<R>,
+    lengths: Sorted<Vec<R>>,
+}
+
+impl<C, R> ArcLength<C, R>
+where
+    C: Curve<R>,
+    R: Real + FromPrimitive,
+{
+    /// Create an arc-length parameterization of the given curve using the given metric.
+    ///
+    /// The domain of the curve is subdivided adaptively until the length of each piece
+    /// is approximated by its chords with an error of at most `tolerance` in total
+    /// and the speed of the curve within each piece is uniform enough, such that any length
+    /// within the piece is mapped to a parameter with an error of at most `tolerance` in length.
+    pub fn new<F>(curve: C, tolerance: R, metric: F) -> Self
+    where
+        F: Fn(C::Output, C::Output) -> R,
+        C::Output: Copy,
+    {
+        let [start, end] = curve.domain();
+        let segments = R::from_usize(INITIAL_SEGMENTS).unwrap();
+        let budget = tolerance / segments;
+        let mut parameters = vec![start];
+        let mut lengths = vec![R::zero()];
+        let mut length = R::zero();
+        let mut last = (start, curve.gen(start));
+        // stack of the end points of the segments still to measure
+        let mut stack = Vec::with_capacity(INITIAL_SEGMENTS + MAX_DEPTH);
+        for i in (1..=INITIAL_SEGMENTS).rev() {
+            let parameter = lerp(start, end, R::from_usize(i).unwrap() / segments);
+            stack.push((parameter, curve.gen(parameter), budget, 0));
+        }
+        while let Some((parameter, point, budget, depth)) = stack.pop() {
+            let (last_parameter, last_point) = last;
+            let half = (last_parameter + parameter) / (R::one() + R::one());
+            let middle = curve.gen(half);
+            let chord = metric(last_point, point);
+            let first = metric(last_point, middle);
+            let second = metric(middle, point);
+            let exact = first + second - chord <= budget;
+            let uniform = (first - second).abs() <= tolerance;
+            if depth >= MAX_DEPTH || (exact && uniform) {
+                length = length + first;
+                parameters.push(half);
+                lengths.push(length);
+                length = length + second;
+                parameters.push(parameter);
+                lengths.push(length);
+                last = (parameter, point);
+            } else {
+                let budget = budget / (R::one() + R::one());
+                stack.push((parameter, point, budget, depth + 1));
+                stack.push((half, middle, budget, depth + 1));
+            }
+        }
+        ArcLength {
+            curve,
+            parameters,
+            lengths: Sorted::new_unchecked(lengths),
+        }
+    }
+
+    /// Returns the total length of the curve.
+    pub fn total_length(&self) -> R {
+        self.lengths.last().unwrap()
+    }
+
+    /// Returns the parameter of the underlying curve at which the given length along the curve is reached.
+    ///
+    /// Lengths outside of `[0.0, total_length]` are extrapolated with the first or last piece of the curve.
+    pub fn parameter_at_length(&self, length: R) -> R
+    where
+        R: Debug,
+    {
+        let (min_index, max_index, factor) = self.lengths.upper_border(length);
+        lerp(
+            self.parameters[min_index],
+            self.parameters[max_index],
+            factor,
+        )
+    }
+
+    /// Returns the underlying curve.
+    pub fn into_inner(self) -> C {
+        self.curve
+    }
+}
+
+impl<C, R> Generator<R> for ArcLength<C, R>
+where
+    C: Curve<R>,
+    R: Real + FromPrimitive + Debug,
+{
+    type Output = C::Output;
+    fn gen(&self, input: R) -> Self::Output {
+        self.curve.gen(self.parameter_at_length(input))
+    }
+}
+
+impl<C, R> Curve<R> for ArcLength<C, R>
+where
+    C: Curve<R>,
+    R: Real + FromPrimitive + Debug,
+{
+    fn domain(&self) -> [R; 2] {
+        [R::zero(), self.total_length()]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ArcLength;
+    use crate::{Curve, Generator};
+
+    /// Quarter of the unit circle with a non-uniform speed.
+    #[derive(Debug, Clone, Copy)]
+    struct Circle;
+
+    impl Generator<f64> for Circle {
+        type Output = [f64; 2];
+        fn gen(&self, input: f64) -> [f64; 2] {
+            let angle = input * input * core::f64::consts::FRAC_PI_2;
+            [angle.cos(), angle.sin()]
+        }
+    }
+
+    impl Curve<f64> for Circle {
+        fn domain(&self) -> [f64; 2] {
+            [0.0, 1.0]
+        }
+    }
+
+    fn distance(a: [f64; 2], b: [f64; 2]) -> f64 {
+        ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2)).sqrt()
+    }
+
+    #[test]
+    fn circle() {
+        let arc = ArcLength::new(Circle, 1e-9, distance);
+        let quarter = core::f64::consts::FRAC_PI_2;
+        assert!((arc.total_length() - quarter).abs() < 1e-8);
+        assert_eq!(arc.domain()[0], 0.0);
+        // equal steps in the input result in equal steps in the angle
+        for (i, point) in arc.by_ref().take(11).enumerate() {
+            let angle = quarter * i as f64 / 10.0;
+            assert!((point[0] - angle.cos()).abs() < 1e-6);
+            assert!((point[1] - angle.sin()).abs() < 1e-6);
+        }
+        assert!((arc.parameter_at_length(quarter / 4.0) - 0.5).abs() < 1e-6);
+    }
+
+    /// Line with a quadratic speed.
+    #[derive(Debug, Clone, Copy)]
+    struct Line;
+
+    impl Generator<f64> for Line {
+        type Output = f64;
+        fn gen(&self, input: f64) -> f64 {
+            input * input
+        }
+    }
+
+    impl Curve<f64> for Line {
+        fn domain(&self) -> [f64; 2] {
+            [-1.0, 2.0]
+        }
+    }
+
+    #[test]
+    fn line() {
+        let arc = ArcLength::new(Line, 1e-9, |a: f64, b: f64| (a - b).abs());
+        assert!((arc.total_length() - 5.0).abs() < 1e-8);
+        assert!((arc.gen(0.5) - 0.5).abs() < 1e-6);
+        assert!(arc.gen(1.0).abs() < 1e-6);
+        assert!((arc.gen(2.0) - 1.0).abs() < 1e-6);
+        assert!((arc.gen(5.0) - 4.0).abs() < 1e-6);
+        assert!((arc.parameter_at_length(3.0) - 2f64.sqrt()).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,517 @@
+//! Exact bounds and extrema of piecewise polynomial and rational curves.
+
+use crate::weights::Homogeneous;
+use num_traits::real::Real;
+use num_traits::FromPrimitive;
+
+/// Maximal number of times an interval gets halved while isolating roots.
+const MAX_DEPTH: usize = 48;
+/// Maximal number of bisection steps used to approximate an isolated root.
+const MAX_ITERATIONS: usize = 64;
+
+/// Trait for elements consisting of scalar components, like points or vectors.
+///
+/// Bounds of curves are calculated component-wise, such they are axis-aligned.
+/// Intersections with other curves, lines and rays are searched with the help of such bounds.
+/// This trait is implemented for floats, which consist of a single component, and arrays of scalars.
+///
+/// # Examples
+///
+/// Points given as own structs implement this trait by listing their coordinates.
+/// The examples of the intersection methods use the following point in two dimensions.
+///
+/// ```rust
+/// # use enterpolation::Components;
+/// #[derive(Debug, Copy, Clone, Default, PartialEq)]
+/// struct Point(f64, f64);
+///
+/// impl Components<f64> for Point {
+///     fn dimension(&self) -> usize {
+///         2
+///     }
+///     fn component(&self, index: usize) -> f64 {
+///         [self.0, self.1][index]
+///     }
+///     fn set_component(&mut self, index: usize, value: f64) {
+///         match index {
+///             0 => self.0 = value,
+///             1 => self.1 = value,
+///             _ => panic!("a point in two dimensions has no component {}", index),
+///         }
+///     }
+/// }
+///
+/// let mut point = Point(1.0, 2.0);
+/// point.set_component(1, 3.0);
+/// assert_eq!(point.component(1), 3.0);
+/// ```
+pub trait Components<R> {
+    /// Returns the number of components.
+    fn dimension(&self) -> usize;
+    /// Returns the component with the given index.
+    fn component(&self, index: usize) -> R;
+    /// Sets the component with the given index to the given value.
+    fn set_component(&mut self, index: usize, value: R);
+}
+
+impl Components<f64> for f64 {
+    fn dimension(&self) -> usize {
+        1
+    }
+    fn component(&self, _index: usize) -> f64 {
+        *self
+    }
+    fn set_component(&mut self, _index: usize, value: f64) {
+        *self = value;
+    }
+}
+
+impl Components<f32> for f32 {
+    fn dimension(&self) -> usize {
+        1
+    }
+    fn component(&self, _index: usize) -> f32 {
+        *self
+    }
+    fn set_component(&mut self, _index: usize, value: f32) {
+        *self = value;
+    }
+}
+
+impl<R, const N: usize> Components<R> for [R; N]
+where
+    R: Copy,
+{
+    fn dimension(&self) -> usize {
+        N
+    }
+    fn component(&self, index: usize) -> R {
+        self[index]
+    }
+    fn set_component(&mut self, index: usize, value: R) {
+        self[index] = value;
+    }
+}
+
+/// The local minima and maxima of a scalar curve.
+///
+/// Both are given as pairs of parameter and value, sorted by their parameter.
+/// The borders of the domain are included, if they are an extremum.
+///
+/// This struct is created by the `extrema()` methods of bezier curves and bsplines.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Extrema<R> {
+    minima: Vec<(R, R)>,
+    maxima: Vec<(R, R)>,
+}
+
+impl<R> Extrema<R> {
+    /// Returns the parameters and values of all local minima.
+    pub fn minima(&self) -> &[(R, R)] {
+        &self.minima
+    }
+
+    /// Returns the parameters and values of all local maxima.
+    pub fn maxima(&self) -> &[(R, R)] {
+        &self.maxima
+    }
+}
+
+/// A piece of a curve given as bezier curve over an interval.
+///
+/// Rational pieces have weights, in which case the elements are the directions of the homogeneous elements.
+#[derive(Debug, Clone)]
+pub(crate) struct Piece<T, R> {
+    pub(super) interval: [R; 2],
+    pub(super) elements: Vec<T>,
+    pub(super) weights: Option<Vec<R>>,
+}
+
+impl<T, R> Piece<T, R> {
+    /// Creates a polynomial piece with the given elements over the given interval.
+    pub(crate) fn new(interval: [R; 2], elements: Vec<T>) -> Self {
+        Piece {
+            interval,
+            elements,
+            weights: None,
+        }
+    }
+}
+
+impl<T, R> Piece<Homogeneous<T, R>, R>
+where
+    T: Copy,
+    R: Copy,
+{
+    /// Transforms a piece with homogeneous elements into a rational piece.
+    pub(crate) fn rational(self) -> Piece<T, R> {
+        Piece {
+            interval: self.interval,
+            elements: self
+                .elements
+                .iter()
+                .map(|element| element.direction())
+                .collect(),
+            weights: Some(
+                self.elements
+                    .iter()
+                    .map(|element| element.rational())
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl<T, R> Piece<T, R>
+where
+    R: Real + FromPrimitive,
+{
+    /// Returns the parameters and values of the borders and all critical points of the piece,
+    /// whose elements are replaced by the given coefficients.
+    fn candidates(&self, coefficients: &[R]) -> Vec<(R, R)> {
+        let weights = self.weights.as_deref();
+        let mut parameters = vec![R::zero()];
+        roots(
+            derivative(coefficients, weights),
+            [R::zero(), R::one()],
+            0,
+            &mut parameters,
+        );
+        parameters.push(R::one());
+        let [start, end] = self.interval;
+        parameters
+            .into_iter()
+            .map(|parameter| {
+                let value = match weights {
+                    Some(weights) => {
+                        evaluate(coefficients, parameter) / evaluate(weights, parameter)
+                    }
+                    None => evaluate(coefficients, parameter),
+                };
+                (start + (end - start) * parameter, value)
+            })
+            .collect()
+    }
+}
+
+/// Returns the component-wise minimum and maximum of the curve consisting of the given pieces.
+///
+/// # Panics
+///
+/// Panics if no pieces or a piece without elements are given.
+pub(crate) fn bounds<T, R>(pieces: &[Piece<T, R>]) -> [T; 2]
+where
+    T: Components<R> + Copy,
+    R: Real + FromPrimitive,
+{
+    let mut min = pieces[0].elements[0];
+    let mut max = min;
+    for axis in 0..min.dimension() {
+        let (low, high) = pieces
+            .iter()
+            .flat_map(|piece| {
+                let coefficients: Vec<R> = piece
+                    .elements
+                    .iter()
+                    .map(|element| element.component(axis))
+                    .collect();
+                piece.candidates(&coefficients)
+            })
+            .fold(
+                (R::max_value(), R::min_value()),
+                |(low, high), (_, value)| (low.min(value), high.max(value)),
+            );
+        min.set_component(axis, low);
+        max.set_component(axis, high);
+    }
+    [min, max]
+}
+
+/// Returns the local extrema of the scalar curve consisting of the given consecutive pieces.
+pub(crate) fn extrema<R>(pieces: &[Piece<R, R>]) -> Extrema<R>
+where
+    R: Real + FromPrimitive,
+{
+    let mut candidates: Vec<(R, R)> = Vec::new();
+    for piece in pieces {
+        for candidate in piece.candidates(&piece.elements) {
+            // borders of consecutive pieces coincide
+            if candidates
+                .last()
+                .map_or(false, |last| last.0 >= candidate.0)
+            {
+                continue;
+            }
+            candidates.push(candidate);
+        }
+    }
+    let mut extrema = Extrema {
+        minima: Vec::new(),
+        maxima: Vec::new(),
+    };
+    for (i, &(parameter, value)) in candidates.iter().enumerate() {
+        let neighbours = [i.checked_sub(1), Some(i + 1)]
+            .into_iter()
+            .flatten()
+            .filter_map(|index| candidates.get(index))
+            .map(|neighbour| neighbour.1);
+        let (mut lower, mut higher) = (false, false);
+        for neighbour in neighbours {
+            lower |= neighbour < value;
+            higher |= neighbour > value;
+        }
+        if lower && !higher {
+            extrema.maxima.push((parameter, value));
+        }
+        if higher && !lower {
+            extrema.minima.push((parameter, value));
+        }
+    }
+    extrema
+}
+
+/// Returns a polynomial in bernstein form with the same roots in (0,1) as the derivative
+/// of the given (rational) bernstein polynomial.
+///
+/// For rational polynomials, this is the numerator of the quotient rule.
+fn derivative<R>(coefficients: &[R], weights: Option<&[R]>) -> Vec<R>
+where
+    R: Real + FromPrimitive,
+{
+    // the constant factor given by the degree does not change the roots
+    let differences =
+        |values: &[R]| -> Vec<R> { values.windows(2).map(|pair| pair[1] - pair[0]).collect() };
+    match weights {
+        None => differences(coefficients),
+        Some(weights) => {
+            let first = product(&differences(coefficients), weights);
+            let second = product(coefficients, &differences(weights));
+            first
+                .into_iter()
+                .zip(second)
+                .map(|(first, second)| first - second)
+                .collect()
+        }
+    }
+}
+
+/// Returns the product of two polynomials in bernstein form.
+fn product<R>(first: &[R], second: &[R]) -> Vec<R>
+where
+    R: Real + FromPrimitive,
+{
+    if first.is_empty() || second.is_empty() {
+        return Vec::new();
+    }
+    let (m, n) = (first.len() - 1, second.len() - 1);
+    (0..=m + n)
+        .map(|k| {
+            let sum = (k.saturating_sub(n)..=k.min(m)).fold(R::zero(), |sum, i| {
+                let weight = binomial::<R>(m, i) * binomial::<R>(n, k - i);
+                sum + first[i] * second[k - i] * weight
+            });
+            sum / binomial::<R>(m + n, k)
+        })
+        .collect()
+}
+
+/// Calculates the binomial coefficient of n over k.
+fn binomial<R>(n: usize, k: usize) -> R
+where
+    R: Real + FromPrimitive,
+{
+    let k = k.min(n - k);
+    (0..k).fold(R::one(), |acc, i| {
+        acc * R::from_usize(n - i).unwrap() / R::from_usize(i + 1).unwrap()
+    })
+}
+
+/// Evaluates the polynomial in bernstein form at the given parameter with de Casteljau's algorithm.
+fn evaluate<R>(coefficients: &[R], parameter: R) -> R
+where
+    R: Real,
+{
+    let mut workspace = coefficients.to_vec();
+    for k in 1..workspace.len() {
+        for i in 0..workspace.len() - k {
+            workspace[i] = workspace[i] * (R::one() - parameter) + workspace[i + 1] * parameter;
+        }
+    }
+    workspace[0]
+}
+
+/// Splits the polynomial in bernstein form into its halves.
+fn halve<R>(coefficients: &[R]) -> (Vec<R>, Vec<R>)
+where
+    R: Real,
+{
+    let half = (R::one() + R::one()).recip();
+    let mut workspace = coefficients.to_vec();
+    let len = workspace.len();
+    let mut left = Vec::with_capacity(len);
+    let mut right = Vec::with_capacity(len);
+    left.push(workspace[0]);
+    right.push(workspace[len - 1]);
+    for k in 1..len {
+        for i in 0..len - k {
+            workspace[i] = (workspace[i] + workspace[i + 1]) * half;
+        }
+        left.push(workspace[0]);
+        right.push(workspace[len - k - 1]);
+    }
+    right.reverse();
+    (left, right)
+}
+
+/// Returns the number of sign changes in the given values, ignoring zeros.
+fn sign_changes<R>(values: &[R]) -> usize
+where
+    R: Real,
+{
+    let mut changes = 0;
+    let mut previous: Option<bool> = None;
+    for value in values.iter().filter(|value| !value.is_zero()) {
+        let positive = value.is_sign_positive();
+        if previous.map_or(false, |previous| previous != positive) {
+            changes += 1;
+        }
+        previous = Some(positive);
+    }
+    changes
+}
+
+/// Adds the roots of the polynomial in bernstein form lying in the inside of the given interval to the list.
+///
+/// The polynomial is halved until it has at most one sign change in its coefficients,
+/// which isolates the roots, as the number of roots is bounded by the number of sign changes.
+/// Roots which cannot be isolated, like double roots, are approximated by the last interval.
+pub(super) fn roots<R>(
+    coefficients: Vec<R>,
+    interval: [R; 2],
+    depth: usize,
+    roots_found: &mut Vec<R>,
+) where
+    R: Real,
+{
+    let changes = sign_changes(&coefficients);
+    if changes == 0 {
+        return;
+    }
+    let [start, end] = interval;
+    let middle = (start + end) / (R::one() + R::one());
+    let first = coefficients[0];
+    let last = coefficients[coefficients.len() - 1];
+    if changes == 1 && !first.is_zero() && !last.is_zero() {
+        roots_found.push(start + (end - start) * bisection(&coefficients));
+        return;
+    }
+    if depth >= MAX_DEPTH {
+        roots_found.push(middle);
+        return;
+    }
+    let (left, right) = halve(&coefficients);
+    let center = right[0];
+    roots(left, [start, middle], depth + 1, roots_found);
+    if center.is_zero() {
+        roots_found.push(middle);
+    }
+    roots(right, [middle, end], depth + 1, roots_found);
+}
+
+/// Approximates the single root in [0,1] of the polynomial in bernstein form,
+/// whose first and last coefficients have different signs.
+fn bisection<R>(coefficients: &[R]) -> R
+where
+    R: Real,
+{
+    let positive = coefficients[0].is_sign_positive();
+    let mut lower = R::zero();
+    let mut upper = R::one();
+    for _ in 0..MAX_ITERATIONS {
+        let middle = (lower + upper) / (R::one() + R::one());
+        if middle <= lower || middle >= upper {
+            break;
+        }
+        let value = evaluate(coefficients, middle);
+        if value.is_zero() {
+            return middle;
+        }
+        if value.is_sign_positive() == positive {
+            lower = middle;
+        } else {
+            upper = middle;
+        }
+    }
+    (lower + upper) / (R::one() + R::one())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn roots_of_polynomials() {
+        // (t - 0.25)(t - 0.5)(t - 0.75) in bernstein form
+        let coefficients = vec![-0.09375, 13.0 / 96.0, -13.0 / 96.0, 0.09375];
+        let mut found = Vec::new();
+        roots(coefficients, [0.0, 1.0], 0, &mut found);
+        assert_eq!(found.len(), 3);
+        for (root, expected) in found.into_iter().zip([0.25, 0.5, 0.75]) {
+            assert_f64_near!(root, expected);
+        }
+        // no roots in the inside
+        let mut found = Vec::new();
+        roots(vec![0.0, 1.0, 2.0], [0.0, 1.0], 0, &mut found);
+        assert!(found.is_empty());
+    }
+
+    #[test]
+    fn products() {
+        let first = [1.0, 3.0, -2.0];
+        let second = [0.5, 2.0];
+        let result = product(&first, &second);
+        assert_eq!(result.len(), 4);
+        for i in 0..=10 {
+            let t = i as f64 / 10.0;
+            let expected = evaluate(&first, t) * evaluate(&second, t);
+            assert!((evaluate(&result, t) - expected).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn components() {
+        let piece = Piece::new([0.0, 1.0], vec![[0.0, 1.0], [2.0, -1.0], [1.0, 1.0]]);
+        let [min, max] = bounds(&[piece]);
+        // x = 4t - 3t^2, y = 1 - 4t + 4t^2
+        assert_f64_near!(min[0], 0.0);
+        assert_f64_near!(max[0], 4.0 / 3.0);
+        assert_f64_near!(min[1], 0.0);
+        assert_f64_near!(max[1], 1.0);
+    }
+
+    #[test]
+    fn rational() {
+        // the quarter of the unit circle
+        let weight = 0.5_f64.sqrt();
+        let piece = Piece {
+            interval: [0.0, 1.0],
+            elements: vec![[1.0, 0.0], [weight, weight], [0.0, 1.0]],
+            weights: Some(vec![1.0, weight, 1.0]),
+        };
+        let [min, max] = bounds(core::slice::from_ref(&piece));
+        assert_f64_near!(min[0], 0.0);
+        assert_f64_near!(max[0], 1.0);
+        assert_f64_near!(min[1], 0.0);
+        assert_f64_near!(max[1], 1.0);
+        // the difference of the coordinates has its maximum at the start and its minimum at the end
+        let difference = Piece {
+            interval: [0.0, 1.0],
+            elements: vec![1.0, 0.0, -1.0],
+            weights: piece.weights,
+        };
+        let extrema = extrema(&[difference]);
+        assert_eq!(extrema.maxima(), &[(0.0, 1.0)]);
+        assert_eq!(extrema.minima(), &[(1.0, -1.0)]);
+    }
+}
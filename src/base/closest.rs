@@ -0,0 +1,219 @@
+//! Projection of points onto curves.
+
+use super::{Curve, Stepper};
+use num_traits::real::Real;
+use num_traits::FromPrimitive;
+
+/// Number of equidistant samples used to find a first approximation of the closest point.
+const SAMPLES: usize = 65;
+/// Maximal number of iterations used to refine the closest point.
+const MAX_ITERATIONS: usize = 128;
+
+/// Samples the curve equidistantly.
+#[cfg(feature = "std")]
+fn samples<C, R>(curve: &C) -> Vec<(R, C::Output)>
+where
+    C: Curve<R>,
+    R: Real + FromPrimitive,
+{
+    let [start, end] = curve.domain();
+    Stepper::new(SAMPLES, start, end)
+        .map(|parameter| (parameter, curve.gen(parameter)))
+        .collect()
+}
+
+/// Returns the parameter, the point and the distance of the point of the curve closest to the given point.
+///
+/// The closest of the given samples is used as first approximation, which gets refined with
+/// Newton's method on the squared distance between the curve and the point in between the
+/// neighboring samples. As curves do not have to be differentiable, the first and second derivative
+/// of the squared distance are approximated by a parabola through three nearby parameters.
+/// Steps leaving the interval in which the closest point is known to lie are replaced by bisection.
+pub(crate) fn closest<C, R, F, I>(
+    curve: &C,
+    samples: I,
+    point: C::Output,
+    tolerance: R,
+    metric: &F,
+) -> (R, C::Output, R)
+where
+    C: Curve<R>,
+    C::Output: Copy,
+    R: Real + FromPrimitive,
+    F: Fn(C::Output, C::Output) -> R,
+    I: IntoIterator<Item = (R, C::Output)>,
+{
+    let [start, end] = curve.domain();
+    let two = R::from_usize(2).unwrap();
+    let step = (end - start) / R::from_usize(SAMPLES - 1).unwrap();
+    let mut best: Option<(R, C::Output, R)> = None;
+    for (parameter, sample) in samples {
+        let distance = metric(sample, point);
+        if best.map_or(true, |(_, _, min)| distance < min) {
+            best = Some((parameter, sample, distance));
+        }
+    }
+    // there is always at least one sample
+    let best = best.unwrap();
+    let squared = |parameter: R| {
+        let distance = metric(curve.gen(parameter), point);
+        distance * distance
+    };
+    // the width of the parabola, balancing the error of the approximation and rounding errors
+    let width = R::epsilon().cbrt() * (end - start);
+    let mut low = (best.0 - step).max(start);
+    let mut high = (best.0 + step).min(end);
+    let mut parameter = best.0;
+    for _ in 0..MAX_ITERATIONS {
+        if high - low <= tolerance {
+            break;
+        }
+        let left = (parameter - width).max(start).min(end - width - width);
+        let center = left + width;
+        let [before, middle, after] = [left, center, center + width].map(squared);
+        let curvature = (after - middle - middle + before) / (width * width);
+        let slope = (after - before) / (width + width) + (parameter - center) * curvature;
+        if slope.is_zero() {
+            break;
+        }
+        // the closest point lies in the direction in which the distance decreases
+        if slope < R::zero() {
+            low = parameter;
+        } else {
+            high = parameter;
+        }
+        let mut next = parameter - slope / curvature;
+        if !(curvature > R::zero() && next > low && next < high) {
+            next = (low + high) / two;
+        }
+        let change = (next - parameter).abs();
+        parameter = next;
+        if change <= tolerance {
+            break;
+        }
+    }
+    let refined = curve.gen(parameter);
+    let refined_distance = metric(refined, point);
+    if refined_distance <= best.2 {
+        (parameter, refined, refined_distance)
+    } else {
+        best
+    }
+}
+
+/// Returns the closest points of the curve to the given points.
+///
+/// The curve is only sampled once for all points.
+#[cfg(feature = "std")]
+pub(crate) fn closest_all<C, R, F, I>(
+    curve: &C,
+    points: I,
+    tolerance: R,
+    metric: &F,
+) -> Vec<(R, C::Output, R)>
+where
+    C: Curve<R>,
+    C::Output: Copy,
+    R: Real + FromPrimitive,
+    F: Fn(C::Output, C::Output) -> R,
+    I: IntoIterator<Item = C::Output>,
+{
+    let samples = samples(curve);
+    points
+        .into_iter()
+        .map(|point| closest(curve, samples.iter().copied(), point, tolerance, metric))
+        .collect()
+}
+
+/// Returns the closest point of the curve to the given point.
+pub(crate) fn closest_one<C, R, F>(
+    curve: &C,
+    point: C::Output,
+    tolerance: R,
+    metric: &F,
+) -> (R, C::Output, R)
+where
+    C: Curve<R>,
+    C::Output: Copy,
+    R: Real + FromPrimitive,
+    F: Fn(C::Output, C::Output) -> R,
+{
+    let [start, end] = curve.domain();
+    let samples =
+        Stepper::new(SAMPLES, start, end).map(|parameter| (parameter, curve.gen(parameter)));
+    closest(curve, samples, point, tolerance, metric)
+}
+
+#[cfg(test)]
+mod test {
+    use super::closest_one;
+    use crate::{Curve, Generator};
+
+    /// Half of the unit circle.
+    #[derive(Debug, Clone, Copy)]
+    struct Circle;
+
+    impl Generator<f64> for Circle {
+        type Output = [f64; 2];
+        fn gen(&self, input: f64) -> [f64; 2] {
+            [input.cos(), input.sin()]
+        }
+    }
+
+    impl Curve<f64> for Circle {
+        fn domain(&self) -> [f64; 2] {
+            [0.0, core::f64::consts::PI]
+        }
+    }
+
+    fn distance(a: [f64; 2], b: [f64; 2]) -> f64 {
+        ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2)).sqrt()
+    }
+
+    #[test]
+    fn circle() {
+        for angle in [0.3, 1.0, 2.2, 3.0] {
+            let point = [2.0 * f64::cos(angle), 2.0 * f64::sin(angle)];
+            let (parameter, closest, dist) = closest_one(&Circle, point, 1e-10, &distance);
+            assert!((parameter - angle).abs() < 1e-8);
+            assert!((closest[0] - angle.cos()).abs() < 1e-8);
+            assert!((closest[1] - angle.sin()).abs() < 1e-8);
+            assert!((dist - 1.0).abs() < 1e-8);
+        }
+        // points outside of the domain are projected onto the borders
+        let (parameter, _, dist) = closest_one(&Circle, [1.0, -1.0], 1e-10, &distance);
+        assert!(parameter.abs() < 1e-8);
+        assert!((dist - 1.0).abs() < 1e-8);
+    }
+
+    #[test]
+    fn metric() {
+        // a metric in which distances along the first axis count twice
+        let stretched =
+            |a: [f64; 2], b: [f64; 2]| (4.0 * (a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2)).sqrt();
+        let point = [0.5, 1.5];
+        let (parameter, _, dist) = closest_one(&Circle, point, 1e-12, &stretched);
+        // compare with a dense sampling of the curve
+        let (expected, min) = (0..=100_000)
+            .map(|i| {
+                let angle = core::f64::consts::PI * i as f64 / 100_000.0;
+                (angle, stretched(Circle.gen(angle), point))
+            })
+            .fold((0.0, f64::INFINITY), |a, b| if b.1 < a.1 { b } else { a });
+        assert!((parameter - expected).abs() < 1e-4);
+        assert!(dist <= min);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn batched() {
+        let points = [[0.0, 0.5], [-3.0, 0.1], [0.5, 0.5]];
+        let results = super::closest_all(&Circle, points, 1e-10, &distance);
+        assert_eq!(results.len(), 3);
+        for (point, result) in points.iter().zip(results) {
+            let single = closest_one(&Circle, *point, 1e-10, &distance);
+            assert!((single.0 - result.0).abs() < 1e-8);
+            assert!((single.2 - result.2).abs() < 1e-8);
+        }
+    }
+}
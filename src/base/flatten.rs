@@ -0,0 +1,143 @@
+//! Adaptive flattening of curves into polylines.
+
+use super::{Curve, Stepper};
+#[cfg(feature = "linear")]
+use crate::{easing::Identity, linear::Linear, Sorted};
+use num_traits::real::Real;
+use num_traits::FromPrimitive;
+use topology_traits::Merge;
+
+/// Number of segments the domain is divided into before subdividing adaptively.
+const INITIAL_SEGMENTS: usize = 16;
+/// Maximal number of times a segment gets halved.
+pub(crate) const MAX_DEPTH: usize = 16;
+
+/// Returns samples of the curve, such that the polyline through them deviates at most by the tolerance from the curve.
+///
+/// The domain is divided into equal segments, which get halved until the curve at a quarter,
+/// the half and three quarters of the segment is within the tolerance of the chord at the same positions.
+/// Features of the curve smaller than a sixteenth of the domain may be missed.
+pub(crate) fn flatten<C, R, F>(curve: &C, tolerance: R, metric: &F) -> Vec<(R, C::Output)>
+where
+    C: Curve<R> + ?Sized,
+    C::Output: Merge<R> + Copy,
+    R: Real + FromPrimitive,
+    F: Fn(C::Output, C::Output) -> R,
+{
+    let [start, end] = curve.domain();
+    let two = R::one() + R::one();
+    let mut samples = vec![(start, curve.gen(start))];
+    let mut previous = samples[0];
+    let flattener = Flattener {
+        curve,
+        tolerance,
+        metric,
+    };
+    for parameter in Stepper::new(INITIAL_SEGMENTS + 1, start, end).skip(1) {
+        let next = (parameter, curve.gen(parameter));
+        let middle = (previous.0 + next.0) / two;
+        flattener.segment(previous, next, curve.gen(middle), 0, &mut samples);
+        previous = next;
+    }
+    samples
+}
+
+/// Creates a linear interpolation through the given samples.
+#[cfg(feature = "linear")]
+pub(crate) fn polyline<R, T>(samples: Vec<(R, T)>) -> Linear<Sorted<Vec<R>>, Vec<T>, Identity>
+where
+    T: Merge<R> + Copy,
+    R: Real,
+{
+    let (knots, elements): (Vec<R>, Vec<T>) = IntoIterator::into_iter(samples).unzip();
+    Linear::new_unchecked(elements, Sorted::new_unchecked(knots), Identity::new())
+}
+
+/// The state of the flattening of a curve.
+struct Flattener<'a, C: ?Sized, R, F> {
+    curve: &'a C,
+    tolerance: R,
+    metric: &'a F,
+}
+
+impl<C, R, F> Flattener<'_, C, R, F>
+where
+    C: Curve<R> + ?Sized,
+    C::Output: Merge<R> + Copy,
+    R: Real + FromPrimitive,
+    F: Fn(C::Output, C::Output) -> R,
+{
+    /// Adds the samples of the segment in between the given samples to the list, except the first one.
+    fn segment(
+        &self,
+        first: (R, C::Output),
+        last: (R, C::Output),
+        middle: C::Output,
+        depth: usize,
+        samples: &mut Vec<(R, C::Output)>,
+    ) {
+        let two = R::one() + R::one();
+        let quarter = two.recip() * two.recip();
+        let center = (first.0 + last.0) / two;
+        let left = (first.0 + center) / two;
+        let right = (center + last.0) / two;
+        let left_value = self.curve.gen(left);
+        let right_value = self.curve.gen(right);
+        let chord = |factor: R| first.1.merge(last.1, factor);
+        let error = (self.metric)(left_value, chord(quarter))
+            .max((self.metric)(middle, chord(two.recip())))
+            .max((self.metric)(right_value, chord(R::one() - quarter)));
+        if error <= self.tolerance || depth >= MAX_DEPTH {
+            samples.push(last);
+            return;
+        }
+        self.segment(first, (center, middle), left_value, depth + 1, samples);
+        self.segment((center, middle), last, right_value, depth + 1, samples);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::easing::FuncEase;
+    use crate::Generator;
+
+    fn distance(a: f64, b: f64) -> f64 {
+        (a - b).abs()
+    }
+
+    #[test]
+    fn adaptive() {
+        // flat on the left, steep on the right
+        let curve = FuncEase::new(|x: f64| x.powi(8));
+        let samples = flatten(&curve, 1e-3, &distance);
+        assert_eq!(samples[0], (0.0, 0.0));
+        assert_eq!(samples[samples.len() - 1], (1.0, 1.0));
+        let left = samples.iter().filter(|(t, _)| *t < 0.5).count();
+        let right = samples.len() - left;
+        assert!(right > 2 * left);
+        for window in samples.windows(2) {
+            let [(t0, p0), (t1, p1)] = [window[0], window[1]];
+            assert!(t0 < t1);
+            for i in 1..10 {
+                let factor = i as f64 / 10.0;
+                let t = t0 + (t1 - t0) * factor;
+                assert!((curve.gen(t) - (p0 + (p1 - p0) * factor)).abs() < 2e-3);
+            }
+        }
+        // straight curves only need the initial segments
+        let line = FuncEase::new(|x: f64| 2.0 * x);
+        assert_eq!(flatten(&line, 1e-9, &distance).len(), INITIAL_SEGMENTS + 1);
+    }
+
+    #[test]
+    #[cfg(feature = "linear")]
+    fn polyline() {
+        let curve = FuncEase::new(|x: f64| (5.0 * x).sin());
+        let line = super::polyline(flatten(&curve, 1e-4, &distance));
+        for i in 0..=100 {
+            let x = i as f64 / 100.0;
+            assert!((line.gen(x) - curve.gen(x)).abs() < 2e-4);
+        }
+    }
+}
@@ -3,9 +3,30 @@ use num_traits::FromPrimitive;
 
 use core::iter::FusedIterator;
 use core::ops::RangeBounds;
+#[cfg(feature = "std")]
+use topology_traits::Merge;
+use topology_traits::QuasiMetric;
 
+#[cfg(feature = "std")]
+use super::closest::closest_all;
+use super::closest::closest_one;
+#[cfg(feature = "std")]
+use super::flatten::flatten;
+#[cfg(all(feature = "std", feature = "linear"))]
+use super::flatten::polyline;
+use super::inverse::solve_for;
+#[cfg(feature = "std")]
+use super::ArcLength;
 use super::Equidistant;
 use super::{Clamp, Composite, Repeat, Slice, Stack};
+use super::{Inverse, InverseError, NotMonotonic};
+use crate::easing::Identity;
+#[cfg(all(feature = "std", feature = "polynomial"))]
+use crate::polynomial::ChebyshevSeries;
+#[cfg(all(feature = "std", feature = "linear"))]
+use crate::{linear::Linear, Sorted};
+#[cfg(all(feature = "std", feature = "polynomial"))]
+use core::ops::{Add, Mul, Sub};
 
 /// Trait which symbolises the generation or copying of an element.
 ///
@@ -287,6 +308,456 @@ where
     {
         Clamp::new(self)
     }
+    /// Invert a monotonic real-valued curve.
+    ///
+    /// The returned curve maps the values of this curve back to their parameter.
+    /// Its domain is the range of this curve and inputs outside of it are clamped.
+    /// The parameters are found by bracketing combined with secant steps. If the derivative
+    /// of the curve is known, consider using [`invert_with_derivative()`] instead.
+    ///
+    /// # Errors
+    ///
+    /// [`NotMonotonic`] if the curve is not monotonic. Monotonicity is checked with samples of the curve.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(all(feature = "linear", feature = "std"), doc = "```rust")]
+    #[cfg_attr(not(all(feature = "linear", feature = "std")), doc = "```ignore")]
+    /// # use enterpolation::{linear::Linear, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let linear = Linear::builder()
+    ///                 .elements([0.0,1.0,4.0])
+    ///                 .knots([0.0,1.0,2.0])
+    ///                 .build()?;
+    /// let inverse = linear.invert()?;
+    /// assert_f64_near!(inverse.gen(0.5), 0.5);
+    /// assert_f64_near!(inverse.gen(2.5), 1.5);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`invert_with_derivative()`]: Curve::invert_with_derivative()
+    /// [`NotMonotonic`]: crate::NotMonotonic
+    fn invert(self) -> Result<Inverse<Self>, NotMonotonic>
+    where
+        Self: Sized + Generator<R, Output = R>,
+        R: FromPrimitive,
+    {
+        Inverse::new(self, None::<Identity>)
+    }
+    /// Invert a monotonic real-valued curve with the help of its derivative.
+    ///
+    /// The returned curve maps the values of this curve back to their parameter.
+    /// Its domain is the range of this curve and inputs outside of it are clamped.
+    /// The parameters are found by bracketing combined with Newton iterations.
+    ///
+    /// # Errors
+    ///
+    /// [`NotMonotonic`] if the curve is not monotonic. Monotonicity is checked with samples of the curve.
+    ///
+    /// [`NotMonotonic`]: crate::NotMonotonic
+    fn invert_with_derivative<D>(self, derivative: D) -> Result<Inverse<Self, D>, NotMonotonic>
+    where
+        Self: Sized + Generator<R, Output = R>,
+        D: Generator<R, Output = R>,
+        R: FromPrimitive,
+    {
+        Inverse::new(self, Some(derivative))
+    }
+    /// Find the parameter at which a monotonic real-valued curve reaches the given value.
+    ///
+    /// The parameter is found by bracketing combined with secant steps.
+    ///
+    /// # Errors
+    ///
+    /// [`NotMonotonic`] if the curve is not monotonic. Monotonicity is checked with samples of the curve.
+    /// [`OutOfRange`] if the value is not reached within the domain of the curve.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(all(feature = "linear", feature = "std"), doc = "```rust")]
+    #[cfg_attr(not(all(feature = "linear", feature = "std")), doc = "```ignore")]
+    /// # use enterpolation::{linear::Linear, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let linear = Linear::builder()
+    ///                 .elements([0.0,1.0,4.0])
+    ///                 .knots([0.0,1.0,2.0])
+    ///                 .build()?;
+    /// assert_f64_near!(linear.solve_for(2.5)?, 1.5);
+    /// assert!(linear.solve_for(5.0).is_err());
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`NotMonotonic`]: crate::InverseError
+    /// [`OutOfRange`]: crate::InverseError
+    fn solve_for(&self, value: R) -> Result<R, InverseError>
+    where
+        Self: Sized + Generator<R, Output = R>,
+        R: FromPrimitive,
+    {
+        solve_for(self, None::<&Identity>, value)
+    }
+    /// Find the point of the curve closest to the given point.
+    ///
+    /// Returns the parameter, the point of the curve and its distance to the given point.
+    /// The distance between elements is measured with their [`QuasiMetric`] implementation.
+    /// The curve is sampled equidistantly to find a first approximation, which is then refined
+    /// with Newton's method until the parameter is accurate up to the given tolerance.
+    ///
+    /// If the elements do not implement [`QuasiMetric`], consider using [`closest_parameter_with()`] instead.
+    ///
+    /// [`QuasiMetric`]: crate::QuasiMetric
+    /// [`closest_parameter_with()`]: Curve::closest_parameter_with()
+    fn closest_parameter(&self, point: Self::Output, tolerance: R) -> (R, Self::Output, R)
+    where
+        Self: Sized,
+        Self::Output: QuasiMetric<R> + Copy,
+        R: FromPrimitive,
+    {
+        closest_one(self, point, tolerance, &QuasiMetric::distance)
+    }
+    /// Find the point of the curve closest to the given point with the given metric.
+    ///
+    /// Returns the parameter, the point of the curve and its distance to the given point.
+    /// The curve is sampled equidistantly to find a first approximation, which is then refined
+    /// with Newton's method until the parameter is accurate up to the given tolerance.
+    ///
+    /// As only samples are used to find the first approximation, very small features of the curve
+    /// may be missed.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "linear", doc = "```rust")]
+    #[cfg_attr(not(feature = "linear"), doc = "```ignore")]
+    /// # use enterpolation::{linear::{Linear, LinearError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let linear = Linear::builder()
+    ///                 .elements([0.0,2.0,0.0])
+    ///                 .knots([0.0,1.0,2.0])
+    ///                 .build()?;
+    /// let distance = |a: f64, b: f64| (a - b).abs();
+    /// let (parameter, point, dist) = linear.closest_parameter_with(3.0, 1e-9, distance);
+    /// assert_f64_near!(parameter, 1.0);
+    /// assert_f64_near!(point, 2.0);
+    /// assert_f64_near!(dist, 1.0);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn closest_parameter_with<F>(
+        &self,
+        point: Self::Output,
+        tolerance: R,
+        metric: F,
+    ) -> (R, Self::Output, R)
+    where
+        Self: Sized,
+        Self::Output: Copy,
+        F: Fn(Self::Output, Self::Output) -> R,
+        R: FromPrimitive,
+    {
+        closest_one(self, point, tolerance, &metric)
+    }
+    /// Find the points of the curve closest to each of the given points.
+    ///
+    /// This is the same as calling [`closest_parameter()`] for every point,
+    /// however the curve is only sampled once for all points.
+    ///
+    /// [`closest_parameter()`]: Curve::closest_parameter()
+    #[cfg(feature = "std")]
+    fn closest_parameters<I>(&self, points: I, tolerance: R) -> Vec<(R, Self::Output, R)>
+    where
+        Self: Sized,
+        Self::Output: QuasiMetric<R> + Copy,
+        I: IntoIterator<Item = Self::Output>,
+        R: FromPrimitive,
+    {
+        closest_all(self, points, tolerance, &QuasiMetric::distance)
+    }
+    /// Find the points of the curve closest to each of the given points with the given metric.
+    ///
+    /// This is the same as calling [`closest_parameter_with()`] for every point,
+    /// however the curve is only sampled once for all points.
+    ///
+    /// [`closest_parameter_with()`]: Curve::closest_parameter_with()
+    #[cfg(feature = "std")]
+    fn closest_parameters_with<I, F>(
+        &self,
+        points: I,
+        tolerance: R,
+        metric: F,
+    ) -> Vec<(R, Self::Output, R)>
+    where
+        Self: Sized,
+        Self::Output: Copy,
+        I: IntoIterator<Item = Self::Output>,
+        F: Fn(Self::Output, Self::Output) -> R,
+        R: FromPrimitive,
+    {
+        closest_all(self, points, tolerance, &metric)
+    }
+    /// Sample the curve adaptively, such that the polyline through the samples is within the tolerance of the curve.
+    ///
+    /// Returns the parameters and points of the samples, beginning with the start of the domain and ending with its end.
+    /// The distance between elements is measured with their [`QuasiMetric`] implementation.
+    ///
+    /// If the elements do not implement [`QuasiMetric`], consider using [`flatten_with()`] instead.
+    ///
+    /// [`QuasiMetric`]: crate::QuasiMetric
+    /// [`flatten_with()`]: Curve::flatten_with()
+    #[cfg(feature = "std")]
+    fn flatten(&self, tolerance: R) -> Vec<(R, Self::Output)>
+    where
+        Self::Output: QuasiMetric<R> + Merge<R> + Copy,
+        R: FromPrimitive,
+    {
+        flatten(self, tolerance, &QuasiMetric::distance)
+    }
+    /// Sample the curve adaptively with the given metric, such that the polyline through the samples
+    /// is within the tolerance of the curve.
+    ///
+    /// Returns the parameters and points of the samples, beginning with the start of the domain and ending with its end.
+    /// In contrast to [`take()`], straight parts of the curve get only a few samples and tight bends a lot of them.
+    ///
+    /// The domain gets divided into 16 segments, which get halved until the curve at a quarter,
+    /// the half and three quarters of each segment is within the tolerance of the chord.
+    /// As only these points are checked, features smaller than a segment may be missed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{easing::FuncEase, Generator, Curve};
+    /// let curve = FuncEase::new(|x: f64| x.powi(4));
+    /// let samples = curve.flatten_with(1e-3, |a: f64, b: f64| (a - b).abs());
+    /// assert_eq!(samples[0], (0.0, 0.0));
+    /// assert_eq!(samples[samples.len() - 1], (1.0, 1.0));
+    /// // the steep end of the curve gets more samples
+    /// let end = samples.iter().filter(|(t, _)| *t > 0.5).count();
+    /// assert!(end > samples.len() / 2);
+    /// ```
+    ///
+    /// [`take()`]: Curve::take()
+    #[cfg(feature = "std")]
+    fn flatten_with<F>(&self, tolerance: R, metric: F) -> Vec<(R, Self::Output)>
+    where
+        Self::Output: Merge<R> + Copy,
+        F: Fn(Self::Output, Self::Output) -> R,
+        R: FromPrimitive,
+    {
+        flatten(self, tolerance, &metric)
+    }
+    /// Approximate the curve by a linear interpolation of adaptively chosen samples.
+    ///
+    /// The samples are the ones of [`flatten()`], which also describes the tolerance.
+    ///
+    /// If the elements do not implement [`QuasiMetric`], consider using [`polyline_with()`] instead.
+    ///
+    /// [`flatten()`]: Curve::flatten()
+    /// [`QuasiMetric`]: crate::QuasiMetric
+    /// [`polyline_with()`]: Curve::polyline_with()
+    #[cfg(all(feature = "std", feature = "linear"))]
+    fn polyline(&self, tolerance: R) -> Linear<Sorted<Vec<R>>, Vec<Self::Output>, Identity>
+    where
+        Self::Output: QuasiMetric<R> + Merge<R> + Copy,
+        R: FromPrimitive,
+    {
+        polyline(flatten(self, tolerance, &QuasiMetric::distance))
+    }
+    /// Approximate the curve by a linear interpolation of adaptively chosen samples, measured with the given metric.
+    ///
+    /// The samples are the ones of [`flatten_with()`], which also describes the tolerance.
+    ///
+    /// [`flatten_with()`]: Curve::flatten_with()
+    #[cfg(all(feature = "std", feature = "linear"))]
+    fn polyline_with<F>(
+        &self,
+        tolerance: R,
+        metric: F,
+    ) -> Linear<Sorted<Vec<R>>, Vec<Self::Output>, Identity>
+    where
+        Self::Output: Merge<R> + Copy,
+        F: Fn(Self::Output, Self::Output) -> R,
+        R: FromPrimitive,
+    {
+        polyline(flatten(self, tolerance, &metric))
+    }
+    /// Reparameterize the curve by its arc length.
+    ///
+    /// The returned curve has the domain `[0.0, total_length]`, such that equal steps of the input
+    /// result in equal distances travelled along the curve. The distance between elements is
+    /// measured with their [`QuasiMetric`] implementation. The length of the curve is approximated
+    /// adaptively with the given tolerance, which is the maximal error of the total length.
+    ///
+    /// If the elements do not implement [`QuasiMetric`], consider using [`arc_length_with()`] instead.
+    ///
+    /// [`QuasiMetric`]: crate::QuasiMetric
+    /// [`arc_length_with()`]: Curve::arc_length_with()
+    #[cfg(feature = "std")]
+    fn arc_length(self, tolerance: R) -> ArcLength<Self, R>
+    where
+        Self: Sized,
+        Self::Output: QuasiMetric<R> + Copy,
+        R: FromPrimitive,
+    {
+        ArcLength::new(self, tolerance, QuasiMetric::distance)
+    }
+    /// Reparameterize the curve by its arc length with the given metric.
+    ///
+    /// The returned curve has the domain `[0.0, total_length]`, such that equal steps of the input
+    /// result in equal distances travelled along the curve. The length of the curve is approximated
+    /// adaptively with the given tolerance, which is the maximal error of the total length.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "linear", doc = "```rust")]
+    #[cfg_attr(not(feature = "linear"), doc = "```ignore")]
+    /// # use enterpolation::{linear::{Linear, LinearError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let linear = Linear::builder()
+    ///                 .elements([0.0,1.0,4.0])
+    ///                 .knots([0.0,1.0,2.0])
+    ///                 .build()?
+    ///                 .arc_length_with(1e-6, |a: f64, b: f64| (a - b).abs());
+    /// assert_f64_near!(linear.total_length(), 4.0);
+    /// assert_f64_near!(linear.parameter_at_length(2.5), 1.5);
+    /// let results = [0.0,1.0,2.0,3.0,4.0];
+    /// for (value,result) in linear.take(results.len()).zip(results.iter().copied()){
+    ///     assert_f64_near!(value, result);
+    /// }
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    fn arc_length_with<F>(self, tolerance: R, metric: F) -> ArcLength<Self, R>
+    where
+        Self: Sized,
+        Self::Output: Copy,
+        F: Fn(Self::Output, Self::Output) -> R,
+        R: FromPrimitive,
+    {
+        ArcLength::new(self, tolerance, metric)
+    }
+    /// Approximate the curve by a Chebyshev series of the given degree.
+    ///
+    /// The returned series interpolates the curve at `degree + 1` Chebyshev points and is cheap to evaluate.
+    /// Its estimated maximal error is measured with the [`QuasiMetric`] implementation of the elements
+    /// and can be accessed with [`error()`].
+    ///
+    /// If the elements do not implement [`QuasiMetric`], consider using [`approximate_chebyshev_with()`] instead.
+    ///
+    /// [`QuasiMetric`]: crate::QuasiMetric
+    /// [`error()`]: crate::polynomial::ChebyshevSeries::error()
+    /// [`approximate_chebyshev_with()`]: Curve::approximate_chebyshev_with()
+    #[cfg(all(feature = "std", feature = "polynomial"))]
+    fn approximate_chebyshev(&self, degree: usize) -> ChebyshevSeries<R, Self::Output>
+    where
+        Self::Output: QuasiMetric<R>
+            + Add<Output = Self::Output>
+            + Sub<Output = Self::Output>
+            + Mul<R, Output = Self::Output>
+            + Copy,
+        R: FromPrimitive,
+    {
+        ChebyshevSeries::new(self, degree, QuasiMetric::distance)
+    }
+    /// Approximate the curve by a Chebyshev series of the given degree, measuring its error with the given metric.
+    ///
+    /// The returned series interpolates the curve at `degree + 1` Chebyshev points and is cheap to evaluate.
+    /// Its estimated maximal error can be accessed with [`error()`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{easing::FuncEase, Generator, Curve};
+    /// let expensive = FuncEase::new(|x: f64| x.exp().sin());
+    /// let cheap = expensive.approximate_chebyshev_with(20, |a: f64, b: f64| (a - b).abs());
+    /// assert!(cheap.error() < 1e-10);
+    /// assert!((cheap.gen(0.3) - expensive.gen(0.3)).abs() < 1e-10);
+    /// ```
+    ///
+    /// [`error()`]: crate::polynomial::ChebyshevSeries::error()
+    #[cfg(all(feature = "std", feature = "polynomial"))]
+    fn approximate_chebyshev_with<F>(
+        &self,
+        degree: usize,
+        metric: F,
+    ) -> ChebyshevSeries<R, Self::Output>
+    where
+        Self::Output: Add<Output = Self::Output>
+            + Sub<Output = Self::Output>
+            + Mul<R, Output = Self::Output>
+            + Copy,
+        F: Fn(Self::Output, Self::Output) -> R,
+        R: FromPrimitive,
+    {
+        ChebyshevSeries::new(self, degree, metric)
+    }
+    /// Approximate the curve by a Chebyshev series whose estimated maximal error is below the given tolerance.
+    ///
+    /// The degree of the series is doubled, starting with 8, until the error measured with the [`QuasiMetric`]
+    /// implementation of the elements is small enough or the degree 1024 is reached.
+    ///
+    /// If the elements do not implement [`QuasiMetric`], consider using [`approximate_chebyshev_tolerance_with()`] instead.
+    ///
+    /// [`QuasiMetric`]: crate::QuasiMetric
+    /// [`approximate_chebyshev_tolerance_with()`]: Curve::approximate_chebyshev_tolerance_with()
+    #[cfg(all(feature = "std", feature = "polynomial"))]
+    fn approximate_chebyshev_tolerance(&self, tolerance: R) -> ChebyshevSeries<R, Self::Output>
+    where
+        Self::Output: QuasiMetric<R>
+            + Add<Output = Self::Output>
+            + Sub<Output = Self::Output>
+            + Mul<R, Output = Self::Output>
+            + Copy,
+        R: FromPrimitive,
+    {
+        ChebyshevSeries::with_tolerance(self, tolerance, QuasiMetric::distance)
+    }
+    /// Approximate the curve by a Chebyshev series whose estimated maximal error, measured with the given metric,
+    /// is below the given tolerance.
+    ///
+    /// The degree of the series is doubled, starting with 8, until the error is small enough or the degree 1024 is reached.
+    /// As the error is only estimated, one should check it with [`error()`] if the tolerance is crucial.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{easing::FuncEase, Generator, Curve};
+    /// let expensive = FuncEase::new(|x: f64| (10.0 * x).cos());
+    /// let cheap = expensive.approximate_chebyshev_tolerance_with(1e-8, |a: f64, b: f64| (a - b).abs());
+    /// assert!(cheap.error() <= 1e-8);
+    /// assert!((cheap.gen(0.7) - expensive.gen(0.7)).abs() < 1e-8);
+    /// ```
+    ///
+    /// [`error()`]: crate::polynomial::ChebyshevSeries::error()
+    #[cfg(all(feature = "std", feature = "polynomial"))]
+    fn approximate_chebyshev_tolerance_with<F>(
+        &self,
+        tolerance: R,
+        metric: F,
+    ) -> ChebyshevSeries<R, Self::Output>
+    where
+        Self::Output: Add<Output = Self::Output>
+            + Sub<Output = Self::Output>
+            + Mul<R, Output = Self::Output>
+            + Copy,
+        F: Fn(Self::Output, Self::Output) -> R,
+        R: FromPrimitive,
+    {
+        ChebyshevSeries::with_tolerance(self, tolerance, metric)
+    }
 }
 
 //Make references of curves also curves
@@ -652,16 +1123,16 @@ mod test {
     fn stepper() {
         let mut stepper = Stepper::normalized(11);
         let res = [0.0, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0];
-        for i in 0..=10 {
+        for value in res {
             let val = stepper.next().unwrap();
-            assert_f64_near!(val, res[i]);
+            assert_f64_near!(val, value);
         }
 
         let mut stepper = Stepper::new(5, 3.0, 5.0);
         let res = [3.0, 3.5, 4.0, 4.5, 5.0];
-        for i in 0..5 {
+        for value in res {
             let val = stepper.next().unwrap();
-            assert_f64_near!(val, res[i]);
+            assert_f64_near!(val, value);
         }
     }
 }
@@ -0,0 +1,382 @@
+//! Intersections of piecewise polynomial and rational curves.
+
+use super::bounds::{roots, Components, Piece};
+use num_traits::real::Real;
+use num_traits::FromPrimitive;
+
+/// Maximal number of times the curves get halved in total while searching for an intersection.
+const MAX_DEPTH: usize = 96;
+
+/// A piece of a curve with its homogeneous elements given by their components.
+///
+/// The last coordinate of every element is its weight, with which the other coordinates are multiplied.
+#[derive(Debug, Clone)]
+struct Segment<R> {
+    interval: [R; 2],
+    points: Vec<Vec<R>>,
+}
+
+impl<R> Segment<R>
+where
+    R: Real,
+{
+    /// Creates the segment of the given piece.
+    fn new<T>(piece: &Piece<T, R>) -> Self
+    where
+        T: Components<R>,
+    {
+        let points = piece
+            .elements
+            .iter()
+            .enumerate()
+            .map(|(i, element)| {
+                let mut point: Vec<R> = (0..element.dimension())
+                    .map(|axis| element.component(axis))
+                    .collect();
+                point.push(
+                    piece
+                        .weights
+                        .as_ref()
+                        .map_or(R::one(), |weights| weights[i]),
+                );
+                point
+            })
+            .collect();
+        Segment {
+            interval: piece.interval,
+            points,
+        }
+    }
+
+    /// Returns the number of components of the points.
+    fn dimension(&self) -> usize {
+        self.points[0].len() - 1
+    }
+
+    /// Returns the minimal and maximal corner of the box containing the projected elements,
+    /// which also contains the segment, as long as all weights are positive.
+    fn bounds(&self) -> (Vec<R>, Vec<R>) {
+        let dimension = self.dimension();
+        let mut min = vec![R::max_value(); dimension];
+        let mut max = vec![R::min_value(); dimension];
+        for point in self.points.iter() {
+            for axis in 0..dimension {
+                let value = point[axis] / point[dimension];
+                min[axis] = min[axis].min(value);
+                max[axis] = max[axis].max(value);
+            }
+        }
+        (min, max)
+    }
+
+    /// Returns the projected point of the segment at the given parameter in [0.0,1.0].
+    fn point(&self, parameter: R) -> Vec<R> {
+        let mut workspace = self.points.clone();
+        for k in 1..workspace.len() {
+            for i in 0..workspace.len() - k {
+                let next = workspace[i + 1].clone();
+                for (value, other) in workspace[i].iter_mut().zip(next) {
+                    *value = *value * (R::one() - parameter) + other * parameter;
+                }
+            }
+        }
+        let mut point = workspace.swap_remove(0);
+        let weight = point.pop().unwrap();
+        point.into_iter().map(|value| value / weight).collect()
+    }
+
+    /// Splits the segment into its halves with de Casteljau's algorithm.
+    fn halve(&self) -> (Self, Self) {
+        let two = R::one() + R::one();
+        let [start, end] = self.interval;
+        let middle = (start + end) / two;
+        let mut workspace = self.points.clone();
+        let len = workspace.len();
+        let mut left = Vec::with_capacity(len);
+        let mut right = Vec::with_capacity(len);
+        left.push(workspace[0].clone());
+        right.push(workspace[len - 1].clone());
+        for k in 1..len {
+            for i in 0..len - k {
+                let next = workspace[i + 1].clone();
+                for (value, other) in workspace[i].iter_mut().zip(next) {
+                    *value = (*value + other) / two;
+                }
+            }
+            left.push(workspace[0].clone());
+            right.push(workspace[len - k - 1].clone());
+        }
+        right.reverse();
+        (
+            Segment {
+                interval: [start, middle],
+                points: left,
+            },
+            Segment {
+                interval: [middle, end],
+                points: right,
+            },
+        )
+    }
+}
+
+/// Returns the largest extent of the box given by its corners.
+fn extent<R>(min: &[R], max: &[R]) -> R
+where
+    R: Real,
+{
+    min.iter()
+        .zip(max)
+        .fold(R::zero(), |extent, (&low, &high)| extent.max(high - low))
+}
+
+/// Returns true if the given intervals overlap or touch.
+fn touches<R>(first: [R; 2], second: [R; 2]) -> bool
+where
+    R: Real,
+{
+    first[0] <= second[1] && second[0] <= first[1]
+}
+
+/// Returns true if the boxes given by their corners are closer than the tolerance on every axis.
+fn near<R>(first: &[Vec<R>; 2], second: &[Vec<R>; 2], tolerance: R) -> bool
+where
+    R: Real,
+{
+    (0..first[0].len()).all(|axis| {
+        first[0][axis] <= second[1][axis] + tolerance
+            && second[0][axis] <= first[1][axis] + tolerance
+    })
+}
+
+/// Parts of two curves which are close to each other.
+#[derive(Debug, Clone)]
+struct Cell<R> {
+    intervals: [[R; 2]; 2],
+    boxes: [[Vec<R>; 2]; 2],
+}
+
+impl<R> Cell<R>
+where
+    R: Real,
+{
+    /// Returns true if both cells belong to the same intersection,
+    /// that is if the parts of each curve are neighbours in either the domain or space.
+    fn touches(&self, other: &Self, tolerance: R) -> bool {
+        (0..2).all(|i| {
+            touches(self.intervals[i], other.intervals[i])
+                || near(&self.boxes[i], &other.boxes[i], tolerance)
+        })
+    }
+
+    /// Extends the cell such it contains the given cell.
+    fn merge(&mut self, other: Cell<R>) {
+        for i in 0..2 {
+            let interval = &mut self.intervals[i];
+            *interval = [
+                interval[0].min(other.intervals[i][0]),
+                interval[1].max(other.intervals[i][1]),
+            ];
+            let [min, max] = &mut self.boxes[i];
+            for axis in 0..min.len() {
+                min[axis] = min[axis].min(other.boxes[i][0][axis]);
+                max[axis] = max[axis].max(other.boxes[i][1][axis]);
+            }
+        }
+    }
+}
+
+/// Returns the pairs of parameters at which the curves consisting of the given pieces intersect.
+///
+/// Pairs of segments are halved as long as the boxes containing their elements overlap
+/// and are larger than the tolerance. Neighbouring pairs which are left are merged into one intersection.
+pub(crate) fn intersections<T, R>(
+    first: &[Piece<T, R>],
+    second: &[Piece<T, R>],
+    tolerance: R,
+) -> Vec<(R, R)>
+where
+    T: Components<R>,
+    R: Real + FromPrimitive,
+{
+    let mut stack: Vec<(Segment<R>, Segment<R>, usize)> = Vec::new();
+    for piece in first {
+        for other in second {
+            stack.push((Segment::new(piece), Segment::new(other), 0));
+        }
+    }
+    let mut cells: Vec<Cell<R>> = Vec::new();
+    while let Some((segment, other, depth)) = stack.pop() {
+        let (min, max) = segment.bounds();
+        let (other_min, other_max) = other.bounds();
+        let separated = (0..min.len()).any(|axis| {
+            min[axis] > other_max[axis] + tolerance || other_min[axis] > max[axis] + tolerance
+        });
+        if separated {
+            continue;
+        }
+        let size = extent(&min, &max);
+        let other_size = extent(&other_min, &other_max);
+        if (size <= tolerance && other_size <= tolerance) || depth >= MAX_DEPTH {
+            cells.push(Cell {
+                intervals: [segment.interval, other.interval],
+                boxes: [[min, max], [other_min, other_max]],
+            });
+            continue;
+        }
+        if size >= other_size {
+            let (left, right) = segment.halve();
+            stack.push((right, other.clone(), depth + 1));
+            stack.push((left, other, depth + 1));
+        } else {
+            let (left, right) = other.halve();
+            stack.push((segment.clone(), right, depth + 1));
+            stack.push((segment, left, depth + 1));
+        }
+    }
+    // merge neighbouring cells, which belong to the same intersection
+    let mut clusters: Vec<Cell<R>> = Vec::new();
+    for cell in cells {
+        let mut cluster = cell;
+        // merging may connect clusters which were separated before
+        while let Some(index) = clusters
+            .iter()
+            .position(|other| other.touches(&cluster, tolerance))
+        {
+            cluster.merge(clusters.swap_remove(index));
+        }
+        clusters.push(cluster);
+    }
+    let two = R::one() + R::one();
+    let mut intersections: Vec<(R, R)> = clusters
+        .into_iter()
+        .map(|Cell { intervals, .. }| {
+            let [first, second] = intervals;
+            ((first[0] + first[1]) / two, (second[0] + second[1]) / two)
+        })
+        .collect();
+    intersections.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+    intersections
+}
+
+/// Returns the parameters of the curve consisting of the given pieces and of the line
+/// at which they intersect.
+///
+/// Only the first two components are used. The intersections are the roots of the
+/// signed distance of the curve to the line, which is again a (rational) bezier curve.
+/// If a piece lies on the line, only its borders are returned.
+///
+/// # Panics
+///
+/// Panics if the elements have less than two components.
+pub(crate) fn line_intersections<T, R>(
+    pieces: &[Piece<T, R>],
+    origin: T,
+    direction: T,
+) -> Vec<(R, R)>
+where
+    T: Components<R>,
+    R: Real + FromPrimitive,
+{
+    let origin = [origin.component(0), origin.component(1)];
+    let direction = [direction.component(0), direction.component(1)];
+    let length = direction[0] * direction[0] + direction[1] * direction[1];
+    let mut intersections: Vec<(R, R)> = Vec::new();
+    for piece in pieces {
+        let segment = Segment::new(piece);
+        let dimension = segment.dimension();
+        let distances: Vec<R> = segment
+            .points
+            .iter()
+            .map(|point| {
+                let weight = point[dimension];
+                direction[0] * (point[1] - origin[1] * weight)
+                    - direction[1] * (point[0] - origin[0] * weight)
+            })
+            .collect();
+        let mut parameters = Vec::new();
+        if distances[0].is_zero() {
+            parameters.push(R::zero());
+        }
+        let last = distances[distances.len() - 1];
+        roots(distances, [R::zero(), R::one()], 0, &mut parameters);
+        if last.is_zero() {
+            parameters.push(R::one());
+        }
+        let [start, end] = segment.interval;
+        for parameter in parameters {
+            let scalar = start + (end - start) * parameter;
+            // borders of consecutive pieces coincide
+            if intersections.last().map_or(false, |last| last.0 >= scalar) {
+                continue;
+            }
+            let point = segment.point(parameter);
+            let along = ((point[0] - origin[0]) * direction[0]
+                + (point[1] - origin[1]) * direction[1])
+                / length;
+            intersections.push((scalar, along));
+        }
+    }
+    intersections
+}
+
+/// Returns the parameters of the curve consisting of the given pieces and of the ray
+/// at which they intersect.
+///
+/// This are the intersections with the line whose parameter of the line is not negative.
+pub(crate) fn ray_intersections<T, R>(
+    pieces: &[Piece<T, R>],
+    origin: T,
+    direction: T,
+) -> Vec<(R, R)>
+where
+    T: Components<R>,
+    R: Real + FromPrimitive,
+{
+    line_intersections(pieces, origin, direction)
+        .into_iter()
+        .filter(|&(_, along)| along >= R::zero())
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn curves() {
+        // the parabolas y = 4x(1-x) and y = 1 - 4x(1-x) intersect at x = 1/2 ± sqrt(2)/4
+        let first = Piece::new([0.0, 1.0], vec![[0.0, 0.0], [0.5, 2.0], [1.0, 0.0]]);
+        let second = Piece::new([0.0, 1.0], vec![[0.0, 1.0], [0.5, -1.0], [1.0, 1.0]]);
+        let found = intersections(&[first], &[second], 1e-9);
+        assert_eq!(found.len(), 2);
+        let offset = 2.0_f64.sqrt() / 4.0;
+        for ((t, s), expected) in found.into_iter().zip([0.5 - offset, 0.5 + offset]) {
+            assert!((t - expected).abs() < 1e-8);
+            assert!((s - expected).abs() < 1e-8);
+        }
+    }
+
+    #[test]
+    fn disjoint() {
+        let first = Piece::new([0.0, 1.0], vec![[0.0, 0.0], [0.5, 2.0], [1.0, 0.0]]);
+        let second = Piece::new([0.0, 1.0], vec![[0.0, 1.5], [1.0, 1.5]]);
+        assert!(intersections(&[first], &[second], 1e-9).is_empty());
+    }
+
+    #[test]
+    fn lines() {
+        let piece = Piece::new([2.0, 4.0], vec![[0.0, 0.0], [0.5, 2.0], [1.0, 0.0]]);
+        // the line y = 0.75, given by the point (0.0, 0.75) and the direction (2.0, 0.0)
+        let found = line_intersections(core::slice::from_ref(&piece), [0.0, 0.75], [2.0, 0.0]);
+        assert_eq!(found.len(), 2);
+        assert_f64_near!(found[0].0, 2.5);
+        assert_f64_near!(found[0].1, 0.125);
+        assert_f64_near!(found[1].0, 3.5);
+        assert_f64_near!(found[1].1, 0.375);
+        // the ray starting at (0.5, 0.75) only intersects once
+        let found = ray_intersections(&[piece], [0.5, 0.75], [2.0, 0.0]);
+        assert_eq!(found.len(), 1);
+        assert_f64_near!(found[0].0, 3.5);
+    }
+}
@@ -0,0 +1,374 @@
+//! Inverse evaluation of monotone real-valued curves.
+
+use super::{Curve, Generator};
+use crate::easing::Identity;
+use crate::utils::lerp;
+use core::fmt;
+use num_traits::real::Real;
+use num_traits::FromPrimitive;
+
+#[cfg(feature = "std")]
+use std::error::Error;
+
+/// Number of equidistant samples used to check for monotonicity and to find a first bracket.
+const SAMPLES: usize = 64;
+/// Maximal number of iterations used to refine a bracket.
+const MAX_ITERATIONS: usize = 128;
+
+/// Errors which could occur when inverting a curve.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum InverseError {
+    /// Error returned if the curve is not monotonic.
+    NotMonotonic(NotMonotonic),
+    /// Error returned if the value to solve for is not reached by the curve.
+    OutOfRange(OutOfRange),
+}
+
+impl fmt::Display for InverseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InverseError::NotMonotonic(inner) => inner.fmt(f),
+            InverseError::OutOfRange(inner) => inner.fmt(f),
+        }
+    }
+}
+
+impl From<NotMonotonic> for InverseError {
+    fn from(from: NotMonotonic) -> Self {
+        InverseError::NotMonotonic(from)
+    }
+}
+
+impl From<OutOfRange> for InverseError {
+    fn from(from: OutOfRange) -> Self {
+        InverseError::OutOfRange(from)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for InverseError {}
+
+/// Error returned if a curve is not monotonic.
+///
+/// Monotonicity is checked with equidistant samples of the curve.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct NotMonotonic {
+    sample: usize,
+}
+
+impl NotMonotonic {
+    /// Create a new error in which from sample to sample + 1 the curve changed its direction.
+    pub fn new(sample: usize) -> Self {
+        NotMonotonic { sample }
+    }
+}
+
+impl fmt::Display for NotMonotonic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "The curve is not monotonic. From sample {} to {} the curve changed its direction.",
+            self.sample,
+            self.sample + 1
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for NotMonotonic {}
+
+/// Error returned if a value is not reached by a curve within its domain.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct OutOfRange {}
+
+impl OutOfRange {
+    /// Create a new error.
+    pub fn new() -> Self {
+        OutOfRange {}
+    }
+}
+
+impl Default for OutOfRange {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for OutOfRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "The given value is not reached by the curve within its domain."
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for OutOfRange {}
+
+/// Inverse of a monotonic real-valued curve.
+///
+/// The domain of this curve is the range of the inverted curve. Inputs outside of this
+/// domain are clamped. Every output is calculated by bracketing combined with Newton iterations,
+/// using the derivative if one was given and secants otherwise.
+///
+/// This struct is created by [`Curve::invert()`] and [`Curve::invert_with_derivative()`].
+/// See their documentation for more information. The type `D` is the type of the derivative,
+/// which defaults to [`Identity`] if no derivative is given.
+///
+/// [`Curve::invert()`]: crate::Curve::invert()
+/// [`Curve::invert_with_derivative()`]: crate::Curve::invert_with_derivative()
+/// [`Identity`]: crate::Identity
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Inverse<C, D = Identity> {
+    curve: C,
+    derivative: Option<D>,
+}
+
+impl<C, D> Inverse<C, D> {
+    /// Create the inverse of the given curve, using the derivative of the curve if one is given.
+    ///
+    /// # Errors
+    ///
+    /// [`NotMonotonic`] if the curve is not monotonic.
+    pub fn new<R>(curve: C, derivative: Option<D>) -> Result<Self, NotMonotonic>
+    where
+        C: Curve<R, Output = R>,
+        D: Generator<R, Output = R>,
+        R: Real + FromPrimitive,
+    {
+        samples(&curve, |_, _| {})?;
+        Ok(Inverse { curve, derivative })
+    }
+
+    /// Returns the inverted curve.
+    pub fn into_inner(self) -> C {
+        self.curve
+    }
+}
+
+impl<C, D, R> Generator<R> for Inverse<C, D>
+where
+    C: Curve<R, Output = R>,
+    D: Generator<R, Output = R>,
+    R: Real + FromPrimitive,
+{
+    type Output = R;
+    fn gen(&self, input: R) -> R {
+        let [start, end] = self.curve.domain();
+        let [min, max] = self.domain();
+        let value = input.max(min).min(max);
+        let ascending = self.curve.gen(start) <= self.curve.gen(end);
+        solve(
+            &self.curve,
+            self.derivative.as_ref(),
+            value,
+            ascending,
+            [start, end],
+        )
+    }
+}
+
+impl<C, D, R> Curve<R> for Inverse<C, D>
+where
+    C: Curve<R, Output = R>,
+    D: Generator<R, Output = R>,
+    R: Real + FromPrimitive,
+{
+    fn domain(&self) -> [R; 2] {
+        let [start, end] = self.curve.domain();
+        let first = self.curve.gen(start);
+        let last = self.curve.gen(end);
+        [first.min(last), first.max(last)]
+    }
+}
+
+/// Solve the given monotonic curve for the given value.
+///
+/// Uses equidistant samples to check for monotonicity and to find a small bracket
+/// before refining it with [`solve()`].
+pub(crate) fn solve_for<C, D, R>(
+    curve: &C,
+    derivative: Option<&D>,
+    value: R,
+) -> Result<R, InverseError>
+where
+    C: Curve<R, Output = R>,
+    D: Generator<R, Output = R>,
+    R: Real + FromPrimitive,
+{
+    let mut bracket = None;
+    let ascending = samples(curve, |[left, right], [first, second]| {
+        let inside = (first <= value && value <= second) || (second <= value && value <= first);
+        if bracket.is_none() && inside {
+            bracket = Some([left, right]);
+        }
+    })?;
+    let bracket = bracket.ok_or_else(OutOfRange::new)?;
+    Ok(solve(curve, derivative, value, ascending, bracket))
+}
+
+/// Samples the curve equidistantly and checks if it is monotonic.
+///
+/// The given function is called for every pair of neighboring samples with their parameters and values.
+/// Returns true if the curve is ascending.
+fn samples<C, R, F>(curve: &C, mut func: F) -> Result<bool, NotMonotonic>
+where
+    C: Curve<R, Output = R>,
+    R: Real + FromPrimitive,
+    F: FnMut([R; 2], [R; 2]),
+{
+    let [start, end] = curve.domain();
+    let ascending = curve.gen(start) <= curve.gen(end);
+    let samples = R::from_usize(SAMPLES).unwrap();
+    let mut last = (start, curve.gen(start));
+    for i in 1..=SAMPLES {
+        let parameter = lerp(start, end, R::from_usize(i).unwrap() / samples);
+        let value = curve.gen(parameter);
+        if (ascending && value < last.1) || (!ascending && value > last.1) {
+            return Err(NotMonotonic::new(i - 1));
+        }
+        func([last.0, parameter], [last.1, value]);
+        last = (parameter, value);
+    }
+    Ok(ascending)
+}
+
+/// Refine the given bracket, which has to contain a solution, until the solution is found.
+///
+/// Every step tries a Newton step with the derivative if given or the secant of the bracket otherwise.
+/// If the step leaves the bracket or the bracket does not shrink fast enough, bisection is used instead.
+fn solve<C, D, R>(
+    curve: &C,
+    derivative: Option<&D>,
+    value: R,
+    ascending: bool,
+    bracket: [R; 2],
+) -> R
+where
+    C: Curve<R, Output = R>,
+    D: Generator<R, Output = R>,
+    R: Real + FromPrimitive,
+{
+    let two = R::one() + R::one();
+    // the function of which we search the root, which is ascending
+    let func = |parameter: R| {
+        let diff = curve.gen(parameter) - value;
+        if ascending {
+            diff
+        } else {
+            -diff
+        }
+    };
+    let [mut low, mut high] = bracket;
+    let mut low_value = func(low);
+    let mut high_value = func(high);
+    if low_value >= R::zero() {
+        return low;
+    }
+    if high_value <= R::zero() {
+        return high;
+    }
+    let mut current = (low, low_value);
+    // width of the bracket before the last step
+    let mut width = (high - low) * two;
+    for _ in 0..MAX_ITERATIONS {
+        let (parameter, current_value) = current;
+        let step = match derivative {
+            Some(derivative) => {
+                let slope = if ascending {
+                    derivative.gen(parameter)
+                } else {
+                    -derivative.gen(parameter)
+                };
+                parameter - current_value / slope
+            }
+            None => low - low_value * (high - low) / (high_value - low_value),
+        };
+        // use bisection if the step is not useful or the bracket did not shrink fast enough
+        let shrunk = (high - low) * two <= width;
+        width = high - low;
+        let candidate = if shrunk && step > low && step < high {
+            step
+        } else {
+            (low + high) / two
+        };
+        let candidate_value = func(candidate);
+        let converged =
+            (candidate - parameter).abs() <= R::epsilon() * (candidate.abs() + R::one());
+        if candidate_value.is_zero() || converged {
+            return candidate;
+        }
+        if candidate_value < R::zero() {
+            low = candidate;
+            low_value = candidate_value;
+        } else {
+            high = candidate;
+            high_value = candidate_value;
+        }
+        current = (candidate, candidate_value);
+        if high - low <= R::epsilon() * (low.abs() + high.abs()) {
+            break;
+        }
+    }
+    if -low_value < high_value {
+        low
+    } else {
+        high
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::InverseError;
+    use crate::easing::{smoothstep, FuncEase, Identity};
+    use crate::{Curve, Generator};
+
+    #[test]
+    fn ascending() {
+        let curve = FuncEase::new(|x: f64| x * x * x);
+        for i in 0..=20 {
+            let value = i as f64 / 20.0;
+            let parameter = curve.solve_for(value).unwrap();
+            assert!((parameter - value.cbrt()).abs() < 1e-10);
+        }
+        let inverse = curve
+            .invert_with_derivative(FuncEase::new(|x: f64| 3.0 * x * x))
+            .unwrap();
+        assert_eq!(inverse.domain(), [0.0, 1.0]);
+        for i in 0..=20 {
+            let value = i as f64 / 20.0;
+            assert!((inverse.gen(value) - value.cbrt()).abs() < 1e-10);
+        }
+        assert!((inverse.gen(2.0) - 1.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn descending() {
+        let curve = FuncEase::new(|x: f64| 1.0 - smoothstep(x));
+        let inverse = curve.invert().unwrap();
+        for i in 0..=20 {
+            let x = i as f64 / 20.0;
+            assert!((inverse.gen(curve.gen(x)) - x).abs() < 1e-10);
+            assert!((curve.solve_for(curve.gen(x)).unwrap() - x).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn errors() {
+        let curve = FuncEase::new(|x: f64| (x - 0.5) * (x - 0.5));
+        assert!(matches!(
+            curve.solve_for(0.1),
+            Err(InverseError::NotMonotonic(_))
+        ));
+        assert!(curve.invert().is_err());
+        assert!(matches!(
+            Identity::new().solve_for(2.0),
+            Err(InverseError::OutOfRange(_))
+        ));
+    }
+}
@@ -573,6 +573,12 @@ impl<R, const N: usize> ConstEquidistant<R, N> {
     }
 }
 
+impl<R, const N: usize> Default for ConstEquidistant<R, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<R, const N: usize> Generator<usize> for ConstEquidistant<R, N>
 where
     R: Real + FromPrimitive,
@@ -713,3 +719,85 @@ where
         (min_index, max_index, factor)
     }
 }
+
+/// Struct used as a generator for Chebyshev points.
+/// Acts like an array of knots.
+///
+/// The points are the extrema of the Chebyshev polynomials, also known as Chebyshev points of the
+/// second kind, and include the borders of the domain. They cluster towards the borders, which
+/// makes them the first choice as knots of polynomial interpolations with high degree.
+///
+/// # Examples
+///
+/// ```
+/// # use enterpolation::{Chebyshev, Generator, DiscreteGenerator};
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// let chebyshev = Chebyshev::new(5, -1.0, 1.0);
+/// let expected = [-1.0, -0.5f64.sqrt(), 0.0, 0.5f64.sqrt(), 1.0];
+/// for (knot, result) in chebyshev.iter().zip(expected) {
+///     assert_f64_near!(knot, result);
+/// }
+/// ```
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Chebyshev<R = f64> {
+    len: usize,
+    start: R,
+    end: R,
+}
+
+impl<R> Chebyshev<R>
+where
+    R: Real + FromPrimitive,
+{
+    /// Create a generator for `len` Chebyshev points in [0.0,1.0].
+    pub fn normalized(len: usize) -> Self {
+        Chebyshev {
+            len,
+            start: R::zero(),
+            end: R::one(),
+        }
+    }
+
+    /// Create a generator for `len` Chebyshev points from `start` to `end`.
+    pub fn new(len: usize, start: R, end: R) -> Self {
+        Chebyshev { len, start, end }
+    }
+}
+
+impl<R> Generator<usize> for Chebyshev<R>
+where
+    R: Real + FromPrimitive,
+{
+    type Output = R;
+    fn gen(&self, input: usize) -> R {
+        let two = R::one() + R::one();
+        if input == 0 && self.len > 1 {
+            return self.start;
+        }
+        if input + 1 == self.len && self.len > 1 {
+            return self.end;
+        }
+        let middle = (self.start + self.end) / two;
+        if self.len < 2 {
+            return middle;
+        }
+        // the sine is used instead of the cosine to get symmetric points
+        let last = R::from_usize(self.len - 1).unwrap();
+        let angle = R::from_f64(core::f64::consts::PI).unwrap()
+            * (R::from_usize(2 * input).unwrap() - last)
+            / (two * last);
+        middle + angle.sin() * (self.end - self.start) / two
+    }
+}
+
+impl<R> DiscreteGenerator for Chebyshev<R>
+where
+    R: Real + FromPrimitive,
+{
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<R> SortedGenerator for Chebyshev<R> where R: Real + FromPrimitive {}
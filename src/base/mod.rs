@@ -1,5 +1,15 @@
 mod adaptors;
+#[cfg(feature = "std")]
+mod arclength;
+#[cfg(all(feature = "std", any(feature = "bezier", feature = "bspline")))]
+mod bounds;
+mod closest;
+#[cfg(feature = "std")]
+mod flatten;
 mod generator;
+#[cfg(all(feature = "std", any(feature = "bezier", feature = "bspline")))]
+mod intersection;
+mod inverse;
 mod list;
 mod space;
 
@@ -7,11 +17,27 @@ mod space;
 #[allow(unreachable_pub)]
 pub use adaptors::{Clamp, Composite, Repeat, Slice, Stack, TransformInput, Wrap};
 #[allow(unreachable_pub)]
+#[cfg(feature = "std")]
+pub use arclength::ArcLength;
+#[cfg(all(feature = "std", any(feature = "bezier", feature = "bspline")))]
+pub(crate) use bounds::{bounds, extrema, Piece};
+#[allow(unreachable_pub)]
+#[cfg(all(feature = "std", any(feature = "bezier", feature = "bspline")))]
+pub use bounds::{Components, Extrema};
+#[cfg(all(feature = "std", feature = "bezier", feature = "linear"))]
+pub(crate) use flatten::polyline;
+#[cfg(all(feature = "std", feature = "bezier"))]
+pub(crate) use flatten::MAX_DEPTH;
+#[allow(unreachable_pub)]
 pub use generator::{
     ConstDiscreteGenerator, Curve, DiscreteGenerator, Extract, Generator, Stepper, Take,
 };
+#[cfg(all(feature = "std", any(feature = "bezier", feature = "bspline")))]
+pub(crate) use intersection::{intersections, line_intersections, ray_intersections};
+#[allow(unreachable_pub)]
+pub use inverse::{Inverse, InverseError, NotMonotonic, OutOfRange};
 #[allow(unreachable_pub)]
-pub use list::{ConstEquidistant, Equidistant, NotSorted, Sorted, SortedGenerator};
+pub use list::{Chebyshev, ConstEquidistant, Equidistant, NotSorted, Sorted, SortedGenerator};
 #[allow(unreachable_pub)]
 #[cfg(feature = "std")]
 pub use space::DynSpace;
@@ -0,0 +1,193 @@
+//! Exact bounds and extrema of bezier curves.
+
+use super::Bezier;
+use crate::base::{bounds, extrema, Piece};
+use crate::weights::{Homogeneous, Weighted};
+use crate::{Components, DiscreteGenerator, Extrema, Space};
+use num_traits::real::Real;
+use num_traits::FromPrimitive;
+use topology_traits::Merge;
+
+impl<R, E, S> Bezier<R, E, S>
+where
+    E: DiscreteGenerator,
+    E::Output: Merge<R> + Copy,
+    S: Space<E::Output>,
+    R: Real + FromPrimitive,
+{
+    /// Returns the smallest axis-aligned box containing the curve in its domain,
+    /// given by its minimal and maximal corner.
+    ///
+    /// In contrast to the box given by the elements, this box is tight. For every component,
+    /// the roots of the derivative are searched and the curve is evaluated at them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bezier::{Bezier, BezierError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BezierError> {
+    /// let bezier = Bezier::builder()
+    ///     .elements([0.0,2.0,0.0])
+    ///     .normalized::<f64>()
+    ///     .constant()
+    ///     .build()?;
+    /// let [min, max] = bezier.bounds();
+    /// assert_f64_near!(min, 0.0);
+    /// // the curve only reaches half of its middle element
+    /// assert_f64_near!(max, 1.0);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn bounds(&self) -> [E::Output; 2]
+    where
+        E::Output: Components<R>,
+    {
+        bounds(&[self.piece()])
+    }
+
+    /// Returns the curve as piece.
+    pub(super) fn piece(&self) -> Piece<E::Output, R> {
+        Piece::new([R::zero(), R::one()], self.elements.iter().collect())
+    }
+}
+
+impl<R, E, S> Bezier<R, E, S>
+where
+    E: DiscreteGenerator<Output = R>,
+    S: Space<R>,
+    R: Real + FromPrimitive,
+{
+    /// Returns the local minima and maxima of the scalar curve in its domain.
+    ///
+    /// The extrema are found at the roots of the derivative and the borders of the domain.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bezier::{Bezier, BezierError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BezierError> {
+    /// let bezier = Bezier::builder()
+    ///     .elements([0.0,3.0,-3.0,0.0])
+    ///     .normalized::<f64>()
+    ///     .constant()
+    ///     .build()?;
+    /// let extrema = bezier.extrema();
+    /// // the start is a minimum and the end a maximum
+    /// assert_eq!(extrema.minima().len(), 2);
+    /// assert_eq!(extrema.maxima().len(), 2);
+    /// let (parameter, value) = extrema.maxima()[0];
+    /// assert_f64_near!(parameter, (3.0 - 3.0_f64.sqrt()) / 6.0);
+    /// assert_f64_near!(value, bezier.gen(parameter));
+    /// assert_eq!(extrema.maxima()[1], (1.0, 0.0));
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn extrema(&self) -> Extrema<R> {
+        extrema(&[self.piece()])
+    }
+}
+
+impl<R, E, S, T> Weighted<Bezier<R, E, S>>
+where
+    E: DiscreteGenerator<Output = Homogeneous<T, R>>,
+    E::Output: Merge<R> + Copy,
+    S: Space<E::Output>,
+    T: Components<R> + Copy,
+    R: Real + FromPrimitive,
+{
+    /// Returns the smallest axis-aligned box containing the rational curve in its domain,
+    /// given by its minimal and maximal corner.
+    ///
+    /// For every component, the roots of the numerator of the derivative given by the quotient rule are searched.
+    pub fn bounds(&self) -> [T; 2] {
+        bounds(&[self.inner_ref().piece().rational()])
+    }
+}
+
+impl<R, E, S> Weighted<Bezier<R, E, S>>
+where
+    E: DiscreteGenerator<Output = Homogeneous<R, R>>,
+    S: Space<Homogeneous<R, R>>,
+    R: Real + FromPrimitive,
+{
+    /// Returns the local minima and maxima of the scalar rational curve in its domain.
+    pub fn extrema(&self) -> Extrema<R> {
+        extrema(&[self.inner_ref().piece().rational()])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::Bezier;
+    use crate::{Curve, Generator};
+
+    #[test]
+    fn bounds() {
+        let bezier = Bezier::builder()
+            .elements([1.0, 8.0, -6.0, 4.0, 2.0])
+            .normalized::<f64>()
+            .constant()
+            .build()
+            .unwrap();
+        let [min, max] = bezier.bounds();
+        let (low, high) = bezier
+            .take(10001)
+            .fold((f64::MAX, f64::MIN), |(low, high), x| {
+                (low.min(x), high.max(x))
+            });
+        assert!(min <= low && low - min < 1e-6);
+        assert!(high <= max && max - high < 1e-6);
+    }
+
+    #[test]
+    fn extrema() {
+        let bezier = Bezier::builder()
+            .elements([1.0, 8.0, -6.0, 4.0, 2.0])
+            .normalized::<f64>()
+            .constant()
+            .build()
+            .unwrap();
+        let extrema = bezier.extrema();
+        // the curve starts rising and ends falling, such both borders are minima
+        assert_eq!(extrema.minima().len(), 3);
+        assert_eq!(extrema.minima()[0], (0.0, 1.0));
+        assert_eq!(extrema.minima()[2], (1.0, 2.0));
+        assert_eq!(extrema.maxima().len(), 2);
+        for &(parameter, value) in extrema.minima().iter().chain(extrema.maxima()) {
+            assert_f64_near!(bezier.gen(parameter), value);
+            if parameter > 0.0 && parameter < 1.0 {
+                assert!(bezier.gen_with_tangent(parameter)[1].abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn weighted() {
+        let bezier = Bezier::builder()
+            .elements_with_weights([(0.0, 1.0), (5.0, 3.0), (3.0, 0.5), (-2.0, 1.0)])
+            .normalized::<f64>()
+            .constant()
+            .build()
+            .unwrap();
+        let [min, max] = bezier.bounds();
+        let (low, high) = bezier
+            .take(10001)
+            .fold((f64::MAX, f64::MIN), |(low, high), x| {
+                (low.min(x), high.max(x))
+            });
+        assert!(min <= low && low - min < 1e-6);
+        assert!(high <= max && max - high < 1e-6);
+        let extrema = bezier.extrema();
+        assert_eq!(extrema.minima(), &[(0.0, 0.0), (1.0, -2.0)]);
+        assert_eq!(extrema.maxima().len(), 1);
+        let (parameter, value) = extrema.maxima()[0];
+        assert!((value - high).abs() < 1e-6);
+        assert!(bezier.domain()[0] < parameter && parameter < bezier.domain()[1]);
+    }
+}
@@ -0,0 +1,220 @@
+//! Exact conic sections as quadratic rational bezier curves.
+
+use super::error::{BezierError, TooWideArc};
+use super::Bezier;
+use crate::builder::Unknown;
+use crate::weights::{elliptic_arc, Homogeneous, Weighted};
+use crate::ConstSpace;
+use core::ops::{Add, Mul};
+use num_traits::real::Real;
+use num_traits::FromPrimitive;
+
+/// Type alias for quadratic rational bezier curves.
+type ConicBezier<T, R> =
+    Weighted<Bezier<R, [Homogeneous<T, R>; 3], ConstSpace<Homogeneous<T, R>, 3>>>;
+
+impl Bezier<Unknown, Unknown, Unknown> {
+    /// Create the conic section starting at `start`, ending at `end` and being pulled to `shoulder`
+    /// by the given weight.
+    ///
+    /// The curve is the quadratic rational bezier curve with the three points as elements,
+    /// of which only the shoulder is weighted. The tangents at both ends of the curve point to the shoulder.
+    /// The conic section is an ellipse if the weight is less than one, a parabola if it equals one
+    /// and a hyperbola if it is greater than one. The weight should be positive.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use core::ops::{Add, Div, Mul};
+    /// # use enterpolation::{bezier::Bezier, Generator};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # #[derive(Debug, Copy, Clone, Default, PartialEq)]
+    /// # struct Point(f64, f64);
+    /// # impl Add for Point {
+    /// #     type Output = Self;
+    /// #     fn add(self, o: Self) -> Self { Point(self.0 + o.0, self.1 + o.1) }
+    /// # }
+    /// # impl Mul<f64> for Point {
+    /// #     type Output = Self;
+    /// #     fn mul(self, s: f64) -> Self { Point(self.0 * s, self.1 * s) }
+    /// # }
+    /// # impl Div<f64> for Point {
+    /// #     type Output = Self;
+    /// #     fn div(self, s: f64) -> Self { Point(self.0 / s, self.1 / s) }
+    /// # }
+    /// // a quarter of the unit circle
+    /// let arc = Bezier::conic(Point(1.0,0.0), Point(1.0,1.0), Point(0.0,1.0), 0.5_f64.sqrt());
+    /// let Point(x, y) = arc.gen(0.3);
+    /// assert_f64_near!(x * x + y * y, 1.0);
+    /// ```
+    pub fn conic<T, R>(start: T, shoulder: T, end: T, weight: R) -> ConicBezier<T, R>
+    where
+        T: Mul<R, Output = T> + Default + Copy,
+        R: Real + Default,
+    {
+        Weighted::new(Bezier::new_unchecked(
+            [
+                Homogeneous::new(start),
+                Homogeneous::weighted_unchecked(shoulder, weight),
+                Homogeneous::new(end),
+            ],
+            ConstSpace::new(),
+        ))
+    }
+
+    /// Create the elliptic arc `center + x_axis * cos(angle) + y_axis * sin(angle)`
+    /// for all angles from `start` to `end`, given in radians.
+    ///
+    /// The arc is exact, however its speed is not constant. If both axes are orthogonal and of the same length,
+    /// the arc is circular with the length of the axes as radius. As the axes may lie in any plane,
+    /// arcs can be created for vectors of any dimension.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TooWideArc`] if the angles differ by π or more, as a single quadratic rational bezier curve
+    /// can not represent such arcs with positive weights. Use [`BSpline::arc()`] for these arcs,
+    /// which joins multiple bezier curves.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use core::ops::{Add, Div, Mul};
+    /// # use enterpolation::{bezier::{Bezier, BezierError}, Generator};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # #[derive(Debug, Copy, Clone, Default, PartialEq)]
+    /// # struct Point(f64, f64);
+    /// # impl Add for Point {
+    /// #     type Output = Self;
+    /// #     fn add(self, o: Self) -> Self { Point(self.0 + o.0, self.1 + o.1) }
+    /// # }
+    /// # impl Mul<f64> for Point {
+    /// #     type Output = Self;
+    /// #     fn mul(self, s: f64) -> Self { Point(self.0 * s, self.1 * s) }
+    /// # }
+    /// # impl Div<f64> for Point {
+    /// #     type Output = Self;
+    /// #     fn div(self, s: f64) -> Self { Point(self.0 / s, self.1 / s) }
+    /// # }
+    /// # fn main() -> Result<(), BezierError> {
+    /// let (center, x_axis, y_axis) = (Point(0.0,0.0), Point(1.0,0.0), Point(0.0,1.0));
+    /// let arc = Bezier::arc(center, x_axis, y_axis, 0.0, 2.0)?;
+    /// let Point(x, y) = arc.gen(0.5);
+    /// assert_f64_near!(x, 1.0_f64.cos());
+    /// assert_f64_near!(y, 1.0_f64.sin());
+    /// // a half circle is too wide for a single bezier curve
+    /// assert!(Bezier::arc(center, x_axis, y_axis, 0.0, 3.5).is_err());
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`TooWideArc`]: BezierError
+    /// [`BSpline::arc()`]: crate::bspline::BSpline::arc()
+    pub fn arc<T, R>(
+        center: T,
+        x_axis: T,
+        y_axis: T,
+        start: R,
+        end: R,
+    ) -> Result<ConicBezier<T, R>, BezierError>
+    where
+        T: Add<Output = T> + Mul<R, Output = T> + Default + Copy,
+        R: Real + FromPrimitive + Default,
+    {
+        let pi = R::from_f64(core::f64::consts::PI).unwrap();
+        if (end - start).abs() >= pi {
+            return Err(TooWideArc::new().into());
+        }
+        Ok(Weighted::new(Bezier::new_unchecked(
+            elliptic_arc(center, x_axis, y_axis, start, end),
+            ConstSpace::new(),
+        )))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{Bezier, BezierError};
+    use crate::test_util::Point3;
+    use crate::Generator;
+    use core::f64::consts::PI;
+
+    #[test]
+    fn conic() {
+        // a third of the circle with radius 2, whose tangents meet at distance 4 from the center
+        let angle = PI / 3.0;
+        let start = Point3(2.0, 0.0, 0.0);
+        let end = Point3(2.0 * angle.cos(), 2.0 * angle.sin(), 0.0);
+        let shoulder = Point3(2.0, 2.0 * (angle / 2.0).tan(), 0.0);
+        let arc = Bezier::conic(start, shoulder, end, (angle / 2.0).cos());
+        assert_eq!(arc.gen(0.0), start);
+        for i in 0..=10 {
+            assert_f64_near!(arc.gen(i as f64 / 10.0).norm(), 2.0);
+        }
+        // a parabola y = x^2
+        let parabola = Bezier::conic(
+            Point3(-1.0, 1.0, 0.0),
+            Point3(0.0, -1.0, 0.0),
+            Point3(1.0, 1.0, 0.0),
+            1.0,
+        );
+        for i in 0..=10 {
+            let Point3(x, y, _) = parabola.gen(i as f64 / 10.0);
+            assert_f64_near!(y, x * x);
+        }
+    }
+
+    #[test]
+    fn arc() {
+        // a circular arc with radius 3 around (1,1,1) in a tilted plane
+        let center = Point3(1.0, 1.0, 1.0);
+        let x_axis = Point3(3.0, 0.0, 0.0);
+        let y_axis = Point3(0.0, 1.8, 2.4);
+        let arc = Bezier::arc(center, x_axis, y_axis, -1.0, 2.0).unwrap();
+        assert!(
+            (arc.gen(0.0) - (center + x_axis * 1.0_f64.cos() - y_axis * 1.0_f64.sin())).norm()
+                < 1e-12
+        );
+        for i in 0..=10 {
+            assert_f64_near!((arc.gen(i as f64 / 10.0) - center).norm(), 3.0);
+        }
+        // an elliptic arc
+        let ellipse = Bezier::arc(
+            Point3::default(),
+            Point3(2.0, 0.0, 0.0),
+            Point3(0.0, 1.0, 0.0),
+            0.5,
+            -2.5,
+        )
+        .unwrap();
+        for i in 0..=10 {
+            let Point3(x, y, _) = ellipse.gen(i as f64 / 10.0);
+            assert_f64_near!(x * x / 4.0 + y * y, 1.0);
+        }
+    }
+
+    #[test]
+    fn wide() {
+        let [center, x_axis, y_axis] = [
+            Point3::default(),
+            Point3(1.0, 0.0, 0.0),
+            Point3(0.0, 1.0, 0.0),
+        ];
+        // half circles, three quarters and full circles in both directions
+        for angle in [PI, 1.5 * PI, 2.0 * PI, 3.0 * PI] {
+            for (start, end) in [(0.0, angle), (1.0, 1.0 - angle)] {
+                assert!(matches!(
+                    Bezier::arc(center, x_axis, y_axis, start, end),
+                    Err(BezierError::TooWideArc(_))
+                ));
+            }
+        }
+        // arcs just below a half circle are still represented
+        let arc = Bezier::arc(center, x_axis, y_axis, 0.0, PI - 1e-3).unwrap();
+        for i in 0..=10 {
+            assert_f64_near!(arc.gen(i as f64 / 10.0).norm(), 1.0);
+        }
+    }
+}
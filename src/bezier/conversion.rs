@@ -0,0 +1,247 @@
+//! Conversion of bezier curves from and to coefficients in the monomial basis.
+
+use super::error::{BezierError, Empty};
+use super::{Bezier, OwnedBezier};
+use crate::weights::{Homogeneous, Weighted};
+use crate::{DiscreteGenerator, DynSpace, Space};
+use core::ops::{Add, Mul};
+use num_traits::real::Real;
+use num_traits::FromPrimitive;
+
+/// Returns the binomial coefficients of the given degree.
+fn binomials<R>(degree: usize) -> Vec<R>
+where
+    R: Real + FromPrimitive,
+{
+    let mut binomials = vec![R::one(); degree + 1];
+    for k in 1..degree {
+        binomials[k] =
+            binomials[k - 1] * R::from_usize(degree - k + 1).unwrap() / R::from_usize(k).unwrap();
+    }
+    binomials
+}
+
+/// Returns the coefficients in the monomial basis of the bezier curve with the given elements.
+///
+/// The coefficient of t^k is the k-th forward difference of the elements, multiplied by binomial(n,k).
+fn to_monomial<T, R>(elements: &[T]) -> Vec<T>
+where
+    T: Add<Output = T> + Mul<R, Output = T> + Copy,
+    R: Real + FromPrimitive,
+{
+    let degree = elements.len() - 1;
+    let outer = binomials::<R>(degree);
+    (0..=degree)
+        .map(|k| {
+            let inner = binomials::<R>(k);
+            let sign = |i: usize| {
+                if (k - i) % 2 == 0 {
+                    R::one()
+                } else {
+                    -R::one()
+                }
+            };
+            (1..=k).fold(elements[0] * (sign(0) * outer[k]), |sum, i| {
+                sum + elements[i] * (sign(i) * inner[i] * outer[k])
+            })
+        })
+        .collect()
+}
+
+/// Returns the elements of the bezier curve with the given coefficients in the monomial basis.
+///
+/// The i-th element is the sum of the coefficients of t^k, weighted by binomial(i,k) / binomial(n,k).
+fn from_monomial<T, R>(coefficients: &[T]) -> Vec<T>
+where
+    T: Add<Output = T> + Mul<R, Output = T> + Copy,
+    R: Real + FromPrimitive,
+{
+    let degree = coefficients.len() - 1;
+    let outer = binomials::<R>(degree);
+    (0..=degree)
+        .map(|i| {
+            let inner = binomials::<R>(i);
+            (1..=i).fold(coefficients[0], |sum, k| {
+                sum + coefficients[k] * (inner[k] / outer[k])
+            })
+        })
+        .collect()
+}
+
+impl<R, E, S> Bezier<R, E, S>
+where
+    E: DiscreteGenerator,
+    E::Output: Add<Output = E::Output> + Mul<R, Output = E::Output> + Copy,
+    S: Space<E::Output>,
+    R: Real + FromPrimitive,
+{
+    /// Returns the coefficients of the curve in the monomial basis, starting with the constant one.
+    ///
+    /// The curve equals the polynomial `c[0] + c[1] * t + c[2] * t^2 + ...` with the returned coefficients `c`.
+    /// The conversion is exact up to numerical precision, however evaluating polynomials in the monomial basis
+    /// is less stable than evaluating bezier curves.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bezier::{Bezier, BezierError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BezierError> {
+    /// let bezier = Bezier::builder()
+    ///     .elements([1.0,2.0,5.0])
+    ///     .normalized::<f64>()
+    ///     .constant()
+    ///     .build()?;
+    /// // (1-t)^2 + 4t(1-t) + 5t^2 = 1 + 2t + 2t^2
+    /// assert_eq!(bezier.to_monomial(), vec![1.0,2.0,2.0]);
+    /// let back = Bezier::<f64, _, _>::from_monomial(bezier.to_monomial())?;
+    /// assert_f64_near!(back.gen(0.3), bezier.gen(0.3));
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn to_monomial(&self) -> Vec<E::Output> {
+        let elements: Vec<E::Output> = self.elements.iter().collect();
+        to_monomial(&elements)
+    }
+}
+
+impl<R, T> Bezier<R, Vec<T>, DynSpace<T>>
+where
+    T: Add<Output = T> + Mul<R, Output = T> + Default + Copy,
+    R: Real + FromPrimitive,
+{
+    /// Create a bezier curve from its coefficients in the monomial basis, starting with the constant one.
+    ///
+    /// The created curve equals the polynomial `c[0] + c[1] * t + c[2] * t^2 + ...`
+    /// with the given coefficients `c` and has the same degree.
+    ///
+    /// # Errors
+    ///
+    /// [`Empty`] if no coefficients are given.
+    ///
+    /// [`Empty`]: BezierError
+    pub fn from_monomial<G>(coefficients: G) -> Result<Self, BezierError>
+    where
+        G: DiscreteGenerator<Output = T>,
+    {
+        if coefficients.is_empty() {
+            return Err(Empty::new().into());
+        }
+        let coefficients: Vec<T> = coefficients.iter().collect();
+        let elements = from_monomial(&coefficients);
+        let len = elements.len();
+        Ok(Bezier::new_unchecked(elements, DynSpace::new(len)))
+    }
+}
+
+#[cfg(feature = "bspline")]
+impl<R, E, S> Bezier<R, E, S> {
+    /// Returns the elements of the curve.
+    pub(crate) fn elements(&self) -> &E {
+        &self.elements
+    }
+}
+
+impl<R, E, S, T> Weighted<Bezier<R, E, S>>
+where
+    E: DiscreteGenerator<Output = Homogeneous<T, R>>,
+    S: Space<E::Output>,
+    T: Add<Output = T> + Mul<R, Output = T> + Copy,
+    R: Real + FromPrimitive,
+{
+    /// Returns the coefficients of the homogeneous curve in the monomial basis, starting with the constant one.
+    ///
+    /// The directions of the coefficients are the coefficients of the numerator and their rationals
+    /// the coefficients of the denominator of the rational curve.
+    pub fn to_monomial(&self) -> Vec<Homogeneous<T, R>> {
+        self.inner_ref().to_monomial()
+    }
+}
+
+impl<R, T> Weighted<OwnedBezier<R, Homogeneous<T, R>>>
+where
+    T: Add<Output = T> + Mul<R, Output = T> + Default + Copy,
+    R: Real + FromPrimitive + Default,
+{
+    /// Create a rational bezier curve from the coefficients of its homogeneous curve in the monomial basis.
+    ///
+    /// The directions of the coefficients are the coefficients of the numerator and their rationals
+    /// the coefficients of the denominator of the rational curve.
+    ///
+    /// # Errors
+    ///
+    /// [`Empty`] if no coefficients are given.
+    ///
+    /// [`Empty`]: BezierError
+    pub fn from_monomial<G>(coefficients: G) -> Result<Self, BezierError>
+    where
+        G: DiscreteGenerator<Output = Homogeneous<T, R>>,
+    {
+        Ok(Weighted::new(Bezier::from_monomial(coefficients)?))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::Bezier;
+    use crate::weights::{Homogeneous, Weighted};
+    use crate::Generator;
+
+    #[test]
+    fn monomial() {
+        let bezier = Bezier::builder()
+            .elements([3.0, -1.0, 4.0, 2.0, -5.0])
+            .normalized::<f64>()
+            .constant()
+            .build()
+            .unwrap();
+        let coefficients = bezier.to_monomial();
+        assert_eq!(coefficients.len(), 5);
+        for i in 0..=10 {
+            let t = i as f64 / 10.0;
+            let value = bezier.gen(t);
+            let polynomial = coefficients
+                .iter()
+                .rev()
+                .fold(0.0, |sum, &coefficient| sum * t + coefficient);
+            assert!((polynomial - value).abs() < 1e-12);
+        }
+        let back = Bezier::<f64, _, _>::from_monomial(coefficients).unwrap();
+        for (expected, element) in [3.0, -1.0, 4.0, 2.0, -5.0].into_iter().zip(back.elements) {
+            assert!((expected - element).abs() < 1e-12);
+        }
+        // constant curves
+        let constant = Bezier::<f64, _, _>::from_monomial([7.0]).unwrap();
+        assert_eq!(constant.to_monomial(), vec![7.0]);
+        assert!(Bezier::<f64, Vec<f64>, _>::from_monomial(Vec::<f64>::new()).is_err());
+    }
+
+    #[test]
+    fn weighted() {
+        let arc = Bezier::builder()
+            .elements_with_weights([(1.0, 1.0), (1.0, 1.0), (0.0, 2.0)])
+            .normalized::<f64>()
+            .constant()
+            .build()
+            .unwrap();
+        let coefficients = arc.to_monomial();
+        let back = Weighted::<Bezier<f64, Vec<Homogeneous<f64, f64>>, _>>::from_monomial(
+            coefficients.clone(),
+        )
+        .unwrap();
+        for t in [0.0, 0.25, 0.5, 0.75, 1.0] {
+            assert_f64_near!(back.gen(t), arc.gen(t));
+            let numerator = coefficients
+                .iter()
+                .rev()
+                .fold(0.0, |sum, coefficient| sum * t + coefficient.direction());
+            let denominator = coefficients
+                .iter()
+                .rev()
+                .fold(0.0, |sum, coefficient| sum * t + coefficient.rational());
+            assert_f64_near!(numerator / denominator, arc.gen(t));
+        }
+    }
+}
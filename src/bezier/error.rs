@@ -14,6 +14,8 @@ pub enum BezierError {
     Empty(Empty),
     /// Error returned if the given workspace is too small for the interpolation to use.
     TooSmallWorkspace(TooSmallWorkspace),
+    /// Error returned if an arc spans too many radians to be represented by a single bezier curve.
+    TooWideArc(TooWideArc),
 }
 
 impl fmt::Display for BezierError {
@@ -21,6 +23,7 @@ impl fmt::Display for BezierError {
         match self {
             BezierError::Empty(inner) => inner.fmt(f),
             BezierError::TooSmallWorkspace(inner) => inner.fmt(f),
+            BezierError::TooWideArc(inner) => inner.fmt(f),
         }
     }
 }
@@ -37,5 +40,43 @@ impl From<TooSmallWorkspace> for BezierError {
     }
 }
 
+impl From<TooWideArc> for BezierError {
+    fn from(from: TooWideArc) -> Self {
+        BezierError::TooWideArc(from)
+    }
+}
+
 #[cfg(feature = "std")]
 impl Error for BezierError {}
+
+/// Error returned if an arc spans π radians or more.
+///
+/// A single quadratic rational bezier curve with positive weights only represents arcs of less than π radians.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct TooWideArc {}
+
+impl TooWideArc {
+    /// Create a new error.
+    pub const fn new() -> Self {
+        TooWideArc {}
+    }
+}
+
+impl Default for TooWideArc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for TooWideArc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "A single bezier curve can only represent arcs of less than π radians."
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for TooWideArc {}
@@ -0,0 +1,381 @@
+//! Fitting of cubic bezier paths to sequences of points.
+
+use super::Bezier;
+use crate::{ConstSpace, DiscreteGenerator, Generator};
+use core::ops::{Add, Mul, Sub};
+use num_traits::real::Real;
+use num_traits::FromPrimitive;
+use topology_traits::{Merge, QuasiMetric};
+
+/// Maximal number of reparameterizations before a segment gets split.
+const MAX_ITERATIONS: usize = 4;
+
+/// A cubic bezier curve as returned by [`fit_bezier_path()`].
+pub(super) type CubicBezier<R, T> = Bezier<R, [T; 4], ConstSpace<T, 4>>;
+
+/// Fit a path of cubic bezier curves to the given points, such that all points are within the given tolerance.
+///
+/// The distance between elements is measured with their [`QuasiMetric`] implementation.
+/// If the elements do not implement [`QuasiMetric`], consider using [`fit_bezier_path_with()`] instead.
+///
+/// [`QuasiMetric`]: crate::QuasiMetric
+pub fn fit_bezier_path<P, T, R>(points: P, tolerance: R) -> Vec<CubicBezier<R, T>>
+where
+    P: DiscreteGenerator<Output = T>,
+    T: QuasiMetric<R>
+        + Merge<R>
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<R, Output = T>
+        + Default
+        + Copy,
+    R: Real + FromPrimitive,
+{
+    fit_bezier_path_with(points, tolerance, QuasiMetric::distance)
+}
+
+/// Fit a path of cubic bezier curves to the given points with the given metric,
+/// such that all points are within the given tolerance.
+///
+/// Every curve approximates a run of consecutive points. The first curve starts at the first point,
+/// the last curve ends at the last point and every curve starts where the previous one ended,
+/// with a tangent pointing in the same direction (G1 continuity).
+///
+/// The curves are fitted with the algorithm of Philip J. Schneider: the points are parameterized
+/// by chord length and the inner elements of a cubic bezier curve are calculated by least squares.
+/// If the curve is not close enough to the points, the parameters are improved by Newton iterations.
+/// If this does not help, the points are split at the point furthest away from the curve and
+/// both halves are fitted recursively. The tolerance is only guaranteed at the given points,
+/// not in between them.
+///
+/// As angles and projections are derived from the metric, it has to be induced by an inner product,
+/// like the euclidean distance. Equal consecutive points are ignored.
+///
+/// # Examples
+///
+/// ```rust
+/// # use enterpolation::{bezier::fit_bezier_path_with, Curve, Generator};
+/// #
+/// # use core::ops::{Add, Mul, Sub};
+/// # #[derive(Debug, Default, Copy, Clone, PartialEq)]
+/// # struct Point([f64; 2]);
+/// # impl Add for Point { type Output = Point; fn add(self, o: Point) -> Point { Point([self.0[0] + o.0[0], self.0[1] + o.0[1]]) } }
+/// # impl Sub for Point { type Output = Point; fn sub(self, o: Point) -> Point { Point([self.0[0] - o.0[0], self.0[1] - o.0[1]]) } }
+/// # impl Mul<f64> for Point { type Output = Point; fn mul(self, f: f64) -> Point { Point([self.0[0] * f, self.0[1] * f]) } }
+/// let distance = |a: Point, b: Point| (a.0[0] - b.0[0]).hypot(a.0[1] - b.0[1]);
+/// // a quarter of a circle with radius 100
+/// let points: Vec<Point> = (0..=50)
+///     .map(|i| {
+///         let angle = i as f64 / 50.0 * std::f64::consts::FRAC_PI_2;
+///         Point([100.0 * angle.cos(), 100.0 * angle.sin()])
+///     })
+///     .collect();
+/// let path = fit_bezier_path_with(points.clone(), 0.5, distance);
+/// // a single cubic bezier curve approximates a quarter circle well enough
+/// assert_eq!(path.len(), 1);
+/// assert_eq!(path[0].gen(0.0), points[0]);
+/// assert_eq!(path[0].gen(1.0), points[50]);
+/// ```
+pub fn fit_bezier_path_with<P, T, R, F>(
+    points: P,
+    tolerance: R,
+    metric: F,
+) -> Vec<CubicBezier<R, T>>
+where
+    P: DiscreteGenerator<Output = T>,
+    T: Merge<R> + Add<Output = T> + Sub<Output = T> + Mul<R, Output = T> + Default + Copy,
+    R: Real + FromPrimitive,
+    F: Fn(T, T) -> R,
+{
+    let mut unique: Vec<T> = Vec::with_capacity(points.len());
+    for point in points.iter() {
+        if unique
+            .last()
+            .map_or(true, |last| !metric(last, point).is_zero())
+        {
+            unique.push(point);
+        }
+    }
+    let mut path = Vec::new();
+    match unique.len() {
+        0 => {}
+        1 => path.push(Bezier::new_unchecked([unique[0]; 4], ConstSpace::new())),
+        len => {
+            let fitter = Fitter {
+                points: unique,
+                tolerance,
+                metric,
+            };
+            let first = fitter.direction(fitter.points[1], fitter.points[0]);
+            let last = fitter.direction(fitter.points[len - 2], fitter.points[len - 1]);
+            fitter.fit(0, len - 1, first, last, &mut path);
+        }
+    }
+    path
+}
+
+/// The state of the fitting of a bezier path.
+struct Fitter<T, R, F> {
+    points: Vec<T>,
+    tolerance: R,
+    metric: F,
+}
+
+impl<T, R, F> Fitter<T, R, F>
+where
+    T: Merge<R> + Add<Output = T> + Sub<Output = T> + Mul<R, Output = T> + Default + Copy,
+    R: Real + FromPrimitive,
+    F: Fn(T, T) -> R,
+{
+    /// Returns the length of the given vector.
+    fn norm(&self, vector: T) -> R {
+        (self.metric)(vector, vector * R::zero())
+    }
+
+    /// Returns the inner product of the given vectors, derived from the metric by the polarization identity.
+    fn dot(&self, first: T, second: T) -> R {
+        let first_norm = self.norm(first);
+        let second_norm = self.norm(second);
+        let distance = (self.metric)(first, second);
+        (first_norm * first_norm + second_norm * second_norm - distance * distance)
+            / (R::one() + R::one())
+    }
+
+    /// Returns the unit vector pointing from `from` to `to`.
+    fn direction(&self, to: T, from: T) -> T {
+        let vector = to - from;
+        let norm = self.norm(vector);
+        if norm.is_zero() {
+            return vector;
+        }
+        vector * norm.recip()
+    }
+
+    /// Fit the points from `first` to `last` (inclusive), beginning and ending with the given unit tangents.
+    ///
+    /// The end tangent points from the last point backwards.
+    fn fit(&self, first: usize, last: usize, start: T, end: T, path: &mut Vec<CubicBezier<R, T>>) {
+        let points = &self.points[first..=last];
+        if points.len() == 2 {
+            let third = (self.metric)(points[0], points[1]) / R::from_usize(3).unwrap();
+            path.push(Bezier::new_unchecked(
+                [
+                    points[0],
+                    points[0] + start * third,
+                    points[1] + end * third,
+                    points[1],
+                ],
+                ConstSpace::new(),
+            ));
+            return;
+        }
+        let mut parameters = self.chord_length(points);
+        let mut bezier = self.generate(points, &parameters, start, end);
+        let (mut error, mut split) = self.max_error(points, &parameters, &bezier);
+        if error <= self.tolerance {
+            path.push(bezier);
+            return;
+        }
+        // only try to improve the parameters if the curve is not too far off
+        if error <= self.tolerance * R::from_usize(4).unwrap() {
+            for _ in 0..MAX_ITERATIONS {
+                parameters = self.reparameterize(points, &parameters, &bezier);
+                bezier = self.generate(points, &parameters, start, end);
+                let (new_error, new_split) = self.max_error(points, &parameters, &bezier);
+                error = new_error;
+                split = new_split;
+                if error <= self.tolerance {
+                    path.push(bezier);
+                    return;
+                }
+            }
+        }
+        let split = first + split;
+        let mut center = self.direction(self.points[split - 1], self.points[split + 1]);
+        if self.norm(center).is_zero() {
+            center = self.direction(self.points[split - 1], self.points[split]);
+        }
+        self.fit(first, split, start, center, path);
+        self.fit(split, last, center * -R::one(), end, path);
+    }
+
+    /// Returns the chord length parameterization of the given points.
+    fn chord_length(&self, points: &[T]) -> Vec<R> {
+        let mut parameters = Vec::with_capacity(points.len());
+        let mut total = R::zero();
+        parameters.push(total);
+        for window in points.windows(2) {
+            total = total + (self.metric)(window[0], window[1]);
+            parameters.push(total);
+        }
+        for parameter in parameters.iter_mut() {
+            *parameter = *parameter / total;
+        }
+        parameters
+    }
+
+    /// Returns the cubic bezier curve with the given end points and tangent directions,
+    /// which fits the points best in the least-squares sense.
+    fn generate(&self, points: &[T], parameters: &[R], start: T, end: T) -> CubicBezier<R, T> {
+        let three = R::from_usize(3).unwrap();
+        let first = points[0];
+        let last = points[points.len() - 1];
+        let mut matrix = [[R::zero(); 2]; 2];
+        let mut rhs = [R::zero(); 2];
+        for (&point, &t) in points.iter().zip(parameters) {
+            let s = R::one() - t;
+            let bernstein = [s * s * s, three * s * s * t, three * s * t * t, t * t * t];
+            let a = [start * bernstein[1], end * bernstein[2]];
+            matrix[0][0] = matrix[0][0] + self.dot(a[0], a[0]);
+            matrix[0][1] = matrix[0][1] + self.dot(a[0], a[1]);
+            matrix[1][1] = matrix[1][1] + self.dot(a[1], a[1]);
+            let rest = point
+                - (first * (bernstein[0] + bernstein[1]) + last * (bernstein[2] + bernstein[3]));
+            rhs[0] = rhs[0] + self.dot(a[0], rest);
+            rhs[1] = rhs[1] + self.dot(a[1], rest);
+        }
+        matrix[1][0] = matrix[0][1];
+        let determinant = matrix[0][0] * matrix[1][1] - matrix[0][1] * matrix[1][0];
+        let length = (self.metric)(first, last);
+        let (alpha_start, alpha_end) = if determinant.is_zero() {
+            (R::zero(), R::zero())
+        } else {
+            (
+                (rhs[0] * matrix[1][1] - rhs[1] * matrix[0][1]) / determinant,
+                (matrix[0][0] * rhs[1] - matrix[1][0] * rhs[0]) / determinant,
+            )
+        };
+        // fall back to a heuristic if the least-squares solution is degenerated
+        let epsilon = length * R::from_f64(1e-6).unwrap();
+        let (alpha_start, alpha_end) = if alpha_start < epsilon || alpha_end < epsilon {
+            (length / three, length / three)
+        } else {
+            (alpha_start, alpha_end)
+        };
+        Bezier::new_unchecked(
+            [
+                first,
+                first + start * alpha_start,
+                last + end * alpha_end,
+                last,
+            ],
+            ConstSpace::new(),
+        )
+    }
+
+    /// Returns the maximal distance of a point to the curve at its parameter, together with the index of the point.
+    fn max_error(&self, points: &[T], parameters: &[R], bezier: &CubicBezier<R, T>) -> (R, usize) {
+        let mut max = R::zero();
+        let mut index = points.len() / 2;
+        for i in 1..points.len() - 1 {
+            let distance = (self.metric)(bezier.gen(parameters[i]), points[i]);
+            if distance > max {
+                max = distance;
+                index = i;
+            }
+        }
+        (max, index)
+    }
+
+    /// Improve the parameters of the points with one Newton iteration each,
+    /// such that they are closer to the parameters of the closest points on the curve.
+    fn reparameterize(&self, points: &[T], parameters: &[R], bezier: &CubicBezier<R, T>) -> Vec<R> {
+        points
+            .iter()
+            .zip(parameters)
+            .map(|(&point, &t)| {
+                let [value, first, second] = bezier.gen_with_deriatives::<3>(t);
+                let difference = value - point;
+                let numerator = self.dot(difference, first);
+                let denominator = self.dot(first, first) + self.dot(difference, second);
+                if denominator.is_zero() {
+                    return t;
+                }
+                (t - numerator / denominator).max(R::zero()).min(R::one())
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::Point;
+    use crate::Curve;
+
+    /// Distance of the point to the path.
+    fn distance_to_path(path: &[CubicBezier<f64, Point>], point: Point) -> f64 {
+        path.iter()
+            .map(|bezier| bezier.closest_parameter(point, 1e-9).2)
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    #[test]
+    fn single() {
+        let bezier = Bezier::new_unchecked(
+            [
+                Point(0.0, 0.0),
+                Point(1.0, 2.0),
+                Point(3.0, 2.0),
+                Point(4.0, 0.0),
+            ],
+            ConstSpace::<Point, 4>::new(),
+        );
+        let points: Vec<Point> = bezier.take(30).collect();
+        // the tangents at the ends are estimated from the points, such the curve is not reproduced exactly
+        let path = fit_bezier_path(points.clone(), 0.05);
+        assert_eq!(path.len(), 1);
+        for point in points {
+            assert!(distance_to_path(&path, point) < 0.05);
+        }
+    }
+
+    #[test]
+    fn wave() {
+        let points: Vec<Point> = (0..=200)
+            .map(|i| {
+                let x = i as f64 / 10.0;
+                Point(x, x.sin() * 3.0)
+            })
+            .collect();
+        let tolerance = 0.05;
+        let path = fit_bezier_path(points.clone(), tolerance);
+        assert!(path.len() > 1);
+        assert!(path.len() < 40);
+        for point in points.iter() {
+            assert!(distance_to_path(&path, point) <= tolerance + 1e-9);
+        }
+        assert_eq!(path[0].gen(0.0), points[0]);
+        assert_eq!(path[path.len() - 1].gen(1.0), points[200]);
+        // the segments are joined with tangents pointing in the same direction
+        for window in path.windows(2) {
+            let [end, incoming] = window[0].gen_with_tangent(1.0);
+            let [start, outgoing] = window[1].gen_with_tangent(0.0);
+            assert_eq!(end, start);
+            let cross = incoming.0 * outgoing.1 - incoming.1 * outgoing.0;
+            let dot = incoming.0 * outgoing.0 + incoming.1 * outgoing.1;
+            assert!(cross.abs() < 1e-9 * dot);
+            assert!(dot > 0.0);
+        }
+        assert_eq!(path[0].domain(), [0.0, 1.0]);
+    }
+
+    #[test]
+    fn degenerated() {
+        assert!(fit_bezier_path(Vec::<Point>::new(), 1.0).is_empty());
+        let path = fit_bezier_path(vec![Point(1.0, 1.0); 3], 1.0);
+        assert_eq!(path.len(), 1);
+        assert_eq!(path[0].gen(0.5), Point(1.0, 1.0));
+        // duplicated points on a line
+        let points = [
+            Point(0.0, 0.0),
+            Point(0.0, 0.0),
+            Point(1.0, 1.0),
+            Point(2.0, 2.0),
+            Point(2.0, 2.0),
+        ];
+        let path = fit_bezier_path(points, 1e-9);
+        assert_eq!(path.len(), 1);
+        assert!(distance_to_path(&path, Point(1.0, 1.0)) < 1e-6);
+    }
+}
@@ -0,0 +1,264 @@
+//! Intersections of bezier curves with other bezier curves, lines and rays.
+
+use super::Bezier;
+use crate::base::{intersections, line_intersections, ray_intersections};
+use crate::weights::{Homogeneous, Weighted};
+use crate::{Components, DiscreteGenerator, Space};
+use num_traits::real::Real;
+use num_traits::FromPrimitive;
+use topology_traits::Merge;
+
+impl<R, E, S> Bezier<R, E, S>
+where
+    E: DiscreteGenerator,
+    E::Output: Merge<R> + Components<R> + Copy,
+    S: Space<E::Output>,
+    R: Real + FromPrimitive,
+{
+    /// Returns the pairs of parameters at which this curve and the given curve intersect,
+    /// sorted by the parameter of this curve.
+    ///
+    /// Both curves are halved until the boxes containing their parts are either disjoint
+    /// or smaller than the given tolerance. The points of both curves at the returned parameters
+    /// are within the tolerance of each other. Intersections closer than the tolerance may be merged.
+    ///
+    /// Curves which overlap along a stretch intersect at every point of the stretch.
+    /// Such the number of intersections found depends on the tolerance and the search gets slow.
+    ///
+    /// # Examples
+    ///
+    /// The elements are points in two dimensions as given in the examples of [`Components`].
+    ///
+    /// ```rust
+    /// # use core::ops::{Add, Mul};
+    /// # use enterpolation::{bezier::{Bezier, BezierError}, Components};
+    /// #
+    /// # #[derive(Debug, Copy, Clone, Default, PartialEq)]
+    /// # struct Point(f64, f64);
+    /// # impl Add for Point {
+    /// #     type Output = Self;
+    /// #     fn add(self, o: Self) -> Self { Point(self.0 + o.0, self.1 + o.1) }
+    /// # }
+    /// # impl Mul<f64> for Point {
+    /// #     type Output = Self;
+    /// #     fn mul(self, s: f64) -> Self { Point(self.0 * s, self.1 * s) }
+    /// # }
+    /// # impl Components<f64> for Point {
+    /// #     fn dimension(&self) -> usize { 2 }
+    /// #     fn component(&self, index: usize) -> f64 { [self.0, self.1][index] }
+    /// #     fn set_component(&mut self, index: usize, value: f64) {
+    /// #         match index { 0 => self.0 = value, _ => self.1 = value }
+    /// #     }
+    /// # }
+    /// # fn main() -> Result<(), BezierError> {
+    /// // the parabolas y = 4x(1-x) and y = 1 - 4x(1-x)
+    /// let arch = Bezier::builder()
+    ///     .elements([Point(0.0, 0.0), Point(0.5, 2.0), Point(1.0, 0.0)])
+    ///     .normalized::<f64>()
+    ///     .constant()
+    ///     .build()?;
+    /// let valley = Bezier::builder()
+    ///     .elements([Point(0.0, 1.0), Point(0.5, -1.0), Point(1.0, 1.0)])
+    ///     .normalized::<f64>()
+    ///     .constant()
+    ///     .build()?;
+    /// let intersections = arch.intersect(&valley, 1e-9);
+    /// assert_eq!(intersections.len(), 2);
+    /// let (t, s) = intersections[0];
+    /// assert!((t - (0.5 - 2.0_f64.sqrt() / 4.0)).abs() < 1e-8);
+    /// assert!((t - s).abs() < 1e-8);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn intersect<F, W>(&self, other: &Bezier<R, F, W>, tolerance: R) -> Vec<(R, R)>
+    where
+        F: DiscreteGenerator<Output = E::Output>,
+        W: Space<E::Output>,
+    {
+        intersections(&[self.piece()], &[other.piece()], tolerance)
+    }
+
+    /// Returns the parameters at which the curve intersects the line through the given origin
+    /// in the given direction.
+    ///
+    /// Every intersection is returned as pair of the parameter of the curve and the parameter of the line,
+    /// such that the intersection lies at `origin + direction * parameter`. Only the first two components
+    /// of the elements are considered, such the line is given in the plane.
+    ///
+    /// The intersections are the roots of the distance of the curve to the line, which is found exactly
+    /// up to numerical precision. If the curve lies on the line, only its borders are returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the elements have less than two components.
+    pub fn intersect_line(&self, origin: E::Output, direction: E::Output) -> Vec<(R, R)> {
+        line_intersections(&[self.piece()], origin, direction)
+    }
+
+    /// Returns the parameters at which the curve intersects the ray starting at the given origin
+    /// in the given direction.
+    ///
+    /// This are all intersections with the line, whose parameter of the line is not negative.
+    /// See [`intersect_line()`] for more information.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the elements have less than two components.
+    ///
+    /// [`intersect_line()`]: Bezier::intersect_line()
+    pub fn intersect_ray(&self, origin: E::Output, direction: E::Output) -> Vec<(R, R)> {
+        ray_intersections(&[self.piece()], origin, direction)
+    }
+}
+
+impl<R, E, S, T> Weighted<Bezier<R, E, S>>
+where
+    E: DiscreteGenerator<Output = Homogeneous<T, R>>,
+    E::Output: Merge<R> + Copy,
+    S: Space<E::Output>,
+    T: Components<R> + Copy,
+    R: Real + FromPrimitive,
+{
+    /// Returns the pairs of parameters at which this rational curve and the given rational curve intersect,
+    /// sorted by the parameter of this curve.
+    ///
+    /// See [`Bezier::intersect()`] for more information. All weights are expected to be positive.
+    pub fn intersect<F, W>(&self, other: &Weighted<Bezier<R, F, W>>, tolerance: R) -> Vec<(R, R)>
+    where
+        F: DiscreteGenerator<Output = E::Output>,
+        W: Space<E::Output>,
+    {
+        intersections(
+            &[self.inner_ref().piece().rational()],
+            &[other.inner_ref().piece().rational()],
+            tolerance,
+        )
+    }
+
+    /// Returns the parameters at which the rational curve intersects the line through the given origin
+    /// in the given direction.
+    ///
+    /// See [`Bezier::intersect_line()`] for more information.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the elements have less than two components.
+    pub fn intersect_line(&self, origin: T, direction: T) -> Vec<(R, R)> {
+        line_intersections(&[self.inner_ref().piece().rational()], origin, direction)
+    }
+
+    /// Returns the parameters at which the rational curve intersects the ray starting at the given origin
+    /// in the given direction.
+    ///
+    /// See [`Bezier::intersect_ray()`] for more information.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the elements have less than two components.
+    pub fn intersect_ray(&self, origin: T, direction: T) -> Vec<(R, R)> {
+        ray_intersections(&[self.inner_ref().piece().rational()], origin, direction)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::Bezier;
+    use crate::test_util::{distance, Point};
+    use crate::Generator;
+
+    #[test]
+    fn curves() {
+        // a loop-free s-curve crossing a wave three times
+        let curve = Bezier::builder()
+            .elements([
+                Point(0.0, 0.0),
+                Point(1.0, 3.0),
+                Point(2.0, -3.0),
+                Point(3.0, 0.0),
+            ])
+            .normalized::<f64>()
+            .constant()
+            .build()
+            .unwrap();
+        let line = Bezier::builder()
+            .elements([Point(-1.0, 0.2), Point(4.0, -0.2)])
+            .normalized::<f64>()
+            .constant()
+            .build()
+            .unwrap();
+        let tolerance = 1e-10;
+        let intersections = curve.intersect(&line, tolerance);
+        assert_eq!(intersections.len(), 3);
+        for pair in intersections.windows(2) {
+            assert!(pair[0].0 < pair[1].0);
+        }
+        for (t, s) in intersections {
+            assert!(distance(curve.gen(t), line.gen(s)) < 1e-9);
+        }
+    }
+
+    #[test]
+    fn lines() {
+        let curve = Bezier::builder()
+            .elements([
+                Point(0.0, 0.0),
+                Point(1.0, 3.0),
+                Point(2.0, -3.0),
+                Point(3.0, 0.0),
+            ])
+            .normalized::<f64>()
+            .constant()
+            .build()
+            .unwrap();
+        let origin = Point(1.5, 0.0);
+        let direction = Point(1.0, 0.0);
+        let intersections = curve.intersect_line(origin, direction);
+        assert_eq!(intersections.len(), 3);
+        for &(t, s) in intersections.iter() {
+            assert!(distance(curve.gen(t), origin + direction * s) < 1e-9);
+        }
+        assert!((intersections[1].0 - 0.5).abs() < 1e-9);
+        assert!(intersections[1].1.abs() < 1e-9);
+        let intersections = curve.intersect_ray(Point(1.6, 0.0), direction);
+        assert_eq!(intersections.len(), 1);
+        assert_eq!(intersections[0].0, 1.0);
+        assert_f64_near!(intersections[0].1, 1.4);
+        // lines missing the curve
+        assert!(curve.intersect_line(Point(0.0, 1.0), direction).is_empty());
+    }
+
+    #[test]
+    fn weighted() {
+        // the quarter of the unit circle
+        let arc = Bezier::builder()
+            .elements_with_weights([
+                (Point(1.0, 0.0), 1.0),
+                (Point(1.0, 1.0), 2.0_f64.sqrt() / 2.0),
+                (Point(0.0, 1.0), 1.0),
+            ])
+            .normalized::<f64>()
+            .constant()
+            .build()
+            .unwrap();
+        let diagonal = Bezier::builder()
+            .elements_with_weights([(Point(0.0, 0.0), 1.0), (Point(1.0, 1.0), 1.0)])
+            .normalized::<f64>()
+            .constant()
+            .build()
+            .unwrap();
+        let intersections = arc.intersect(&diagonal, 1e-10);
+        assert_eq!(intersections.len(), 1);
+        let (t, s) = intersections[0];
+        assert!((t - 0.5).abs() < 1e-9);
+        assert!((s - 0.5_f64.sqrt()).abs() < 1e-9);
+        let intersections = arc.intersect_ray(Point(0.0, 0.0), Point(1.0, 1.0));
+        assert_eq!(intersections.len(), 1);
+        let (t, s) = intersections[0];
+        assert!((t - 0.5).abs() < 1e-9);
+        assert!((s - 0.5_f64.sqrt()).abs() < 1e-9);
+        assert!(arc
+            .intersect_ray(Point(0.0, 0.0), Point(-1.0, -1.0))
+            .is_empty());
+    }
+}
@@ -25,18 +25,49 @@
 //! Bezier curves are polynomial curves with their degree given by the number of elements they consist of.
 //!
 //! [`BezierBuilder`]: BezierBuilder
+#[cfg(all(feature = "std", feature = "linear"))]
+use crate::base::polyline;
+#[cfg(feature = "std")]
+use crate::base::MAX_DEPTH;
 use crate::builder::Unknown;
+use crate::frames::Differentiable;
+#[cfg(all(feature = "std", feature = "polynomial"))]
+use crate::polynomial::{ChebyshevWeights, Polynomial};
+use crate::weights::{project_derivatives, Homogeneous, Weighted};
+#[cfg(all(feature = "std", feature = "polynomial"))]
+use crate::Chebyshev;
+#[cfg(feature = "std")]
+use crate::DynSpace;
+#[cfg(all(feature = "std", feature = "linear"))]
+use crate::{easing::Identity, linear::Linear, Sorted};
 use crate::{Curve, DiscreteGenerator, Generator, Space};
 use core::marker::PhantomData;
-use core::ops::{Mul, Sub};
+use core::ops::{Add, Div, Mul, Sub};
 use num_traits::cast::FromPrimitive;
 use num_traits::real::Real;
 use topology_traits::Merge;
+#[cfg(feature = "std")]
+use topology_traits::QuasiMetric;
 
+#[cfg(feature = "std")]
+mod bounds;
 mod builder;
+mod conics;
+#[cfg(feature = "std")]
+mod conversion;
 pub use builder::{BezierBuilder, BezierDirector};
 mod error;
-pub use error::{BezierError, Empty, TooSmallWorkspace};
+pub use error::{BezierError, Empty, TooSmallWorkspace, TooWideArc};
+#[cfg(feature = "std")]
+mod fitting;
+#[cfg(feature = "std")]
+pub use fitting::{fit_bezier_path, fit_bezier_path_with};
+#[cfg(feature = "std")]
+mod intersection;
+#[cfg(feature = "std")]
+mod offset;
+#[cfg(all(feature = "std", feature = "bspline"))]
+pub(crate) use offset::offset_curve;
 
 /// Calculate a pascalsche triangle with the given closure until the maximal steps as levels are reached.
 /// If one wants to fold all values into the first position of the given buffer
@@ -66,7 +97,7 @@ where
     let elements = triangle.as_mut();
     let len = elements.len();
     for k in 1..=steps {
-        for i in k..len {
+        for i in (k..len).rev() {
             elements[i] = func(elements[i - 1], elements[i]);
         }
     }
@@ -126,14 +157,19 @@ where
     R: Real + FromPrimitive,
 {
     let len = elements.as_mut().len();
-    let deg = K.min(len - 1);
+    // take a zero out vector which can be copied to initialise the array (and have the right default)
+    let mut grad = [elements.as_mut()[0] * R::zero(); K];
+    if K == 0 {
+        return grad;
+    }
+    // the highest deriative to calculate, all others are zero
+    let deg = (K - 1).min(len - 1);
     triangle_folding_inline(
         elements.as_mut(),
         |first, second| first.merge(second, scalar),
         len - deg - 1,
     );
-    // take a zero out vector which can be copied to initialise the array (and have the right default)
-    let mut grad = [elements.as_mut()[0] * R::zero(); K];
+    grad[..=deg].clone_from_slice(&elements.as_mut()[..=deg]);
     for k in (1..=deg).rev() {
         //calculate difference folding
         let grad_slice = &mut grad[..=k];
@@ -152,6 +188,106 @@ where
     grad
 }
 
+/// Calculates the binomial coefficient `n` choose `k`.
+#[cfg(feature = "std")]
+fn binomial<R>(n: usize, k: usize) -> R
+where
+    R: Real + FromPrimitive,
+{
+    (1..=k).fold(R::one(), |acc, i| {
+        acc * R::from_usize(n - k + i).unwrap() / R::from_usize(i).unwrap()
+    })
+}
+
+/// Splits the elements of a bezier curve at the given scalar into the elements of the two parts.
+///
+/// The intermediate results of the de Casteljau algorithm on the left and right border
+/// are the elements of the two parts.
+#[cfg(feature = "std")]
+fn split_elements<R, T>(mut elements: Vec<T>, scalar: R) -> (Vec<T>, Vec<T>)
+where
+    T: Merge<R> + Copy,
+    R: Real,
+{
+    let len = elements.len();
+    let mut left = Vec::with_capacity(len);
+    let mut right = Vec::with_capacity(len);
+    left.push(elements[0]);
+    right.push(elements[len - 1]);
+    for k in 1..len {
+        triangle_folding_inline(
+            &mut elements[..=len - k],
+            |first, second| first.merge(second, scalar),
+            1,
+        );
+        left.push(elements[0]);
+        right.push(elements[len - k - 1]);
+    }
+    right.reverse();
+    (left, right)
+}
+
+/// Adds samples of the bezier curve with the given elements to the list, except the first one.
+///
+/// The curve is split in half until the distances of the elements to the chord are within the tolerance.
+/// As a bezier curve is a convex combination of its elements and the chord is the same convex combination
+/// of the elements projected onto it, the distance of the curve to the chord is bounded by these distances.
+#[cfg(feature = "std")]
+fn flatten_elements<R, T, F>(
+    elements: Vec<T>,
+    interval: [R; 2],
+    tolerance: R,
+    metric: &F,
+    depth: usize,
+    samples: &mut Vec<(R, T)>,
+) where
+    T: Merge<R> + Copy,
+    F: Fn(T, T) -> R,
+    R: Real + FromPrimitive,
+{
+    let len = elements.len();
+    let (first, last) = (elements[0], elements[len - 1]);
+    let degree = R::from_usize(len - 1).unwrap();
+    let flatness = elements[..len - 1]
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(i, &element)| {
+            metric(
+                element,
+                first.merge(last, R::from_usize(i).unwrap() / degree),
+            )
+        })
+        .fold(R::zero(), |max, distance| max.max(distance));
+    if flatness <= tolerance || depth >= MAX_DEPTH {
+        samples.push((interval[1], last));
+        return;
+    }
+    let two = R::one() + R::one();
+    let middle = (interval[0] + interval[1]) / two;
+    let (left, right) = split_elements(elements, two.recip());
+    flatten_elements(
+        left,
+        [interval[0], middle],
+        tolerance,
+        metric,
+        depth + 1,
+        samples,
+    );
+    flatten_elements(
+        right,
+        [middle, interval[1]],
+        tolerance,
+        metric,
+        depth + 1,
+        samples,
+    );
+}
+
+/// Type alias for bezier curves which own their elements.
+#[cfg(feature = "std")]
+pub(crate) type OwnedBezier<R, T> = Bezier<R, Vec<T>, DynSpace<T>>;
+
 /// Bezier curve.
 ///
 /// See [bezier module] for more information.
@@ -285,6 +421,334 @@ where
     }
 }
 
+impl<R, E, S> Differentiable<R> for Bezier<R, E, S>
+where
+    E: DiscreteGenerator,
+    E::Output: Merge<R> + Mul<R, Output = E::Output> + Sub<Output = E::Output> + Copy,
+    S: Space<E::Output>,
+    R: Real + FromPrimitive,
+{
+    fn gen_with_derivatives<const N: usize>(&self, scalar: R) -> [E::Output; N] {
+        self.gen_with_deriatives(scalar)
+    }
+}
+
+impl<R, E, S, T> Differentiable<R> for Weighted<Bezier<R, E, S>>
+where
+    E: DiscreteGenerator<Output = Homogeneous<T, R>>,
+    S: Space<E::Output>,
+    T: Add<Output = T> + Mul<R, Output = T> + Sub<Output = T> + Div<R, Output = T> + Copy,
+    R: Real + FromPrimitive,
+{
+    /// Generate the value and its derivatives, the order hereby is from value, then first derivative, then second and so on.
+    ///
+    /// The derivatives of the rational curve are calculated with the quotient rule
+    /// from the derivatives of the underlying homogeneous curve.
+    fn gen_with_derivatives<const N: usize>(&self, scalar: R) -> [T; N] {
+        project_derivatives(self.inner_ref().gen_with_deriatives(scalar))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R, E, S> Bezier<R, E, S>
+where
+    E: DiscreteGenerator,
+    E::Output: Merge<R> + Copy,
+    S: Space<E::Output>,
+    R: Real + FromPrimitive,
+{
+    /// Elevate the degree of the curve by the given amount without changing its shape.
+    ///
+    /// The resulting curve owns its elements and uses a vector as workspace.
+    /// If you want to use another workspace, consider using [`elevate_with_workspace()`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bezier::{Bezier, BezierError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BezierError> {
+    /// let quadratic = Bezier::builder()
+    ///     .elements([0.0,3.0,0.0])
+    ///     .normalized::<f64>()
+    ///     .constant()
+    ///     .build()?;
+    /// let cubic = quadratic.elevate(1);
+    /// for (value, result) in cubic.take(5).zip(quadratic.take(5)) {
+    ///     assert_f64_near!(value, result);
+    /// }
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`elevate_with_workspace()`]: Bezier::elevate_with_workspace()
+    pub fn elevate(&self, by: usize) -> OwnedBezier<R, E::Output> {
+        self.owned(self.elevated_elements(by))
+    }
+
+    /// Elevate the degree of the curve by the given amount without changing its shape.
+    ///
+    /// The resulting curve owns its elements and uses the given workspace.
+    ///
+    /// # Errors
+    ///
+    /// [`TooSmallWorkspace`] if the given workspace is smaller than the number of elements of the resulting curve.
+    ///
+    /// [`TooSmallWorkspace`]: BezierError
+    pub fn elevate_with_workspace<W>(
+        &self,
+        by: usize,
+        space: W,
+    ) -> Result<Bezier<R, Vec<E::Output>, W>, BezierError>
+    where
+        W: Space<E::Output>,
+    {
+        Bezier::new(self.elevated_elements(by), space)
+    }
+
+    /// Split the curve at the given scalar into two curves.
+    ///
+    /// The first curve represents the part of this curve from 0.0 to the given scalar,
+    /// the second one the part from the scalar to 1.0. Both curves have the domain [0.0,1.0].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bezier::{Bezier, BezierError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BezierError> {
+    /// let bezier = Bezier::builder()
+    ///     .elements([0.0,5.0,3.0,10.0])
+    ///     .normalized::<f64>()
+    ///     .constant()
+    ///     .build()?;
+    /// let (left, right) = bezier.split(0.25);
+    /// assert_f64_near!(left.gen(0.5), bezier.gen(0.125));
+    /// assert_f64_near!(right.gen(0.0), bezier.gen(0.25));
+    /// assert_f64_near!(right.gen(1.0), 10.0);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn split(&self, scalar: R) -> (OwnedBezier<R, E::Output>, OwnedBezier<R, E::Output>) {
+        let (left, right) = split_elements(self.elements.iter().collect(), scalar);
+        (self.owned(left), self.owned(right))
+    }
+
+    /// Subdivide the curve into the given number of curves.
+    ///
+    /// The curves represent the parts of this curve between equidistant scalars in [0.0,1.0] and
+    /// have the domain [0.0,1.0] themselves. If the given number is zero, no curves are returned.
+    pub fn subdivide(&self, pieces: usize) -> Vec<OwnedBezier<R, E::Output>> {
+        let mut curves = Vec::with_capacity(pieces);
+        let mut rest: Vec<E::Output> = self.elements.iter().collect();
+        for remaining in (2..=pieces).rev() {
+            let (left, right) = split_elements(rest, R::from_usize(remaining).unwrap().recip());
+            curves.push(self.owned(left));
+            rest = right;
+        }
+        if pieces > 0 {
+            curves.push(self.owned(rest));
+        }
+        curves
+    }
+
+    /// Sample the curve adaptively, such that the polyline through the samples is within the tolerance of the curve.
+    ///
+    /// This is a faster and more precise version of [`Curve::flatten()`] for bezier curves,
+    /// which uses the distances of the elements to the chord as bound of the deviation of the curve.
+    ///
+    /// If the elements do not implement [`QuasiMetric`], consider using [`flatten_with()`] instead.
+    ///
+    /// [`Curve::flatten()`]: crate::Curve::flatten()
+    /// [`QuasiMetric`]: topology_traits::QuasiMetric
+    /// [`flatten_with()`]: Bezier::flatten_with()
+    pub fn flatten(&self, tolerance: R) -> Vec<(R, E::Output)>
+    where
+        E::Output: QuasiMetric<R>,
+    {
+        self.flatten_with(tolerance, QuasiMetric::distance)
+    }
+
+    /// Sample the curve adaptively with the given metric, such that the polyline through the samples
+    /// is within the tolerance of the curve.
+    ///
+    /// The curve is halved until the distance of every element to the chord of its part is within the tolerance.
+    /// In contrast to [`Curve::flatten_with()`], the resulting polyline is guaranteed to be within the tolerance
+    /// for metrics induced by a norm, as long as the curve does not have to be halved more than 16 times.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bezier::{Bezier, BezierError}, Generator, Curve};
+    /// #
+    /// # fn main() -> Result<(), BezierError> {
+    /// let bezier = Bezier::builder()
+    ///     .elements([0.0,5.0,3.0,10.0])
+    ///     .normalized::<f64>()
+    ///     .constant()
+    ///     .build()?;
+    /// let samples = bezier.flatten_with(0.01, |a: f64, b: f64| (a - b).abs());
+    /// assert_eq!(samples[0], (0.0, 0.0));
+    /// assert_eq!(samples[samples.len() - 1], (1.0, 10.0));
+    /// for (t, point) in samples {
+    ///     assert!((bezier.gen(t) - point).abs() < 1e-12);
+    /// }
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Curve::flatten_with()`]: crate::Curve::flatten_with()
+    pub fn flatten_with<F>(&self, tolerance: R, metric: F) -> Vec<(R, E::Output)>
+    where
+        F: Fn(E::Output, E::Output) -> R,
+    {
+        let elements: Vec<E::Output> = self.elements.iter().collect();
+        let mut samples = vec![(R::zero(), elements[0])];
+        let interval = [R::zero(), R::one()];
+        flatten_elements(elements, interval, tolerance, &metric, 0, &mut samples);
+        samples
+    }
+
+    /// Approximate the curve by a linear interpolation of adaptively chosen samples.
+    ///
+    /// The samples are the ones of [`flatten()`], which also describes the tolerance.
+    ///
+    /// [`flatten()`]: Bezier::flatten()
+    #[cfg(feature = "linear")]
+    pub fn polyline(&self, tolerance: R) -> Linear<Sorted<Vec<R>>, Vec<E::Output>, Identity>
+    where
+        E::Output: QuasiMetric<R>,
+    {
+        polyline(self.flatten(tolerance))
+    }
+
+    /// Approximate the curve by a linear interpolation of adaptively chosen samples, measured with the given metric.
+    ///
+    /// The samples are the ones of [`flatten_with()`], which also describes the tolerance.
+    ///
+    /// [`flatten_with()`]: Bezier::flatten_with()
+    #[cfg(feature = "linear")]
+    pub fn polyline_with<F>(
+        &self,
+        tolerance: R,
+        metric: F,
+    ) -> Linear<Sorted<Vec<R>>, Vec<E::Output>, Identity>
+    where
+        F: Fn(E::Output, E::Output) -> R,
+    {
+        polyline(self.flatten_with(tolerance, metric))
+    }
+
+    /// Creates a bezier curve with the given elements and a fitting workspace.
+    fn owned(&self, elements: Vec<E::Output>) -> OwnedBezier<R, E::Output> {
+        Bezier {
+            space: DynSpace::new(elements.len()),
+            elements,
+            _input: PhantomData,
+        }
+    }
+
+    /// Calculates the elements of the curve with a degree elevated by the given amount.
+    ///
+    /// Every new element is a convex combination of the old ones, whose weights
+    /// are given by binomial coefficients.
+    fn elevated_elements(&self, by: usize) -> Vec<E::Output> {
+        let degree = self.elements.len() - 1;
+        (0..=degree + by)
+            .map(|i| {
+                let lower = i.saturating_sub(by);
+                let mut element = self.elements.gen(lower);
+                let mut total = R::zero();
+                for j in lower..=i.min(degree) {
+                    let weight = binomial::<R>(degree, j) * binomial::<R>(by, i - j);
+                    total = total + weight;
+                    element = element.merge(self.elements.gen(j), weight / total);
+                }
+                element
+            })
+            .collect()
+    }
+}
+
+#[cfg(all(feature = "std", feature = "polynomial"))]
+impl<R, E, S> Bezier<R, E, S>
+where
+    E: DiscreteGenerator,
+    E::Output: Merge<R> + Copy,
+    S: Space<E::Output>,
+    R: Real + FromPrimitive,
+{
+    /// Returns the same curve as polynomial interpolation.
+    ///
+    /// The curve is sampled at as many Chebyshev points as it has elements,
+    /// which determines the polynomial uniquely. The polynomial has the domain [0.0,1.0].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bezier::{Bezier, BezierError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BezierError> {
+    /// let bezier = Bezier::builder()
+    ///     .elements([0.0,5.0,3.0,10.0])
+    ///     .normalized::<f64>()
+    ///     .constant()
+    ///     .build()?;
+    /// let polynomial = bezier.to_polynomial();
+    /// assert_f64_near!(polynomial.gen(0.3), bezier.gen(0.3));
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn to_polynomial(&self) -> Polynomial<Chebyshev<R>, Vec<E::Output>, ChebyshevWeights<R>> {
+        let len = self.elements.len();
+        let knots = Chebyshev::normalized(len);
+        let elements = knots.iter().map(|knot| self.gen(knot)).collect();
+        Polynomial::new_unchecked(elements, knots, ChebyshevWeights::new(len))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R, E, S, T> Weighted<Bezier<R, E, S>>
+where
+    E: DiscreteGenerator<Output = Homogeneous<T, R>>,
+    E::Output: Merge<R> + Copy,
+    S: Space<E::Output>,
+    R: Real + FromPrimitive,
+{
+    /// Split the curve at the given scalar into two curves.
+    ///
+    /// The curve is split with its homogeneous elements, such the weights are split accordingly.
+    #[allow(clippy::type_complexity)]
+    pub fn split(
+        &self,
+        scalar: R,
+    ) -> (
+        Weighted<OwnedBezier<R, E::Output>>,
+        Weighted<OwnedBezier<R, E::Output>>,
+    ) {
+        let (left, right) = self.inner_ref().split(scalar);
+        (Weighted::new(left), Weighted::new(right))
+    }
+
+    /// Subdivide the curve into the given number of curves.
+    ///
+    /// The curve is subdivided with its homogeneous elements, such the weights are subdivided accordingly.
+    pub fn subdivide(&self, pieces: usize) -> Vec<Weighted<OwnedBezier<R, E::Output>>> {
+        self.inner_ref()
+            .subdivide(pieces)
+            .into_iter()
+            .map(Weighted::new)
+            .collect()
+    }
+}
+
 impl<R, E, S> Bezier<R, E, S>
 where
     E: DiscreteGenerator,
@@ -306,7 +770,9 @@ where
             _input: PhantomData,
         })
     }
+}
 
+impl<R, E, S> Bezier<R, E, S> {
     /// Create generic bezier curve without doing any checking.
     ///
     /// Building a bezier curve with the associated builder is recommended.
@@ -388,4 +854,164 @@ mod test {
         assert_f64_near!(res[3], 0.0);
         assert_f64_near!(res[4], 0.0);
     }
+
+    #[test]
+    fn deriatives_up_to_degree() {
+        // the curve x^3, whose third deriative is the highest one which is not zero
+        let bez = Bezier::builder()
+            .elements([0.0, 0.0, 0.0, 1.0])
+            .normalized::<f64>()
+            .constant()
+            .build()
+            .unwrap();
+        let res = bez.gen_with_deriatives::<4>(0.5);
+        assert_f64_near!(res[0], 0.125);
+        assert_f64_near!(res[1], 0.75);
+        assert_f64_near!(res[2], 3.0);
+        assert_f64_near!(res[3], 6.0);
+        // asking for less deriatives than the degree
+        let res = bez.gen_with_deriatives::<2>(0.5);
+        assert_f64_near!(res[0], 0.125);
+        assert_f64_near!(res[1], 0.75);
+        let res = bez.gen_with_deriatives::<1>(0.5);
+        assert_f64_near!(res[0], 0.125);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn elevate() {
+        let bez = Bezier::builder()
+            .elements([0.0, 3.0, 0.0])
+            .normalized::<f64>()
+            .constant()
+            .build()
+            .unwrap();
+        let elevated = bez.elevate(1);
+        let expected = [0.0, 2.0, 2.0, 0.0];
+        assert_eq!(elevated.elements.len(), expected.len());
+        for (value, result) in elevated.elements.iter().zip(expected.iter()) {
+            assert_f64_near!(value, result);
+        }
+        let elevated = bez.elevate(3);
+        assert_eq!(elevated.elements.len(), 6);
+        for (value, result) in elevated.take(11).zip(bez.take(11)) {
+            assert!((value - result).abs() < 1e-10);
+        }
+        let elevated = bez.elevate(0);
+        for (value, result) in elevated.elements.iter().zip([0.0, 3.0, 0.0].iter()) {
+            assert_f64_near!(value, result);
+        }
+        assert!(bez
+            .elevate_with_workspace(2, ConstSpace::<_, 5>::new())
+            .is_ok());
+        assert!(bez
+            .elevate_with_workspace(2, ConstSpace::<_, 4>::new())
+            .is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn split() {
+        let bez = Bezier::builder()
+            .elements([0.0, 5.0, 3.0, 10.0])
+            .normalized::<f64>()
+            .constant()
+            .build()
+            .unwrap();
+        let (left, right) = bez.split(0.3);
+        assert_eq!(left.elements.len(), 4);
+        assert_eq!(right.elements.len(), 4);
+        for i in 0..=10 {
+            let scalar = i as f64 / 10.0;
+            assert!((left.gen(scalar) - bez.gen(0.3 * scalar)).abs() < 1e-10);
+            assert!((right.gen(scalar) - bez.gen(0.3 + 0.7 * scalar)).abs() < 1e-10);
+        }
+        let pieces = bez.subdivide(4);
+        assert_eq!(pieces.len(), 4);
+        for (i, piece) in pieces.iter().enumerate() {
+            for j in 0..=10 {
+                let scalar = j as f64 / 10.0;
+                let global = (i as f64 + scalar) / 4.0;
+                assert!((piece.gen(scalar) - bez.gen(global)).abs() < 1e-10);
+            }
+        }
+        assert!(bez.subdivide(0).is_empty());
+        assert_eq!(bez.subdivide(1)[0].elements, vec![0.0, 5.0, 3.0, 10.0]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn split_weighted() {
+        let bez = Bezier::builder()
+            .elements_with_weights([(0.0, 1.0), (5.0, 3.0), (3.0, 0.5), (10.0, 1.0)])
+            .normalized::<f64>()
+            .constant()
+            .build()
+            .unwrap();
+        let (left, right) = bez.split(0.6);
+        for i in 0..=10 {
+            let scalar = i as f64 / 10.0;
+            assert!((left.gen(scalar) - bez.gen(0.6 * scalar)).abs() < 1e-10);
+            assert!((right.gen(scalar) - bez.gen(0.6 + 0.4 * scalar)).abs() < 1e-10);
+        }
+        let pieces = bez.subdivide(3);
+        for (i, piece) in pieces.iter().enumerate() {
+            let global = (i as f64 + 0.5) / 3.0;
+            assert!((piece.gen(0.5) - bez.gen(global)).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn flatten() {
+        let metric = |a: f64, b: f64| (a - b).abs();
+        let bez = Bezier::builder()
+            .elements([0.0, 5.0, 3.0, 10.0])
+            .normalized::<f64>()
+            .constant()
+            .build()
+            .unwrap();
+        let samples = bez.flatten_with(1e-3, metric);
+        assert_eq!(samples[0], (0.0, 0.0));
+        assert_eq!(samples[samples.len() - 1], (1.0, 10.0));
+        for window in samples.windows(2) {
+            let [(t0, p0), (t1, p1)] = [window[0], window[1]];
+            assert!(t0 < t1);
+            assert!((bez.gen(t1) - p1).abs() < 1e-12);
+            for i in 1..10 {
+                let factor = i as f64 / 10.0;
+                let chord = p0 + (p1 - p0) * factor;
+                assert!((bez.gen(t0 + (t1 - t0) * factor) - chord).abs() <= 1e-3);
+            }
+        }
+        // lines and points do not get subdivided
+        let line = Bezier::builder()
+            .elements([1.0, 2.0, 3.0])
+            .normalized::<f64>()
+            .constant()
+            .build()
+            .unwrap();
+        assert_eq!(line.flatten_with(0.0, metric), vec![(0.0, 1.0), (1.0, 3.0)]);
+        let point = Bezier::new([5.0], ConstSpace::<_, 1>::new()).unwrap();
+        assert_eq!(
+            point.flatten_with(0.0, metric),
+            vec![(0.0, 5.0), (1.0, 5.0)]
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", feature = "linear"))]
+    fn polyline() {
+        let bez = Bezier::builder()
+            .elements([0.0, 5.0, 3.0, 10.0])
+            .normalized::<f64>()
+            .constant()
+            .build()
+            .unwrap();
+        let polyline = bez.polyline_with(1e-4, |a: f64, b: f64| (a - b).abs());
+        for i in 0..=100 {
+            let scalar = i as f64 / 100.0;
+            assert!((polyline.gen(scalar) - bez.gen(scalar)).abs() <= 1e-4);
+        }
+    }
 }
@@ -0,0 +1,454 @@
+//! Offset curves of planar curves, approximated by paths of cubic bezier curves.
+
+use super::fitting::CubicBezier;
+use super::{split_elements, Bezier};
+use crate::base::{intersections, Piece};
+use crate::frames::Differentiable;
+use crate::weights::{Homogeneous, Weighted};
+use crate::{Components, ConstSpace, DiscreteGenerator, Generator, Space};
+use core::ops::{Add, Div, Mul, Sub};
+use num_traits::real::Real;
+use num_traits::FromPrimitive;
+use topology_traits::Merge;
+
+/// Maximal number of times an interval gets halved while approximating the offset curve.
+const MAX_DEPTH: usize = 16;
+/// Number of samples taken per interval of the domain to search for cusps of the offset curve.
+const CUSP_SAMPLES: usize = 64;
+/// Number of samples taken per cubic bezier curve to measure its distance to the offset curve.
+const ERROR_SAMPLES: usize = 8;
+/// Maximal number of bisection steps used to locate a cusp.
+const MAX_ITERATIONS: usize = 64;
+
+/// Returns the euclidean distance between the components of the given elements.
+fn distance<T, R>(first: T, second: T) -> R
+where
+    T: Components<R>,
+    R: Real,
+{
+    (0..first.dimension())
+        .map(|axis| first.component(axis) - second.component(axis))
+        .fold(R::zero(), |sum, difference| sum + difference * difference)
+        .sqrt()
+}
+
+/// Returns the element moved by the given factor of the given direction, component-wise.
+fn shift<T, R>(element: T, direction: T, factor: R) -> T
+where
+    T: Components<R> + Copy,
+    R: Real,
+{
+    let mut shifted = element;
+    for axis in 0..element.dimension() {
+        shifted.set_component(
+            axis,
+            element.component(axis) + direction.component(axis) * factor,
+        );
+    }
+    shifted
+}
+
+/// Returns the largest parameter below the end of the interval, for which the curve is still evaluated
+/// in the interval, such derivatives at knots are taken from the left.
+fn before<R>(start: R, end: R) -> R
+where
+    R: Real,
+{
+    end - end.abs().max(end - start) * R::epsilon()
+}
+
+/// Struct to approximate the offset curve of a planar curve.
+struct Offsetter<'a, C, R> {
+    curve: &'a C,
+    distance: R,
+    tolerance: R,
+}
+
+impl<C, T, R> Offsetter<'_, C, R>
+where
+    C: Differentiable<R, Output = T>,
+    T: Components<R> + Merge<R> + Default + Copy,
+    R: Real + FromPrimitive,
+{
+    /// Returns the point of the offset curve at the given parameter and its derivative.
+    ///
+    /// With the normal being the tangent rotated counterclockwise, the derivative of the offset curve
+    /// is the derivative of the curve stretched by one minus the product of the distance and the signed curvature.
+    fn gen(&self, scalar: R) -> [T; 2] {
+        let [point, velocity, acceleration] = self.curve.gen_with_derivatives::<3>(scalar);
+        let (dx, dy) = (velocity.component(0), velocity.component(1));
+        let speed = dx.hypot(dy);
+        let mut offset = point;
+        offset.set_component(0, point.component(0) - dy / speed * self.distance);
+        offset.set_component(1, point.component(1) + dx / speed * self.distance);
+        let stretch = self.stretch(velocity, acceleration);
+        let mut tangent = velocity;
+        tangent.set_component(0, dx * stretch);
+        tangent.set_component(1, dy * stretch);
+        [offset, tangent]
+    }
+
+    /// Returns the factor with which the tangent of the offset curve is stretched in comparison to the curve.
+    ///
+    /// The offset curve has a cusp where this factor changes its sign.
+    fn stretch(&self, velocity: T, acceleration: T) -> R {
+        let (dx, dy) = (velocity.component(0), velocity.component(1));
+        let speed = dx.hypot(dy);
+        let cross = dx * acceleration.component(1) - dy * acceleration.component(0);
+        R::one() - self.distance * cross / (speed * speed * speed)
+    }
+
+    /// Returns the stretch at the given parameter.
+    fn stretch_at(&self, scalar: R) -> R {
+        let [_, velocity, acceleration] = self.curve.gen_with_derivatives::<3>(scalar);
+        self.stretch(velocity, acceleration)
+    }
+
+    /// Returns the parameters inside the given interval at which the offset curve has cusps.
+    fn cusps(&self, start: R, end: R) -> Vec<R> {
+        let samples = R::from_usize(CUSP_SAMPLES).unwrap();
+        let mut cusps = Vec::new();
+        let mut low = start;
+        let mut low_sign = self.stretch_at(low) < R::zero();
+        for i in 1..=CUSP_SAMPLES {
+            let high = start + (end - start) * R::from_usize(i).unwrap() / samples;
+            let high_sign = if i == CUSP_SAMPLES {
+                self.stretch_at(before(start, end)) < R::zero()
+            } else {
+                self.stretch_at(high) < R::zero()
+            };
+            if low_sign != high_sign {
+                let (mut left, mut right) = (low, high);
+                for _ in 0..MAX_ITERATIONS {
+                    let middle = (left + right) / (R::one() + R::one());
+                    if middle <= left || middle >= right {
+                        break;
+                    }
+                    if (self.stretch_at(middle) < R::zero()) == low_sign {
+                        left = middle;
+                    } else {
+                        right = middle;
+                    }
+                }
+                cusps.push((left + right) / (R::one() + R::one()));
+            }
+            low = high;
+            low_sign = high_sign;
+        }
+        cusps
+    }
+
+    /// Adds cubic bezier curves approximating the offset curve in the given interval to the path.
+    ///
+    /// The elements are given by the points and derivatives of the offset curve at the borders of the interval,
+    /// whereby the derivative at the end is taken from inside the interval.
+    /// If the curve is not close enough to the offset curve, the interval is split in half.
+    fn approximate(
+        &self,
+        start: R,
+        end: R,
+        depth: usize,
+        path: &mut Vec<([R; 2], CubicBezier<R, T>)>,
+    ) {
+        let [first, first_tangent] = self.gen(start);
+        let [last, _] = self.gen(end);
+        let [_, last_tangent] = self.gen(before(start, end));
+        let third = (end - start) / R::from_usize(3).unwrap();
+        let bezier = Bezier::new_unchecked(
+            [
+                first,
+                shift(first, first_tangent, third),
+                shift(last, last_tangent, -third),
+                last,
+            ],
+            ConstSpace::new(),
+        );
+        let samples = R::from_usize(ERROR_SAMPLES).unwrap();
+        let error = (1..ERROR_SAMPLES)
+            .map(|i| {
+                let scalar = R::from_usize(i).unwrap() / samples;
+                let [point, _] = self.gen(start + (end - start) * scalar);
+                distance(bezier.gen(scalar), point)
+            })
+            .fold(R::zero(), |max, error| max.max(error));
+        if error <= self.tolerance || depth >= MAX_DEPTH {
+            path.push(([start, end], bezier));
+            return;
+        }
+        let middle = (start + end) / (R::one() + R::one());
+        self.approximate(start, middle, depth + 1, path);
+        self.approximate(middle, end, depth + 1, path);
+    }
+}
+
+/// Removes the loops of the path which contain a cusp of the offset curve.
+///
+/// Beyond a cusp, the offset curve runs backwards until the next cusp, which creates a loop.
+/// The parts of the path between the crossings of such loops are removed.
+fn trim<T, R>(
+    path: Vec<([R; 2], CubicBezier<R, T>)>,
+    cusps: &[R],
+    tolerance: R,
+) -> Vec<CubicBezier<R, T>>
+where
+    T: Components<R> + Merge<R> + Default + Copy,
+    R: Real + FromPrimitive,
+{
+    if cusps.is_empty() {
+        return IntoIterator::into_iter(path)
+            .map(|(_, bezier)| bezier)
+            .collect();
+    }
+    let pieces: Vec<Piece<T, R>> = path
+        .as_slice()
+        .iter()
+        .map(|(interval, bezier)| Piece::new(*interval, bezier.elements.to_vec()))
+        .collect();
+    // neighbouring curves only touch at their common border
+    let mut crossings: Vec<(R, R)> = Vec::new();
+    for i in 0..pieces.len() {
+        for j in i + 2..pieces.len() {
+            crossings.extend(intersections(&pieces[i..=i], &pieces[j..=j], tolerance));
+        }
+    }
+    crossings.retain(|&(first, second)| cusps.iter().any(|&cusp| first < cusp && cusp < second));
+    crossings.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+    // collect the parts of the domain to keep
+    let mut from = path[0].0[0];
+    let mut parts = Vec::new();
+    for (first, second) in crossings {
+        if first > from {
+            parts.push([from, first]);
+            from = second;
+        }
+    }
+    parts.push([from, path[path.len() - 1].0[1]]);
+    let mut trimmed = Vec::new();
+    for [from, to] in parts {
+        for ([start, end], bezier) in &path {
+            if *end <= from || *start >= to {
+                continue;
+            }
+            let low = ((from - *start) / (*end - *start)).max(R::zero());
+            let high = ((to - *start) / (*end - *start)).min(R::one());
+            if low.is_zero() && high == R::one() {
+                trimmed.push(*bezier);
+                continue;
+            }
+            let (left, _) = split_elements(bezier.elements.to_vec(), high);
+            let (_, part) = split_elements(left, low / high);
+            trimmed.push(Bezier::new_unchecked(
+                [part[0], part[1], part[2], part[3]],
+                ConstSpace::new(),
+            ));
+        }
+    }
+    trimmed
+}
+
+/// Returns a path of cubic bezier curves approximating the offset curve of the given curve.
+///
+/// The domain is split at the given sorted parameters, which have to contain the borders of the domain,
+/// and at the cusps of the offset curve.
+pub(crate) fn offset_curve<C, T, R>(
+    curve: &C,
+    breaks: &[R],
+    distance: R,
+    tolerance: R,
+) -> Vec<CubicBezier<R, T>>
+where
+    C: Differentiable<R, Output = T>,
+    T: Components<R> + Merge<R> + Default + Copy,
+    R: Real + FromPrimitive,
+{
+    let offsetter = Offsetter {
+        curve,
+        distance,
+        tolerance,
+    };
+    let mut parameters = Vec::new();
+    let mut cusps = Vec::new();
+    for window in breaks.windows(2) {
+        parameters.push(window[0]);
+        let found = offsetter.cusps(window[0], window[1]);
+        parameters.extend(found.as_slice());
+        cusps.extend(found);
+    }
+    parameters.push(breaks[breaks.len() - 1]);
+    let mut path = Vec::new();
+    for window in parameters.windows(2) {
+        offsetter.approximate(window[0], window[1], 0, &mut path);
+    }
+    trim(path, &cusps, tolerance)
+}
+
+impl<R, E, S> Bezier<R, E, S>
+where
+    E: DiscreteGenerator,
+    E::Output: Components<R>
+        + Merge<R>
+        + Mul<R, Output = E::Output>
+        + Sub<Output = E::Output>
+        + Default
+        + Copy,
+    S: Space<E::Output>,
+    R: Real + FromPrimitive,
+{
+    /// Returns a path of cubic bezier curves approximating the offset curve in the given distance.
+    ///
+    /// The offset curve consists of the points moved by the given distance along the normal of the curve,
+    /// which is the tangent rotated counterclockwise. Such positive distances offset to the left of the curve
+    /// and negative distances to the right. Only the first two components of the elements are offset,
+    /// such the curve has to be planar in them.
+    ///
+    /// Every cubic bezier curve is constructed from the points and tangents of the offset curve at its borders
+    /// and is split in half until its distance to the offset curve, measured at a few samples, is within the tolerance.
+    /// Where the distance is larger than the radius of curvature, the offset curve has cusps and runs backwards,
+    /// creating a loop. Loops containing a cusp are trimmed at their self-intersection.
+    ///
+    /// The curve is expected to be regular, that is its derivative is never zero.
+    ///
+    /// # Examples
+    ///
+    /// The elements are points in two dimensions as given in the examples of [`Components`].
+    ///
+    /// ```rust
+    /// # use core::ops::{Add, Mul, Sub};
+    /// # use enterpolation::{bezier::{Bezier, BezierError}, Components, Generator};
+    /// #
+    /// # #[derive(Debug, Copy, Clone, Default, PartialEq)]
+    /// # struct Point(f64, f64);
+    /// # impl Add for Point {
+    /// #     type Output = Self;
+    /// #     fn add(self, o: Self) -> Self { Point(self.0 + o.0, self.1 + o.1) }
+    /// # }
+    /// # impl Sub for Point {
+    /// #     type Output = Self;
+    /// #     fn sub(self, o: Self) -> Self { Point(self.0 - o.0, self.1 - o.1) }
+    /// # }
+    /// # impl Mul<f64> for Point {
+    /// #     type Output = Self;
+    /// #     fn mul(self, s: f64) -> Self { Point(self.0 * s, self.1 * s) }
+    /// # }
+    /// # impl Components<f64> for Point {
+    /// #     fn dimension(&self) -> usize { 2 }
+    /// #     fn component(&self, index: usize) -> f64 { [self.0, self.1][index] }
+    /// #     fn set_component(&mut self, index: usize, value: f64) {
+    /// #         match index { 0 => self.0 = value, _ => self.1 = value }
+    /// #     }
+    /// # }
+    /// # fn main() -> Result<(), BezierError> {
+    /// let curve = Bezier::builder()
+    ///     .elements([Point(0.0, 0.0), Point(1.0, 2.0), Point(3.0, -1.0), Point(4.0, 1.0)])
+    ///     .normalized::<f64>()
+    ///     .constant()
+    ///     .build()?;
+    /// let path = curve.offset(0.25, 1e-4);
+    /// // the path starts above the start of the curve, as the curve starts going up and right
+    /// let start = path[0].gen(0.0);
+    /// let normal = Point(-2.0, 1.0) * (0.25 / 5.0_f64.sqrt());
+    /// assert!((start.0 - normal.0).abs() < 1e-12 && (start.1 - normal.1).abs() < 1e-12);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn offset(&self, distance: R, tolerance: R) -> Vec<CubicBezier<R, E::Output>> {
+        offset_curve(self, &[R::zero(), R::one()], distance, tolerance)
+    }
+}
+
+impl<R, E, S, T> Weighted<Bezier<R, E, S>>
+where
+    E: DiscreteGenerator<Output = Homogeneous<T, R>>,
+    S: Space<E::Output>,
+    T: Components<R>
+        + Default
+        + Add<Output = T>
+        + Mul<R, Output = T>
+        + Sub<Output = T>
+        + Div<R, Output = T>
+        + Copy,
+    R: Real + FromPrimitive,
+{
+    /// Returns a path of cubic bezier curves approximating the offset curve of the rational curve
+    /// in the given distance.
+    ///
+    /// See [`Bezier::offset()`] for more information.
+    pub fn offset(&self, distance: R, tolerance: R) -> Vec<CubicBezier<R, T>> {
+        offset_curve(self, &[R::zero(), R::one()], distance, tolerance)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::Bezier;
+    use crate::test_util::{distance, Point};
+    use crate::{Curve, Generator};
+
+    #[test]
+    fn weighted() {
+        // the quarter of the unit circle, running counterclockwise
+        let arc = Bezier::builder()
+            .elements_with_weights([
+                (Point(1.0, 0.0), 1.0),
+                (Point(1.0, 1.0), 0.5_f64.sqrt()),
+                (Point(0.0, 1.0), 1.0),
+            ])
+            .normalized::<f64>()
+            .constant()
+            .build()
+            .unwrap();
+        let tolerance = 1e-6;
+        for (offset, radius) in [(0.5, 0.5), (-0.5, 1.5)] {
+            let path = arc.offset(offset, tolerance);
+            assert!(distance(path[0].gen(0.0), Point(radius, 0.0)) < 1e-12);
+            for bezier in path.iter() {
+                for point in bezier.take(11) {
+                    assert!((distance(point, Point(0.0, 0.0)) - radius).abs() < 2.0 * tolerance);
+                }
+            }
+            let end = path[path.len() - 1].gen(1.0);
+            assert!(distance(end, Point(0.0, radius)) < 1e-12);
+        }
+    }
+
+    #[test]
+    fn cusps() {
+        // the parabola y = x² has a radius of curvature of 0.5 at its vertex
+        let parabola = Bezier::builder()
+            .elements([Point(-1.0, 1.0), Point(0.0, -1.0), Point(1.0, 1.0)])
+            .normalized::<f64>()
+            .constant()
+            .build()
+            .unwrap();
+        let offset = 0.8;
+        let tolerance = 1e-5;
+        let path = parabola.offset(offset, tolerance);
+        let samples: Vec<Point> = parabola.take(20001).collect();
+        for pair in path.windows(2) {
+            assert!(distance(pair[0].gen(1.0), pair[1].gen(0.0)) < 2.0 * tolerance);
+        }
+        // the loop behind the cusps is removed, such no point is closer to the parabola than the offset
+        for bezier in path.iter() {
+            for point in bezier.take(21) {
+                let closest = samples
+                    .iter()
+                    .map(|&sample| distance(sample, point))
+                    .fold(f64::MAX, f64::min);
+                assert!(closest > offset - 1e-3);
+            }
+        }
+        // the trimmed path meets itself on the axis of the parabola
+        let middle = path
+            .iter()
+            .flat_map(|bezier| [bezier.gen(0.0), bezier.gen(1.0)])
+            .filter(|point| point.0.abs() < 1e-3)
+            .count();
+        assert!(middle >= 2);
+        // without cusps nothing is trimmed
+        let path = parabola.offset(-offset, tolerance);
+        let [start, end] = parabola.domain();
+        assert!(distance(path[0].gen(0.0), parabola.gen(start)) > offset - 1e-12);
+        assert!(distance(path[path.len() - 1].gen(1.0), parabola.gen(end)) > offset - 1e-12);
+    }
+}
@@ -1,5 +1,6 @@
 use crate::builder::TooFewElements;
 use crate::{DiscreteGenerator, Generator, SortedGenerator};
+use core::ops::{Add, Sub};
 
 /// DiscreteGenerator Adaptor which repeats its first and last element `n` more times.
 #[derive(Debug, Copy, Clone)]
@@ -145,10 +146,72 @@ where
     }
 }
 
+/// DiscreteGenerator Adaptor which periodically extends sorted knots by `n` elements on both sides.
+///
+/// The period is given by the difference of the last and the first element of the underlying generator.
+/// That is, the last `n` intervals are prepended and the first `n` intervals are appended.
+///
+/// # Panics
+///
+/// Using this Generator may cause a panic if the underlying generator has not more than `n` elements.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Periodic<G> {
+    inner: G,
+    n: usize,
+}
+
+impl<G> Periodic<G>
+where
+    G: DiscreteGenerator,
+{
+    /// Creates a generator which extends the given generator periodically by `n` elements on both sides.
+    pub fn new(inner: G, n: usize) -> Self {
+        Periodic { inner, n }
+    }
+}
+
+impl<G> Generator<usize> for Periodic<G>
+where
+    G: DiscreteGenerator,
+    G::Output: Add<Output = G::Output> + Sub<Output = G::Output>,
+{
+    type Output = G::Output;
+    fn gen(&self, input: usize) -> Self::Output {
+        let last = self.inner.len() - 1;
+        if input < self.n {
+            let period = self.inner.gen(last) - self.inner.gen(0);
+            return self.inner.gen(last + input - self.n) - period;
+        }
+        if input - self.n > last {
+            let period = self.inner.gen(last) - self.inner.gen(0);
+            return self.inner.gen(input - self.n - last) + period;
+        }
+        self.inner.gen(input - self.n)
+    }
+}
+
+impl<G> DiscreteGenerator for Periodic<G>
+where
+    G: DiscreteGenerator,
+    G::Output: Add<Output = G::Output> + Sub<Output = G::Output>,
+{
+    fn len(&self) -> usize {
+        self.inner.len() + 2 * self.n
+    }
+}
+
+impl<G> SortedGenerator for Periodic<G>
+where
+    G: SortedGenerator,
+    G::Output: Add<Output = G::Output> + Sub<Output = G::Output>,
+{
+}
+
 #[cfg(test)]
 mod test {
-    use super::{BorderBuffer, BorderDeletion};
-    use crate::{DiscreteGenerator, Equidistant, SortedGenerator};
+    use super::{BorderBuffer, BorderDeletion, Periodic};
+    use crate::{DiscreteGenerator, Equidistant, Sorted, SortedGenerator};
 
     #[test]
     fn borderdeletion() {
@@ -177,4 +240,16 @@ mod test {
         assert_eq!(buf.strict_upper_bound_clamped(0.8, 1, 5), 5);
         assert_eq!(buf.strict_upper_bound_clamped(0.45, 3, 9), 8);
     }
+
+    #[test]
+    fn periodic() {
+        let per = Periodic::new(Sorted::new_unchecked([0.0, 1.0, 3.0, 4.0]), 2);
+        assert_eq!(per.len(), 8);
+        let expect = [-3.0, -1.0, 0.0, 1.0, 3.0, 4.0, 5.0, 7.0];
+        for (val, exp) in per.iter().zip(expect.iter()) {
+            assert_f64_near!(val, exp);
+        }
+        assert_eq!(per.strict_upper_bound(-2.0), 1);
+        assert_eq!(per.strict_upper_bound(4.5), 6);
+    }
 }
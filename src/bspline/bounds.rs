@@ -0,0 +1,242 @@
+//! Exact bounds and extrema of bsplines, calculated on the bezier curves of their knot spans.
+
+use super::BSpline;
+use crate::base::{bounds, extrema, Piece};
+use crate::weights::{Homogeneous, Weighted};
+use crate::{Components, Curve, DiscreteGenerator, Extrema, Generator, SortedGenerator, Space};
+use core::fmt::Debug;
+use num_traits::real::Real;
+use num_traits::FromPrimitive;
+use topology_traits::Merge;
+
+impl<K, E, S, R> BSpline<K, E, S>
+where
+    E: DiscreteGenerator,
+    E::Output: Merge<R> + Copy,
+    S: Space<E::Output>,
+    K: SortedGenerator<Output = R>,
+    R: Real + FromPrimitive + Debug,
+{
+    /// Returns the smallest axis-aligned box containing the curve in its domain,
+    /// given by its minimal and maximal corner.
+    ///
+    /// In contrast to the box given by the elements, this box is tight. Every knot span is
+    /// transformed into a bezier curve, for which the roots of the derivative are searched
+    /// component-wise and the curve is evaluated at them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bspline::{BSpline, BSplineError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BSplineError> {
+    /// let bspline = BSpline::builder()
+    ///                 .clamped()
+    ///                 .elements([0.0,4.0,-4.0,0.0])
+    ///                 .equidistant::<f64>()
+    ///                 .degree(2)
+    ///                 .normalized()
+    ///                 .constant::<3>()
+    ///                 .build()?;
+    /// let [min, max] = bspline.bounds();
+    /// assert!(min > -4.0 && max < 4.0);
+    /// assert_f64_near!(max, bspline.take(1001).fold(f64::MIN, f64::max), 100);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn bounds(&self) -> [E::Output; 2]
+    where
+        E::Output: Components<R>,
+    {
+        bounds(&self.pieces())
+    }
+
+    /// Returns the knot spans of the curve as pieces.
+    pub(super) fn pieces(&self) -> Vec<Piece<E::Output, R>> {
+        let mut pieces: Vec<_> = self
+            .bezier_spans()
+            .into_iter()
+            .map(|(span, elements)| Piece::new(span, elements))
+            .collect();
+        if pieces.is_empty() {
+            // the domain consists of a single point
+            let [start, end] = self.domain();
+            pieces.push(Piece::new([start, end], vec![self.gen(start)]));
+        }
+        pieces
+    }
+}
+
+impl<K, E, S, R> BSpline<K, E, S>
+where
+    E: DiscreteGenerator<Output = R>,
+    S: Space<R>,
+    K: SortedGenerator<Output = R>,
+    R: Real + FromPrimitive + Debug,
+{
+    /// Returns the local minima and maxima of the scalar curve in its domain.
+    ///
+    /// The extrema are found at the roots of the derivative, the borders of the domain and at knots,
+    /// at which the curve may not be differentiable.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bspline::{BSpline, BSplineError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BSplineError> {
+    /// let bspline = BSpline::builder()
+    ///                 .clamped()
+    ///                 .elements([0.0,4.0,-4.0,0.0])
+    ///                 .equidistant::<f64>()
+    ///                 .degree(2)
+    ///                 .normalized()
+    ///                 .constant::<3>()
+    ///                 .build()?;
+    /// let extrema = bspline.extrema();
+    /// let (parameter, _) = extrema.maxima()[0];
+    /// assert!(bspline.gen_with_tangent(parameter)[1].abs() < 1e-9);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn extrema(&self) -> Extrema<R> {
+        extrema(&self.pieces())
+    }
+}
+
+impl<K, E, S, T, R> Weighted<BSpline<K, E, S>>
+where
+    E: DiscreteGenerator<Output = Homogeneous<T, R>>,
+    E::Output: Merge<R> + Copy,
+    S: Space<E::Output>,
+    K: SortedGenerator<Output = R>,
+    T: Components<R> + Copy,
+    R: Real + FromPrimitive + Debug,
+{
+    /// Returns the smallest axis-aligned box containing the rational curve in its domain,
+    /// given by its minimal and maximal corner.
+    ///
+    /// For every component, the roots of the numerator of the derivative given by the quotient rule are searched.
+    pub fn bounds(&self) -> [T; 2] {
+        let pieces: Vec<_> = self
+            .inner_ref()
+            .pieces()
+            .into_iter()
+            .map(Piece::rational)
+            .collect();
+        bounds(&pieces)
+    }
+}
+
+impl<K, E, S, R> Weighted<BSpline<K, E, S>>
+where
+    E: DiscreteGenerator<Output = Homogeneous<R, R>>,
+    S: Space<Homogeneous<R, R>>,
+    K: SortedGenerator<Output = R>,
+    R: Real + FromPrimitive + Debug,
+{
+    /// Returns the local minima and maxima of the scalar rational curve in its domain.
+    pub fn extrema(&self) -> Extrema<R> {
+        let pieces: Vec<_> = self
+            .inner_ref()
+            .pieces()
+            .into_iter()
+            .map(Piece::rational)
+            .collect();
+        extrema(&pieces)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::BSpline;
+    use crate::{Curve, Generator};
+
+    /// Returns the minimum and maximum of the given values.
+    fn range(values: impl Iterator<Item = f64>) -> (f64, f64) {
+        values.fold((f64::MAX, f64::MIN), |(low, high), x| {
+            (low.min(x), high.max(x))
+        })
+    }
+
+    #[test]
+    fn bounds() {
+        let spline = BSpline::builder()
+            .elements([0.0, 5.0, -3.0, 10.0, 7.0, 1.0])
+            .knots([0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0])
+            .constant::<4>()
+            .build()
+            .unwrap();
+        let [min, max] = spline.bounds();
+        let (low, high) = range(spline.take(10001));
+        assert!(min <= low && low - min < 1e-6);
+        assert!(high <= max && max - high < 1e-6);
+        // multiple knots create kinks
+        let kinked = BSpline::builder()
+            .clamped()
+            .elements([0.0, 1.0, 0.0, -1.0])
+            .knots([0.0, 1.0, 1.0, 2.0])
+            .constant::<3>()
+            .build()
+            .unwrap();
+        assert_eq!(kinked.bounds(), [-1.0, 1.0]);
+        let extrema = kinked.extrema();
+        assert_eq!(extrema.maxima(), &[(1.0, 1.0)]);
+        assert_eq!(extrema.minima(), &[(0.0, 0.0), (2.0, -1.0)]);
+    }
+
+    #[test]
+    fn extrema() {
+        let spline = BSpline::builder()
+            .elements([0.0, 5.0, -3.0, 10.0, 7.0, 1.0])
+            .knots([0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0])
+            .constant::<4>()
+            .build()
+            .unwrap();
+        let extrema = spline.extrema();
+        let [start, end] = spline.domain();
+        let mut all: Vec<_> = extrema
+            .minima()
+            .iter()
+            .chain(extrema.maxima())
+            .copied()
+            .collect();
+        all.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+        // minima and maxima alternate
+        for pair in all.windows(2) {
+            let is_minimum = |x: (f64, f64)| extrema.minima().contains(&x);
+            assert_ne!(is_minimum(pair[0]), is_minimum(pair[1]));
+        }
+        for (parameter, value) in all {
+            assert_f64_near!(spline.gen(parameter), value);
+            if parameter > start && parameter < end {
+                assert!(spline.gen_with_tangent(parameter)[1].abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn weighted() {
+        let spline = BSpline::builder()
+            .clamped()
+            .elements_with_weights([(0.0, 1.0), (5.0, 2.0), (3.0, 0.5), (10.0, 1.0), (-1.0, 3.0)])
+            .equidistant::<f64>()
+            .degree(3)
+            .normalized()
+            .constant::<4>()
+            .build()
+            .unwrap();
+        let [min, max] = spline.bounds();
+        let (low, high) = range(spline.take(10001));
+        assert!(min <= low && low - min < 1e-6);
+        assert!(high <= max && max - high < 1e-6);
+        let extrema = spline.extrema();
+        for &(parameter, value) in extrema.minima().iter().chain(extrema.maxima()) {
+            assert!((spline.gen(parameter) - value).abs() < 1e-10);
+        }
+    }
+}
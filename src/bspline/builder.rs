@@ -2,20 +2,18 @@
 //!
 //! Each interpolation has it's own builder module, which accumalates all methods to create their interpolation.
 
-use super::adaptors::{BorderBuffer, BorderDeletion};
+use super::adaptors::{BorderBuffer, BorderDeletion, Periodic};
 use super::error::{
     BSplineError, IncongruousElementsDegree, IncongruousElementsKnots, InvalidDegree, TooFewKnots,
 };
 use super::{BSpline, TooFewElements, TooSmallWorkspace};
 use crate::builder::{Type, Unknown, WithWeight, WithoutWeight};
-use crate::weights::{Homogeneous, IntoWeight, Weighted, Weights};
+use crate::weights::{IntoWeight, Project, Weighted, Weights};
 #[cfg(feature = "std")]
 use crate::DynSpace;
-use crate::{
-    ConstSpace, DiscreteGenerator, Equidistant, Generator, Sorted, SortedGenerator, Space,
-};
+use crate::{ConstSpace, DiscreteGenerator, Equidistant, Sorted, SortedGenerator, Space, Wrap};
 use core::marker::PhantomData;
-use core::ops::{Div, Mul};
+use core::ops::{Add, Mul, Sub};
 use num_traits::identities::Zero;
 use num_traits::real::Real;
 use num_traits::FromPrimitive;
@@ -34,9 +32,10 @@ pub struct Open;
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Legacy;
-// #[derive(Debug, Clone, Copy)]
-// #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
-// pub struct Closed;
+/// Marker struct to signify the building of a closed curve, that is a seamless loop.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Closed;
 
 /// Marker Struct which saves data for equidistant.
 ///
@@ -67,6 +66,25 @@ impl<R> UnknownDomain<R> {
     }
 }
 
+/// Marker Struct which saves the knots of a closed curve.
+///
+/// For closed curves, the degree can not be deduced from the number of knots and elements.
+/// Such, it has to be given explicitly before the knots can be extended periodically.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct UnknownDegree<K> {
+    knots: K,
+}
+
+impl<K> UnknownDegree<K> {
+    pub fn new(knots: K) -> Self {
+        UnknownDegree { knots }
+    }
+    pub fn into_inner(self) -> K {
+        self.knots
+    }
+}
+
 /// Builder for bspline interpolation.
 ///
 /// This struct helps create bspline interpolations. The difference between this struct and [`BSplineBuilder`]
@@ -74,15 +92,15 @@ impl<R> UnknownDomain<R> {
 ///
 /// Before building, one has to give information for:
 /// - The elements the interpolation should use. Methods like [`elements()`] and [`elements_with_weights`()]
-///     exist for that cause.
+///   exist for that cause.
 /// - The knots the interpolation uses. Either by giving them directly with [`knots()`] or by using
-///     equidistant knots with [`equidistant()`].
+///   equidistant knots with [`equidistant()`].
 /// - A workspace to use, that is, a mutable slice-like object to do operations on.
-///     Usually this is done by calling [`constant()`] or [`dynamic()`].
-///     [`workspace()`] is also posbbile for a custom workspace.
+///   Usually this is done by calling [`constant()`] or [`dynamic()`].
+///   [`workspace()`] is also posbbile for a custom workspace.
 ///
-/// Furthermore one may want to use different modes, toggled by the methods [`open()`],[`clamped()`]
-/// and [`legacy()`], where [`open()`] is the default one.
+/// Furthermore one may want to use different modes, toggled by the methods [`open()`],[`clamped()`],
+/// [`legacy()`] and [`closed()`], where [`open()`] is the default one.
 ///
 /// [`build()`]: BSplineDirector::build()
 /// [`BSplineBuilder`]: BSplineBuilder
@@ -96,6 +114,7 @@ impl<R> UnknownDomain<R> {
 /// [`open()`]: BSplineDirector::open()
 /// [`clamped()`]: BSplineDirector::clamped()
 /// [`legacy()`]: BSplineDirector::legacy()
+/// [`closed()`]: BSplineDirector::closed()
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct BSplineDirector<K, E, S, W, M> {
@@ -113,15 +132,15 @@ pub struct BSplineDirector<K, E, S, W, M> {
 ///
 /// Before building, one has to give information for:
 /// - The elements the interpolation should use. Methods like [`elements()`] and [`elements_with_weights`()]
-///     exist for that cause.
+///   exist for that cause.
 /// - The knots the interpolation uses. Either by giving them directly with [`knots()`] or by using
-///     equidistant knots with [`equidistant()`].
+///   equidistant knots with [`equidistant()`].
 /// - A workspace to use, that is, a mutable slice-like object to do operations on.
-///     Usually this is done by calling [`constant()`] or [`dynamic()`].
-///     [`workspace()`] is also posbbile for a custom workspace.
+///   Usually this is done by calling [`constant()`] or [`dynamic()`].
+///   [`workspace()`] is also posbbile for a custom workspace.
 ///
-/// Furthermore one may want to use different modes, toggled by the methods [`open()`],[`clamped()`]
-/// and [`legacy()`], where [`open()`] is the default one.
+/// Furthermore one may want to use different modes, toggled by the methods [`open()`],[`clamped()`],
+/// [`legacy()`] and [`closed()`], where [`open()`] is the default one.
 ///
 /// [`build()`]: BSplineBuilder::build()
 /// [`builder()`]: super::BSpline::builder()
@@ -135,6 +154,7 @@ pub struct BSplineDirector<K, E, S, W, M> {
 /// [`open()`]: BSplineBuilder::open()
 /// [`clamped()`]: BSplineBuilder::clamped()
 /// [`legacy()`]: BSplineBuilder::legacy()
+/// [`closed()`]: BSplineBuilder::closed()
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct BSplineBuilder<K, E, S, W, M> {
@@ -210,12 +230,20 @@ impl<M> BSplineDirector<Unknown, Unknown, Unknown, Unknown, M> {
         }
     }
 
-    // /// Ensure the curve to be a loop, that is, its start and end point are equal and have a smooth transition.
-    // ///
-    // /// This method changes the underlying knot and element generator, by repeating some.
-    // pub fn loop(self) -> BSplineDirector<K,E, Unknown, W>{
-    //
-    // }
+    /// Change the mode to a closed curve.
+    ///
+    /// The curve becomes a loop, that is, its start and end point are equal and
+    /// the transition between them is as smooth as anywhere else on the curve.
+    /// To achieve this, the elements and knots are repeated periodically, such that
+    /// the first element does not have to be duplicated by hand.
+    pub fn closed(self) -> BSplineDirector<Unknown, Unknown, Unknown, Unknown, Closed> {
+        BSplineDirector {
+            knots: self.knots,
+            space: self.space,
+            elements: self.elements,
+            _phantoms: (self._phantoms.0, PhantomData),
+        }
+    }
 
     /// Set the elements of the bspline interpolation.
     ///
@@ -307,12 +335,17 @@ impl<M> BSplineBuilder<Unknown, Unknown, Unknown, Unknown, M> {
         }
     }
 
-    // /// Ensure the curve to be a loop, that is, its start and end point are equal and have a smooth transition.
-    // ///
-    // /// This method changes the underlying knot and element generator, by repeating some.
-    // pub fn loop(self) -> BSplineDirector<K,E, Unknown, W>{
-    //
-    // }
+    /// Change the mode to a closed curve.
+    ///
+    /// The curve becomes a loop, that is, its start and end point are equal and
+    /// the transition between them is as smooth as anywhere else on the curve.
+    /// To achieve this, the elements and knots are repeated periodically, such that
+    /// the first element does not have to be duplicated by hand.
+    pub fn closed(self) -> BSplineBuilder<Unknown, Unknown, Unknown, Unknown, Closed> {
+        BSplineBuilder {
+            inner: self.inner.map(|director| director.closed()),
+        }
+    }
 
     /// Set the elements of the bspline interpolation.
     pub fn elements<E>(self, elements: E) -> BSplineBuilder<Unknown, E, Unknown, WithoutWeight, M>
@@ -559,6 +592,125 @@ impl<E, W> BSplineBuilder<Unknown, E, Unknown, W, Legacy> {
     }
 }
 
+impl<E, W> BSplineDirector<Unknown, E, Unknown, W, Closed> {
+    /// Set the knots of the interpolation.
+    ///
+    /// For closed curves, the knots define one period of the loop and there
+    /// has to be exactly one knot more than there are elements.
+    /// As the degree can not be deduced from the number of knots, one has to call [`degree()`] afterwards.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NotSorted`] if a knot is not greater or equal then the knot before him.
+    /// Returns [`TooFewKnots`] if not at least 2 knots are given.
+    /// Returns [`IncongruousElementsKnots`] if the number of knots is not one more than the number of elements.
+    ///
+    /// # Performance
+    ///
+    /// If you have equidistant knots, near equidistant knots are you do not really care about
+    /// knots, consider using [`equidistant()`] instead.
+    ///
+    /// [`degree()`]: BSplineDirector::degree()
+    /// [`equidistant()`]: BSplineDirector::equidistant()
+    /// [`NotSorted`]: super::error::BSplineError
+    /// [`TooFewKnots`]: super::error::BSplineError
+    /// [`IncongruousElementsKnots`]: super::error::BSplineError
+    pub fn knots<K>(self, knots: K) -> Result<UnknownDegreeBSplineDirector<K, E, W>, BSplineError>
+    where
+        E: DiscreteGenerator,
+        K: DiscreteGenerator,
+        K::Output: PartialOrd,
+    {
+        if knots.len() < 2 {
+            return Err(TooFewKnots::new(knots.len()).into());
+        }
+        if knots.len() != self.elements.len() + 1 {
+            return Err(IncongruousElementsKnots::closed(self.elements.len(), knots.len()).into());
+        }
+        Ok(BSplineDirector {
+            knots: UnknownDegree::new(Sorted::new(knots)?),
+            elements: self.elements,
+            space: self.space,
+            _phantoms: self._phantoms,
+        })
+    }
+}
+
+impl<E, W> BSplineBuilder<Unknown, E, Unknown, W, Closed> {
+    /// Set the knots of the interpolation.
+    ///
+    /// For closed curves, the knots define one period of the loop and there
+    /// has to be exactly one knot more than there are elements.
+    /// As the degree can not be deduced from the number of knots, one has to call [`degree()`] afterwards.
+    ///
+    /// # Performance
+    ///
+    /// If you have equidistant knots, near equidistant knots are you do not really care about
+    /// knots, consider using [`equidistant()`] instead.
+    ///
+    /// [`degree()`]: BSplineBuilder::degree()
+    /// [`equidistant()`]: BSplineBuilder::equidistant()
+    pub fn knots<K>(self, knots: K) -> UnknownDegreeBSplineBuilder<K, E, W>
+    where
+        E: DiscreteGenerator,
+        K: DiscreteGenerator,
+        K::Output: PartialOrd,
+    {
+        BSplineBuilder {
+            inner: self.inner.and_then(|director| director.knots(knots)),
+        }
+    }
+}
+
+impl<K, E, W> BSplineDirector<UnknownDegree<K>, E, Unknown, W, Closed>
+where
+    E: DiscreteGenerator,
+    K: SortedGenerator,
+    K::Output: Add<Output = K::Output> + Sub<Output = K::Output>,
+{
+    /// Set the degree of the curve.
+    ///
+    /// The degree of the curve has to be at least 1 and be less than the number of elements.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidDegree`] if given degree is 0.
+    /// Returns [`IncongruousElementsDegree`] if degree is not less than the number of elements.
+    ///
+    /// [`InvalidDegree`]: super::error::BSplineError
+    /// [`IncongruousElementsDegree`]: super::error::BSplineError
+    pub fn degree(self, degree: usize) -> Result<ClosedBSplineDirector<K, E, W>, BSplineError> {
+        if degree < 1 {
+            return Err(InvalidDegree::new(degree).into());
+        }
+        if self.elements.len() <= degree {
+            return Err(IncongruousElementsDegree::closed(self.elements.len(), degree).into());
+        }
+        Ok(BSplineDirector {
+            knots: Periodic::new(self.knots.into_inner(), degree - 1),
+            elements: Wrap::new(self.elements, degree),
+            space: self.space,
+            _phantoms: self._phantoms,
+        })
+    }
+}
+
+impl<K, E, W> BSplineBuilder<UnknownDegree<K>, E, Unknown, W, Closed>
+where
+    E: DiscreteGenerator,
+    K: SortedGenerator,
+    K::Output: Add<Output = K::Output> + Sub<Output = K::Output>,
+{
+    /// Set the degree of the curve.
+    ///
+    /// The degree of the curve has to be at least 1 and be less than the number of elements.
+    pub fn degree(self, degree: usize) -> ClosedBSplineBuilder<K, E, W> {
+        BSplineBuilder {
+            inner: self.inner.and_then(|director| director.degree(degree)),
+        }
+    }
+}
+
 impl<E, W, M> BSplineDirector<Unknown, E, Unknown, W, M> {
     /// Build an interpolation with equidistant knots.
     ///
@@ -896,6 +1048,77 @@ where
     }
 }
 
+impl<R, E, W> BSplineDirector<Type<R>, E, Unknown, W, Closed>
+where
+    E: DiscreteGenerator,
+{
+    /// Set the degree of the curve.
+    ///
+    /// The degree of the curve has to be at least 1 and be less than the number of elements.
+    /// For closed curves, the number of knots is always one more than the number of elements.
+    ///
+    /// After this call, you also have to call either of
+    /// - [`domain()`],
+    /// - [`normalized()`] or
+    /// - [`distance()`],
+    ///
+    /// which all define the domain of the interpolation and the spacing of the knots.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidDegree`] if given degree is 0.
+    /// Returns [`IncongruousElementsDegree`] if degree is not less than the number of elements.
+    ///
+    /// [`InvalidDegree`]: super::error::BSplineError
+    /// [`IncongruousElementsDegree`]: super::error::BSplineError
+    /// [`domain()`]: BSplineDirector::domain()
+    /// [`normalized()`]: BSplineDirector::normalized()
+    /// [`distance()`]: BSplineDirector::distance()
+    pub fn degree(
+        self,
+        degree: usize,
+    ) -> Result<BSplineDirector<UnknownDomain<R>, E, Unknown, W, Closed>, BSplineError> {
+        if degree < 1 {
+            return Err(InvalidDegree::new(degree).into());
+        }
+        if self.elements.len() <= degree {
+            return Err(IncongruousElementsDegree::closed(self.elements.len(), degree).into());
+        }
+        Ok(BSplineDirector {
+            knots: UnknownDomain::new(self.elements.len() + 1, degree),
+            elements: self.elements,
+            space: self.space,
+            _phantoms: self._phantoms,
+        })
+    }
+}
+
+impl<R, E, W> BSplineBuilder<Type<R>, E, Unknown, W, Closed>
+where
+    E: DiscreteGenerator,
+{
+    /// Set the degree of the curve.
+    ///
+    /// The degree of the curve has to be at least 1 and be less than the number of elements.
+    /// For closed curves, the number of knots is always one more than the number of elements.
+    ///
+    /// After this call, you also have to call either of
+    /// - [`domain()`],
+    /// - [`normalized()`] or
+    /// - [`distance()`],
+    ///
+    /// which all define the domain of the interpolation and the spacing of the knots.
+    ///
+    /// [`domain()`]: BSplineBuilder::domain()
+    /// [`normalized()`]: BSplineBuilder::normalized()
+    /// [`distance()`]: BSplineBuilder::distance()
+    pub fn degree(self, degree: usize) -> BSplineBuilder<UnknownDomain<R>, E, Unknown, W, Closed> {
+        BSplineBuilder {
+            inner: self.inner.and_then(|director| director.degree(degree)),
+        }
+    }
+}
+
 impl<R, E, W> BSplineDirector<UnknownDomain<R>, E, Unknown, W, Open>
 where
     E: DiscreteGenerator,
@@ -1055,6 +1278,76 @@ where
     }
 }
 
+impl<R, E, W> BSplineDirector<UnknownDomain<R>, E, Unknown, W, Closed>
+where
+    E: DiscreteGenerator,
+    R: Real + FromPrimitive,
+{
+    /// Set the domain of the interpolation, which is also the period of the loop.
+    pub fn domain(self, start: R, end: R) -> ClosedBSplineDirector<Equidistant<R>, E, W> {
+        BSplineDirector {
+            knots: Periodic::new(
+                Equidistant::new(self.knots.len(), start, end),
+                self.knots.deg() - 1,
+            ),
+            elements: Wrap::new(self.elements, self.knots.deg()),
+            space: self.space,
+            _phantoms: self._phantoms,
+        }
+    }
+
+    /// Set the domain of the interpolation to be [0.0,1.0].
+    pub fn normalized(self) -> ClosedBSplineDirector<Equidistant<R>, E, W> {
+        BSplineDirector {
+            knots: Periodic::new(
+                Equidistant::normalized(self.knots.len()),
+                self.knots.deg() - 1,
+            ),
+            elements: Wrap::new(self.elements, self.knots.deg()),
+            space: self.space,
+            _phantoms: self._phantoms,
+        }
+    }
+    /// Set the domain of the interpolation by defining the distance between the knots.
+    pub fn distance(self, start: R, step: R) -> ClosedBSplineDirector<Equidistant<R>, E, W> {
+        BSplineDirector {
+            knots: Periodic::new(
+                Equidistant::step(self.knots.len(), start, step),
+                self.knots.deg() - 1,
+            ),
+            elements: Wrap::new(self.elements, self.knots.deg()),
+            space: self.space,
+            _phantoms: self._phantoms,
+        }
+    }
+}
+
+impl<R, E, W> BSplineBuilder<UnknownDomain<R>, E, Unknown, W, Closed>
+where
+    E: DiscreteGenerator,
+    R: Real + FromPrimitive,
+{
+    /// Set the domain of the interpolation, which is also the period of the loop.
+    pub fn domain(self, start: R, end: R) -> ClosedBSplineBuilder<Equidistant<R>, E, W> {
+        BSplineBuilder {
+            inner: self.inner.map(|director| director.domain(start, end)),
+        }
+    }
+
+    /// Set the domain of the interpolation to be [0.0,1.0].
+    pub fn normalized(self) -> ClosedBSplineBuilder<Equidistant<R>, E, W> {
+        BSplineBuilder {
+            inner: self.inner.map(|director| director.normalized()),
+        }
+    }
+    /// Set the domain of the interpolation by defining the distance between the knots.
+    pub fn distance(self, start: R, step: R) -> ClosedBSplineBuilder<Equidistant<R>, E, W> {
+        BSplineBuilder {
+            inner: self.inner.map(|director| director.distance(start, step)),
+        }
+    }
+}
+
 impl<K, E, W, M> BSplineDirector<K, E, Unknown, W, M>
 where
     E: DiscreteGenerator,
@@ -1240,15 +1533,12 @@ where
     }
 }
 
-impl<K, G, S, M> BSplineDirector<K, Weights<G>, S, WithWeight, M>
+impl<K, E, S, M> BSplineDirector<K, E, S, WithWeight, M>
 where
-    G: DiscreteGenerator,
-    G::Output: IntoWeight,
     K: SortedGenerator,
-    S: Space<Homogeneous<<G::Output as IntoWeight>::Element, <G::Output as IntoWeight>::Weight>>,
-    <Weights<G> as Generator<usize>>::Output: Merge<K::Output> + Copy,
-    <G::Output as IntoWeight>::Element:
-        Div<<G::Output as IntoWeight>::Weight, Output = <G::Output as IntoWeight>::Element>,
+    E: DiscreteGenerator,
+    E::Output: Merge<K::Output> + Project + Copy,
+    S: Space<E::Output>,
 {
     /// Build a bezier interpolation.
     ///
@@ -1261,7 +1551,7 @@ where
     /// [`TooFewElements`]: super::BSplineError
     /// [`InvalidDegree`]: super::BSplineError
     /// [`TooSmallWorkspace`]: super::BSplineError
-    pub fn build(self) -> WeightedBSpline<K, G, S> {
+    pub fn build(self) -> Weighted<BSpline<K, E, S>> {
         Weighted::new(BSpline::new_unchecked(
             self.elements,
             self.knots,
@@ -1270,15 +1560,12 @@ where
     }
 }
 
-impl<K, G, S, M> BSplineBuilder<K, Weights<G>, S, WithWeight, M>
+impl<K, E, S, M> BSplineBuilder<K, E, S, WithWeight, M>
 where
-    G: DiscreteGenerator,
-    G::Output: IntoWeight,
     K: SortedGenerator,
-    S: Space<Homogeneous<<G::Output as IntoWeight>::Element, <G::Output as IntoWeight>::Weight>>,
-    <Weights<G> as Generator<usize>>::Output: Merge<K::Output> + Copy,
-    <G::Output as IntoWeight>::Element:
-        Div<<G::Output as IntoWeight>::Weight, Output = <G::Output as IntoWeight>::Element>,
+    E: DiscreteGenerator,
+    E::Output: Merge<K::Output> + Project + Copy,
+    S: Space<E::Output>,
 {
     /// Build a bezier interpolation.
     ///
@@ -1294,7 +1581,7 @@ where
     /// [`TooSmallWorkspace`]: super::BSplineError
     /// [`NotSorted`]: super::BSplineError
     /// [`knots()`]: BSplineBuilder::knots()
-    pub fn build(self) -> Result<WeightedBSpline<K, G, S>, BSplineError> {
+    pub fn build(self) -> Result<Weighted<BSpline<K, E, S>>, BSplineError> {
         match self.inner {
             Err(err) => Err(err),
             Ok(director) => Ok(director.build()),
@@ -1302,8 +1589,6 @@ where
     }
 }
 
-/// Type alias for weighted bsplines.
-type WeightedBSpline<K, G, S> = Weighted<BSpline<K, Weights<G>, S>>;
 /// Type alias for ClampedBuilder
 type ClampedBSplineBuilder<K, E, W> =
     BSplineBuilder<BorderBuffer<Sorted<K>>, E, Unknown, W, Clamped>;
@@ -1316,6 +1601,16 @@ type LegacyBSplineBuilder<K, E, W> =
 ///Type alias for LegacyDirector
 type LegacyBSplineDirector<K, E, W> =
     BSplineDirector<BorderDeletion<Sorted<K>>, E, Unknown, W, Legacy>;
+/// Type alias for a closed builder which is missing its degree
+type UnknownDegreeBSplineBuilder<K, E, W> =
+    BSplineBuilder<UnknownDegree<Sorted<K>>, E, Unknown, W, Closed>;
+/// Type alias for a closed director which is missing its degree
+type UnknownDegreeBSplineDirector<K, E, W> =
+    BSplineDirector<UnknownDegree<Sorted<K>>, E, Unknown, W, Closed>;
+/// Type alias for ClosedBuilder
+type ClosedBSplineBuilder<K, E, W> = BSplineBuilder<Periodic<K>, Wrap<E>, Unknown, W, Closed>;
+/// Type alias for ClosedDirector
+type ClosedBSplineDirector<K, E, W> = BSplineDirector<Periodic<K>, Wrap<E>, Unknown, W, Closed>;
 
 #[cfg(test)]
 mod test {
@@ -1633,4 +1928,140 @@ mod test {
             .knots([0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0])
             .is_err());
     }
+
+    #[test]
+    fn closed() {
+        let elements = [0.0, 6.0, 12.0, 6.0, 3.0];
+        let closed = BSplineBuilder::new()
+            .closed()
+            .elements(elements)
+            .equidistant::<f64>()
+            .degree(3)
+            .normalized()
+            .constant::<4>()
+            .build()
+            .unwrap();
+        assert_eq!(closed.domain(), [0.0, 1.0]);
+        // uniform cubic bsplines weight the elements around a knot with 1/6, 4/6 and 1/6
+        assert_f64_near!(closed.gen(0.0), 6.0);
+        assert_f64_near!(closed.gen(0.2), 10.0);
+        // the seam is as smooth as the rest of the curve
+        let start: [f64; 3] = closed.gen_with_derivatives(0.0);
+        let end: [f64; 3] = closed.gen_with_derivatives(1.0);
+        for (a, b) in start.iter().zip(end.iter()) {
+            assert!((a - b).abs() < 1e-10);
+        }
+
+        let custom = BSplineBuilder::new()
+            .closed()
+            .elements(elements)
+            .knots([0.0, 1.0, 3.0, 4.0, 6.0, 7.0])
+            .degree(2)
+            .constant::<3>()
+            .build()
+            .unwrap();
+        assert_eq!(custom.domain(), [0.0, 7.0]);
+        let start: [f64; 2] = custom.gen_with_derivatives(0.0);
+        let end: [f64; 2] = custom.gen_with_derivatives(7.0);
+        for (a, b) in start.iter().zip(end.iter()) {
+            assert!((a - b).abs() < 1e-10);
+        }
+
+        let linear = BSplineBuilder::new()
+            .closed()
+            .elements(elements)
+            .equidistant::<f64>()
+            .degree(1)
+            .distance(0.0, 1.0)
+            .constant::<2>()
+            .build()
+            .unwrap();
+        assert_eq!(linear.domain(), [0.0, 5.0]);
+        assert_f64_near!(linear.gen(4.5), 1.5);
+
+        let weighted = BSplineBuilder::new()
+            .closed()
+            .elements_with_weights([(0.0, 1.0), (6.0, 2.0), (12.0, 1.0), (6.0, 0.5)])
+            .equidistant::<f64>()
+            .degree(2)
+            .domain(0.0, 4.0)
+            .constant::<3>()
+            .build()
+            .unwrap();
+        let start: [f64; 2] = weighted.gen_with_derivatives(0.0);
+        let end: [f64; 2] = weighted.gen_with_derivatives(4.0);
+        for (a, b) in start.iter().zip(end.iter()) {
+            assert!((a - b).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn closed_errors() {
+        // too few elements
+        assert!(BSplineDirector::new().closed().elements([0.0]).is_err());
+
+        // incongruous knots
+        assert!(BSplineDirector::new()
+            .closed()
+            .elements([0.0, 1.0, 2.0])
+            .unwrap()
+            .knots([0.0, 1.0, 2.0])
+            .is_err());
+
+        // not sorted
+        assert!(BSplineDirector::new()
+            .closed()
+            .elements([0.0, 1.0, 2.0])
+            .unwrap()
+            .knots([0.0, 2.0, 1.0, 3.0])
+            .is_err());
+
+        // invalid degree
+        assert!(BSplineDirector::new()
+            .closed()
+            .elements([0.0, 1.0, 2.0])
+            .unwrap()
+            .knots([0.0, 1.0, 2.0, 3.0])
+            .unwrap()
+            .degree(0)
+            .is_err());
+
+        // incongruous degree
+        assert!(BSplineDirector::new()
+            .closed()
+            .elements([0.0, 1.0, 2.0])
+            .unwrap()
+            .knots([0.0, 1.0, 2.0, 3.0])
+            .unwrap()
+            .degree(3)
+            .is_err());
+
+        assert!(BSplineDirector::new()
+            .closed()
+            .elements([0.0, 1.0, 2.0])
+            .unwrap()
+            .equidistant::<f32>()
+            .degree(3)
+            .is_err());
+
+        assert!(BSplineDirector::new()
+            .closed()
+            .elements([0.0, 1.0, 2.0])
+            .unwrap()
+            .equidistant::<f32>()
+            .degree(2)
+            .is_ok());
+
+        // too small of a workspace
+        assert!(BSplineDirector::new()
+            .closed()
+            .elements([0.0, 1.0, 2.0])
+            .unwrap()
+            .equidistant::<f32>()
+            .degree(2)
+            .unwrap()
+            .normalized()
+            .constant::<2>()
+            .is_err());
+    }
 }
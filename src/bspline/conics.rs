@@ -0,0 +1,281 @@
+//! Exact circles, ellipses, arcs and conic sections as quadratic rational bsplines.
+
+use super::refinement::OwnedWeightedBSpline;
+use super::BSpline;
+use crate::builder::Unknown;
+use crate::weights::{elliptic_arc, Homogeneous, Weighted};
+use crate::{Components, DynSpace, Sorted};
+use core::iter::repeat;
+use core::ops::{Add, Mul};
+use num_traits::real::Real;
+use num_traits::FromPrimitive;
+
+/// Returns the quadratic rational bspline with the given homogeneous elements,
+/// whose knot spans are all of the same length.
+///
+/// All inner knots have a multiplicity of two, such that every knot span is a bezier curve
+/// with three consecutive elements.
+fn quadratic<T, R>(elements: Vec<Homogeneous<T, R>>) -> OwnedWeightedBSpline<T, R>
+where
+    R: Real + FromPrimitive,
+{
+    let spans = elements.len() / 2;
+    let knots = (0..=spans)
+        .flat_map(|index| {
+            repeat(R::from_usize(index).unwrap() / R::from_usize(spans).unwrap()).take(2)
+        })
+        .collect();
+    Weighted::new(BSpline {
+        elements,
+        knots: Sorted::new_unchecked(knots),
+        space: DynSpace::new(3),
+        degree: 2,
+    })
+}
+
+/// Returns the homogeneous elements of the elliptic arc between the given angles,
+/// split into parts of at most a quarter turn.
+fn elliptic_arcs<T, R>(center: T, x_axis: T, y_axis: T, start: R, end: R) -> Vec<Homogeneous<T, R>>
+where
+    T: Add<Output = T> + Mul<R, Output = T> + Copy,
+    R: Real + FromPrimitive,
+{
+    let quarter = R::from_f64(core::f64::consts::FRAC_PI_2).unwrap();
+    let parts = (end - start).abs() / quarter;
+    // avoid an additional part due to rounding errors
+    let parts = (parts - parts * R::epsilon())
+        .ceil()
+        .to_usize()
+        .unwrap_or(1)
+        .max(1);
+    let angle = |index: usize| {
+        start + (end - start) * R::from_usize(index).unwrap() / R::from_usize(parts).unwrap()
+    };
+    let mut elements = Vec::with_capacity(2 * parts + 1);
+    for index in 0..parts {
+        let [first, shoulder, last] =
+            elliptic_arc(center, x_axis, y_axis, angle(index), angle(index + 1));
+        if index == 0 {
+            elements.push(first);
+        }
+        elements.push(shoulder);
+        elements.push(last);
+    }
+    elements
+}
+
+impl BSpline<Unknown, Unknown, Unknown> {
+    /// Create the circle with the given center and radius in the plane of the first two components.
+    ///
+    /// The circle starts on the first axis and runs counterclockwise through the domain `[0.0,1.0]`,
+    /// reaching the next axis at every quarter of the domain. It is exact, however its speed is not constant.
+    /// Use [`ellipse()`] to create circles in other planes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use core::ops::{Add, Div, Mul};
+    /// # use enterpolation::{bspline::BSpline, Components, Curve, Generator};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # #[derive(Debug, Copy, Clone, Default, PartialEq)]
+    /// # struct Point(f64, f64);
+    /// # impl Add for Point {
+    /// #     type Output = Self;
+    /// #     fn add(self, o: Self) -> Self { Point(self.0 + o.0, self.1 + o.1) }
+    /// # }
+    /// # impl Mul<f64> for Point {
+    /// #     type Output = Self;
+    /// #     fn mul(self, s: f64) -> Self { Point(self.0 * s, self.1 * s) }
+    /// # }
+    /// # impl Div<f64> for Point {
+    /// #     type Output = Self;
+    /// #     fn div(self, s: f64) -> Self { Point(self.0 / s, self.1 / s) }
+    /// # }
+    /// # impl Components<f64> for Point {
+    /// #     fn dimension(&self) -> usize { 2 }
+    /// #     fn component(&self, index: usize) -> f64 { [self.0, self.1][index] }
+    /// #     fn set_component(&mut self, index: usize, value: f64) {
+    /// #         match index { 0 => self.0 = value, _ => self.1 = value }
+    /// #     }
+    /// # }
+    /// let circle = BSpline::circle(Point(1.0,2.0), 3.0);
+    /// for Point(x, y) in (&circle).take(10) {
+    ///     assert_f64_near!((x - 1.0).hypot(y - 2.0), 3.0);
+    /// }
+    /// assert_f64_near!(circle.gen(0.25).1, 5.0);
+    /// ```
+    ///
+    /// [`ellipse()`]: BSpline::ellipse()
+    pub fn circle<T, R>(center: T, radius: R) -> OwnedWeightedBSpline<T, R>
+    where
+        T: Components<R> + Add<Output = T> + Mul<R, Output = T> + Default + Copy,
+        R: Real + FromPrimitive,
+    {
+        let mut x_axis = T::default();
+        x_axis.set_component(0, radius);
+        let mut y_axis = T::default();
+        y_axis.set_component(1, radius);
+        Self::ellipse(center, x_axis, y_axis)
+    }
+
+    /// Create the ellipse `center + x_axis * cos(angle) + y_axis * sin(angle)`.
+    ///
+    /// The ellipse starts at the end of the first axis and runs through the domain `[0.0,1.0]`,
+    /// reaching the ends of the axes at every quarter of the domain.
+    /// The axes are conjugate semi-diameters of the ellipse, if they are orthogonal they are its semi-axes.
+    /// If they are also of the same length, the ellipse is a circle. As the axes may lie in any plane,
+    /// ellipses can be created for vectors of any dimension.
+    pub fn ellipse<T, R>(center: T, x_axis: T, y_axis: T) -> OwnedWeightedBSpline<T, R>
+    where
+        T: Add<Output = T> + Mul<R, Output = T> + Copy,
+        R: Real + FromPrimitive,
+    {
+        let full = R::from_f64(core::f64::consts::TAU).unwrap();
+        let mut elements = elliptic_arcs(center, x_axis, y_axis, R::zero(), full);
+        // close the curve exactly
+        let last = elements.len() - 1;
+        elements[last] = elements[0];
+        quadratic(elements)
+    }
+
+    /// Create the elliptic arc `center + x_axis * cos(angle) + y_axis * sin(angle)`
+    /// for all angles from `start` to `end`, given in radians.
+    ///
+    /// The arc is split into bezier curves of at most a quarter turn, which are equally distributed over
+    /// the domain `[0.0,1.0]`. Arcs over more than a full turn wind multiple times around the center.
+    /// See [`ellipse()`] for more information about the axes.
+    ///
+    /// [`ellipse()`]: BSpline::ellipse()
+    pub fn arc<T, R>(
+        center: T,
+        x_axis: T,
+        y_axis: T,
+        start: R,
+        end: R,
+    ) -> OwnedWeightedBSpline<T, R>
+    where
+        T: Add<Output = T> + Mul<R, Output = T> + Copy,
+        R: Real + FromPrimitive,
+    {
+        quadratic(elliptic_arcs(center, x_axis, y_axis, start, end))
+    }
+
+    /// Create the conic section starting at `start`, ending at `end` and being pulled to `shoulder`
+    /// by the given weight.
+    ///
+    /// The created curve is a single quadratic rational bezier curve over the domain `[0.0,1.0]`.
+    /// See [`Bezier::conic()`] for more information.
+    ///
+    /// [`Bezier::conic()`]: crate::bezier::Bezier::conic()
+    pub fn conic<T, R>(start: T, shoulder: T, end: T, weight: R) -> OwnedWeightedBSpline<T, R>
+    where
+        T: Mul<R, Output = T>,
+        R: Real + FromPrimitive,
+    {
+        quadratic(vec![
+            Homogeneous::new(start),
+            Homogeneous::weighted_unchecked(shoulder, weight),
+            Homogeneous::new(end),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::BSpline;
+    use crate::test_util::Point3;
+    use crate::{Curve, Generator};
+    use core::f64::consts::PI;
+
+    #[test]
+    fn circle() {
+        let center = Point3(1.0, -2.0, 3.0);
+        let circle = BSpline::circle(center, 2.5);
+        assert_eq!(circle.domain(), [0.0, 1.0]);
+        for point in (&circle).take(101) {
+            assert_f64_near!((point - center).norm(), 2.5);
+            assert!((point.2 - 3.0).abs() < 1e-12);
+        }
+        assert_eq!(circle.gen(0.0), circle.gen(1.0));
+        assert!((circle.gen(0.25) - Point3(1.0, 0.5, 3.0)).norm() < 1e-12);
+        assert!((circle.gen(0.5) - Point3(-1.5, -2.0, 3.0)).norm() < 1e-12);
+    }
+
+    #[test]
+    fn ellipse() {
+        // a circle with radius 5 in a tilted plane
+        let circle = BSpline::ellipse(
+            Point3::default(),
+            Point3(0.0, 3.0, 4.0),
+            Point3(5.0, 0.0, 0.0),
+        );
+        for point in (&circle).take(101) {
+            assert_f64_near!(point.norm(), 5.0);
+        }
+        // an ellipse with semi-axes 3 and 1
+        let ellipse = BSpline::ellipse(
+            Point3(1.0, 1.0, 0.0),
+            Point3(3.0, 0.0, 0.0),
+            Point3(0.0, 1.0, 0.0),
+        );
+        for Point3(x, y, _) in (&ellipse).take(101) {
+            assert!(((x - 1.0).powi(2) / 9.0 + (y - 1.0).powi(2) - 1.0).abs() < 1e-12);
+        }
+        // the ends of the axes are reached at every quarter
+        assert!((ellipse.gen(0.75) - Point3(1.0, 0.0, 0.0)).norm() < 1e-12);
+    }
+
+    #[test]
+    fn arc() {
+        let center = Point3(0.0, 1.0, 0.0);
+        let (x_axis, y_axis) = (Point3(2.0, 0.0, 0.0), Point3(0.0, 2.0, 0.0));
+        for (start, end) in [
+            (0.0, 0.3),
+            (1.0, -2.0),
+            (0.0, 2.0 * PI),
+            (-PI, 2.0 * PI),
+            (0.0, 5.0 * PI),
+        ] {
+            let arc = BSpline::arc(center, x_axis, y_axis, start, end);
+            for point in (&arc).take(101) {
+                assert_f64_near!((point - center).norm(), 2.0);
+            }
+            for (t, angle) in [(0.0, start), (1.0, end)] {
+                let expected = center + x_axis * f64::cos(angle) + y_axis * f64::sin(angle);
+                assert!((arc.gen(t) - expected).norm() < 1e-12);
+            }
+        }
+        // the bezier curves are distributed equally
+        let arc = BSpline::arc(center, x_axis, y_axis, 0.0, 1.5 * PI);
+        assert_eq!(arc.inner_ref().knots.gen(7), 1.0);
+        assert_eq!(arc.inner_ref().elements.len(), 7);
+        assert!((arc.gen(1.0 / 3.0) - Point3(0.0, 3.0, 0.0)).norm() < 1e-12);
+    }
+
+    #[test]
+    fn conic() {
+        // a quarter of the circle with radius 2 around the origin
+        let quarter = BSpline::conic(
+            Point3(2.0, 0.0, 0.0),
+            Point3(2.0, 2.0, 0.0),
+            Point3(0.0, 2.0, 0.0),
+            0.5_f64.sqrt(),
+        );
+        for point in (&quarter).take(101) {
+            assert_f64_near!(point.norm(), 2.0);
+        }
+        // a hyperbola x^2 - y^2 = 1 whose tangents at the ends meet in the origin
+        let a = 2.0_f64;
+        let hyperbola = BSpline::conic(
+            Point3(a, -(a * a - 1.0).sqrt(), 0.0),
+            Point3(a.recip(), 0.0, 0.0),
+            Point3(a, (a * a - 1.0).sqrt(), 0.0),
+            a,
+        );
+        assert_eq!(hyperbola.gen(0.5), Point3(1.0, 0.0, 0.0));
+        for Point3(x, y, _) in (&hyperbola).take(101) {
+            assert!((x * x - y * y - 1.0).abs() < 1e-12);
+        }
+    }
+}
@@ -0,0 +1,385 @@
+//! Conversion of bsplines from and to sequences of bezier curves.
+
+use super::error::{
+    BSplineError, DegreeMismatch, DiscontinuousBeziers, DuplicateKnots, IncongruousBeziersKnots,
+    InvalidDegree, NotSorted, TooFewKnots,
+};
+use super::refinement::{OwnedBSpline, OwnedWeightedBSpline};
+use super::BSpline;
+use crate::bezier::{Bezier, OwnedBezier};
+use crate::weights::{Homogeneous, Weighted};
+use crate::{DiscreteGenerator, DynSpace, Sorted, SortedGenerator, Space};
+use core::iter::repeat;
+use num_traits::real::Real;
+use topology_traits::Merge;
+
+impl<K, E, S, R> BSpline<K, E, S>
+where
+    K: SortedGenerator<Output = R>,
+    R: Real,
+{
+    /// Returns the distinct knots inside the domain, including its borders.
+    ///
+    /// These are the borders of the bezier curves returned by [`to_beziers()`].
+    ///
+    /// [`to_beziers()`]: BSpline::to_beziers()
+    pub fn breakpoints(&self) -> Vec<R> {
+        let last = self.knots.len() - self.degree;
+        let mut breaks = vec![self.knots.gen(self.degree - 1)];
+        for index in self.degree..=last {
+            let knot = self.knots.gen(index);
+            if knot > breaks[breaks.len() - 1] || index == last {
+                breaks.push(knot);
+            }
+        }
+        breaks
+    }
+}
+
+impl<K, E, S, R> BSpline<K, E, S>
+where
+    E: DiscreteGenerator,
+    E::Output: Merge<R> + Default + Copy,
+    S: Space<E::Output>,
+    K: SortedGenerator<Output = R>,
+    R: Real,
+{
+    /// Decompose the curve into the bezier curves of its knot spans.
+    ///
+    /// The elements of every bezier curve are the blossom of its knot span evaluated at the borders of the span,
+    /// which is the same as inserting every knot inside the domain until it occurs as often as the degree of the curve.
+    /// The i-th bezier curve equals the curve between the i-th and (i+1)-th of the [`breakpoints()`],
+    /// reparameterized to the domain of bezier curves.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bspline::{BSpline, BSplineError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BSplineError> {
+    /// let bspline = BSpline::builder()
+    ///                 .clamped()
+    ///                 .elements([0.0,5.0,3.0,10.0])
+    ///                 .knots([0.0,1.0,2.0])
+    ///                 .constant::<3>()
+    ///                 .build()?;
+    /// let beziers = bspline.to_beziers();
+    /// assert_eq!(beziers.len(), 2);
+    /// assert_f64_near!(beziers[1].gen(0.5), bspline.gen(1.5));
+    /// let back = BSpline::from_beziers(&beziers, bspline.breakpoints())?;
+    /// assert_f64_near!(back.gen(0.7), bspline.gen(0.7));
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`breakpoints()`]: BSpline::breakpoints()
+    pub fn to_beziers(&self) -> Vec<OwnedBezier<R, E::Output>> {
+        let len = self.degree + 1;
+        self.bezier_spans()
+            .into_iter()
+            .map(|(_, elements)| Bezier::new_unchecked(elements, DynSpace::new(len)))
+            .collect()
+    }
+}
+
+impl<R, T> BSpline<Sorted<Vec<R>>, Vec<T>, DynSpace<T>>
+where
+    T: PartialEq + Default + Copy,
+    R: Real,
+{
+    /// Join bezier curves of the same degree to a clamped bsplines.
+    ///
+    /// The i-th bezier curve becomes the knot span between the i-th and (i+1)-th of the given breakpoints,
+    /// such that there has to be exactly one breakpoint more than bezier curves.
+    /// Every breakpoint occurs as often as the degree in the knots of the created curve.
+    ///
+    /// Every bezier curve except the first one has to start with the last element of the curve before,
+    /// such that the created curve is continuous.
+    ///
+    /// # Errors
+    ///
+    /// [`TooFewKnots`] if less than two breakpoints are given.
+    /// [`IncongruousBeziersKnots`] if the number of breakpoints is not one more than the number of bezier curves.
+    /// [`InvalidDegree`] if the bezier curves are constant.
+    /// [`DegreeMismatch`] if the bezier curves have different degrees.
+    /// [`NotSorted`] if the breakpoints are not sorted.
+    /// [`DuplicateKnots`] if a breakpoint is given more than once.
+    /// [`DiscontinuousBeziers`] if a bezier curve does not start with the last element of the curve before.
+    ///
+    /// [`TooFewKnots`]: BSplineError
+    /// [`IncongruousBeziersKnots`]: BSplineError
+    /// [`InvalidDegree`]: BSplineError
+    /// [`DegreeMismatch`]: BSplineError
+    /// [`NotSorted`]: BSplineError
+    /// [`DuplicateKnots`]: BSplineError
+    /// [`DiscontinuousBeziers`]: BSplineError
+    pub fn from_beziers<E, S, G>(
+        beziers: &[Bezier<R, E, S>],
+        breakpoints: G,
+    ) -> Result<Self, BSplineError>
+    where
+        E: DiscreteGenerator<Output = T>,
+        S: Space<T>,
+        G: DiscreteGenerator<Output = R>,
+    {
+        let breaks: Vec<R> = breakpoints.iter().collect();
+        if breaks.len() < 2 {
+            return Err(TooFewKnots::new(breaks.len()).into());
+        }
+        if breaks.len() != beziers.len() + 1 {
+            return Err(IncongruousBeziersKnots::new(beziers.len(), breaks.len()).into());
+        }
+        let degree = beziers[0].elements().len() - 1;
+        if degree == 0 {
+            return Err(InvalidDegree::new(degree).into());
+        }
+        for (index, bezier) in beziers.iter().enumerate() {
+            if bezier.elements().len() != degree + 1 {
+                return Err(DegreeMismatch::new(index, bezier.elements().len() - 1, degree).into());
+            }
+        }
+        for index in 1..breaks.len() {
+            if breaks[index - 1] == breaks[index] {
+                return Err(DuplicateKnots::new(index).into());
+            }
+            if breaks[index - 1] > breaks[index] {
+                return Err(NotSorted::new(index).into());
+            }
+        }
+        for index in 1..beziers.len() {
+            if beziers[index - 1].elements().last() != beziers[index].elements().first() {
+                return Err(DiscontinuousBeziers::new(index).into());
+            }
+        }
+        let mut elements: Vec<T> = beziers[0].elements().iter().collect();
+        for bezier in beziers[1..].iter() {
+            // the first element is the last element of the curve before
+            elements.extend(bezier.elements().iter().skip(1));
+        }
+        let knots = IntoIterator::into_iter(breaks)
+            .flat_map(|knot| repeat(knot).take(degree))
+            .collect();
+        Ok(BSpline {
+            elements,
+            knots: Sorted::new_unchecked(knots),
+            space: DynSpace::new(degree + 1),
+            degree,
+        })
+    }
+}
+
+impl<K, E, S, T, R> Weighted<BSpline<K, E, S>>
+where
+    E: DiscreteGenerator<Output = Homogeneous<T, R>>,
+    E::Output: Merge<R> + Copy,
+    S: Space<E::Output>,
+    K: SortedGenerator<Output = R>,
+    T: Default,
+    R: Real + Default,
+{
+    /// Decompose the rational curve into the rational bezier curves of its knot spans.
+    ///
+    /// The decomposition is done with the homogeneous elements, such the weights are changed accordingly.
+    /// See [`BSpline::to_beziers()`] for more information.
+    pub fn to_beziers(&self) -> Vec<Weighted<OwnedBezier<R, Homogeneous<T, R>>>> {
+        IntoIterator::into_iter(self.inner_ref().to_beziers())
+            .map(Weighted::new)
+            .collect()
+    }
+}
+
+impl<T, R> OwnedWeightedBSpline<T, R>
+where
+    T: PartialEq + Default + Copy,
+    R: Real + Default,
+{
+    /// Join rational bezier curves of the same degree to a clamped rational bspline.
+    ///
+    /// The curves are joined with their homogeneous elements, such that consecutive curves also have to share
+    /// the weight of their common element. See [`BSpline::from_beziers()`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// [`TooFewKnots`] if less than two breakpoints are given.
+    /// [`IncongruousBeziersKnots`] if the number of breakpoints is not one more than the number of bezier curves.
+    /// [`InvalidDegree`] if the bezier curves are constant.
+    /// [`DegreeMismatch`] if the bezier curves have different degrees.
+    /// [`NotSorted`] if the breakpoints are not sorted.
+    /// [`DuplicateKnots`] if a breakpoint is given more than once.
+    /// [`DiscontinuousBeziers`] if a bezier curve does not start with the last homogeneous element of the curve before.
+    ///
+    /// [`TooFewKnots`]: BSplineError
+    /// [`IncongruousBeziersKnots`]: BSplineError
+    /// [`InvalidDegree`]: BSplineError
+    /// [`DegreeMismatch`]: BSplineError
+    /// [`NotSorted`]: BSplineError
+    /// [`DuplicateKnots`]: BSplineError
+    /// [`DiscontinuousBeziers`]: BSplineError
+    pub fn from_beziers<E, S, G>(
+        beziers: &[Weighted<Bezier<R, E, S>>],
+        breakpoints: G,
+    ) -> Result<Self, BSplineError>
+    where
+        E: DiscreteGenerator<Output = Homogeneous<T, R>>,
+        S: Space<Homogeneous<T, R>>,
+        G: DiscreteGenerator<Output = R>,
+    {
+        let beziers: Vec<OwnedBezier<R, Homogeneous<T, R>>> = beziers
+            .iter()
+            .map(|bezier| {
+                let elements: Vec<Homogeneous<T, R>> =
+                    bezier.inner_ref().elements().iter().collect();
+                let len = elements.len();
+                Bezier::new_unchecked(elements, DynSpace::new(len))
+            })
+            .collect();
+        Ok(Weighted::new(OwnedBSpline::from_beziers(
+            &beziers,
+            breakpoints,
+        )?))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{BSpline, BSplineError};
+    use crate::test_util::Point;
+    use crate::weights::Weighted;
+    use crate::{Curve, Generator};
+
+    #[test]
+    fn beziers() {
+        let bspline = BSpline::builder()
+            .clamped()
+            .elements([0.0, 5.0, 3.0, 10.0, -2.0, 4.0, 1.0])
+            .knots([0.0, 1.0, 2.0, 2.0, 4.0])
+            .constant::<4>()
+            .build()
+            .unwrap();
+        let breaks = bspline.breakpoints();
+        assert_eq!(breaks, vec![0.0, 1.0, 2.0, 4.0]);
+        let beziers = bspline.to_beziers();
+        assert_eq!(beziers.len(), 3);
+        for (bezier, pair) in beziers.iter().zip(breaks.windows(2)) {
+            for i in 0..=10 {
+                let t = i as f64 / 10.0;
+                let value = bspline.gen(pair[0] + t * (pair[1] - pair[0]));
+                assert!((bezier.gen(t) - value).abs() < 1e-12);
+            }
+        }
+        let back = BSpline::from_beziers(&beziers, breaks).unwrap();
+        assert_eq!(back.domain(), bspline.domain());
+        assert_eq!(back.degree(), 3);
+        for i in 0..=40 {
+            let t = i as f64 / 10.0;
+            assert!((back.gen(t) - bspline.gen(t)).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn open() {
+        let bspline = BSpline::builder()
+            .elements([0.0, 5.0, 3.0, 10.0, -2.0, 4.0])
+            .knots([-1.0, 0.0, 1.0, 2.0, 3.0, 4.0, 6.0, 9.0])
+            .constant::<4>()
+            .build()
+            .unwrap();
+        let breaks = bspline.breakpoints();
+        assert_eq!(breaks, vec![1.0, 2.0, 3.0, 4.0]);
+        let beziers = bspline.to_beziers();
+        assert_eq!(beziers.len(), 3);
+        for (bezier, pair) in beziers.iter().zip(breaks.windows(2)) {
+            for i in 0..=10 {
+                let t = i as f64 / 10.0;
+                let value = bspline.gen(pair[0] + t * (pair[1] - pair[0]));
+                assert!((bezier.gen(t) - value).abs() < 1e-12);
+            }
+        }
+        // the knots outside of the domain are dropped, such the joined curve is clamped
+        let back = BSpline::from_beziers(&beziers, breaks).unwrap();
+        assert_eq!(back.domain(), bspline.domain());
+        for i in 0..=30 {
+            let t = 1.0 + i as f64 / 10.0;
+            assert!((back.gen(t) - bspline.gen(t)).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn errors() {
+        let beziers = BSpline::builder()
+            .clamped()
+            .elements([0.0, 5.0, 3.0, 10.0])
+            .knots([0.0, 1.0, 2.0])
+            .constant::<3>()
+            .build()
+            .unwrap()
+            .to_beziers();
+        assert!(matches!(
+            BSpline::from_beziers(&beziers, [0.0]),
+            Err(BSplineError::TooFewKnots(_))
+        ));
+        assert!(matches!(
+            BSpline::from_beziers(&beziers, [0.0, 1.0]),
+            Err(BSplineError::IncongruousBeziersKnots(_))
+        ));
+        assert!(matches!(
+            BSpline::from_beziers(&beziers, [0.0, 1.0, 1.0]),
+            Err(BSplineError::DuplicateKnots(_))
+        ));
+        assert!(matches!(
+            BSpline::from_beziers(&beziers, [0.0, 2.0, 1.0]),
+            Err(BSplineError::NotSorted(_))
+        ));
+        let mut mixed = beziers.clone();
+        mixed[1] = mixed[1].elevate(1);
+        assert!(matches!(
+            BSpline::from_beziers(&mixed, [0.0, 1.0, 2.0]),
+            Err(BSplineError::DegreeMismatch(_))
+        ));
+        let mut reversed = beziers.clone();
+        reversed.reverse();
+        assert!(matches!(
+            BSpline::from_beziers(&reversed, [0.0, 1.0, 2.0]),
+            Err(BSplineError::DiscontinuousBeziers(_))
+        ));
+        assert!(BSpline::from_beziers(&beziers, [0.0, 1.0, 2.0]).is_ok());
+    }
+
+    #[test]
+    fn weighted() {
+        // the upper half of the unit circle
+        let half = 0.5_f64.sqrt();
+        let circle = BSpline::builder()
+            .clamped()
+            .elements_with_weights([
+                (Point(1.0, 0.0), 1.0),
+                (Point(1.0, 1.0), half),
+                (Point(0.0, 1.0), 1.0),
+                (Point(-1.0, 1.0), half),
+                (Point(-1.0, 0.0), 1.0),
+            ])
+            .knots([0.0, 1.0, 1.0, 2.0])
+            .constant::<3>()
+            .build()
+            .unwrap();
+        let arcs = circle.to_beziers();
+        assert_eq!(arcs.len(), 2);
+        assert_eq!(arcs[0].gen(1.0), Point(0.0, 1.0));
+        let mut reversed = arcs.clone();
+        reversed.reverse();
+        assert!(matches!(
+            Weighted::<BSpline<_, Vec<_>, _>>::from_beziers(&reversed, [0.0, 1.0, 2.0]),
+            Err(BSplineError::DiscontinuousBeziers(_))
+        ));
+        let back = Weighted::<BSpline<_, Vec<_>, _>>::from_beziers(&arcs, [0.0, 1.0, 2.0]).unwrap();
+        for i in 0..=20 {
+            let t = i as f64 / 10.0;
+            let Point(x, y) = back.gen(t);
+            let Point(a, b) = circle.gen(t);
+            assert!((x - a).abs() < 1e-12 && (y - b).abs() < 1e-12);
+            assert!((x * x + y * y - 1.0).abs() < 1e-12);
+        }
+    }
+}
@@ -0,0 +1,346 @@
+//! Degree elevation, which increases the degree of a bspline without changing its shape.
+
+use super::error::{BSplineError, TooSmallWorkspace};
+use super::BSpline;
+use crate::{DiscreteGenerator, DynSpace, Sorted, SortedGenerator, Space};
+use core::iter::repeat;
+use num_traits::real::Real;
+use num_traits::FromPrimitive;
+use topology_traits::Merge;
+
+impl<K, E, S, R> BSpline<K, E, S>
+where
+    E: DiscreteGenerator,
+    E::Output: Merge<R> + Copy,
+    S: Space<E::Output>,
+    K: SortedGenerator<Output = R>,
+    R: Real + FromPrimitive,
+{
+    /// Elevate the degree of the curve by the given amount without changing its shape.
+    ///
+    /// The multiplicity of every knot within the domain is increased by the given amount,
+    /// such that the curve keeps its continuity at each knot. The resulting curve is clamped,
+    /// such that knots outside of the domain are not kept.
+    /// The resulting curve owns its elements and knots and uses a vector as workspace.
+    /// If you want to use another workspace, consider using [`elevate_with_workspace()`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bspline::{BSpline, BSplineError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BSplineError> {
+    /// let quadratic = BSpline::builder()
+    ///                 .clamped()
+    ///                 .elements([0.0,5.0,3.0,10.0])
+    ///                 .equidistant::<f64>()
+    ///                 .degree(2)
+    ///                 .normalized()
+    ///                 .constant::<3>()
+    ///                 .build()?;
+    /// let cubic = quadratic.elevate(1);
+    /// assert_eq!(cubic.degree(), 3);
+    /// assert_f64_near!(cubic.gen(0.3), quadratic.gen(0.3));
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`elevate_with_workspace()`]: BSpline::elevate_with_workspace()
+    #[allow(clippy::type_complexity)]
+    pub fn elevate(
+        &self,
+        by: usize,
+    ) -> BSpline<Sorted<Vec<R>>, Vec<E::Output>, DynSpace<E::Output>> {
+        let (elements, knots) = self.elevated(by);
+        BSpline {
+            elements,
+            knots: Sorted::new_unchecked(knots),
+            space: DynSpace::new(self.degree + by + 1),
+            degree: self.degree + by,
+        }
+    }
+
+    /// Elevate the degree of the curve by the given amount without changing its shape.
+    ///
+    /// The resulting curve owns its elements and knots and uses the given workspace.
+    ///
+    /// # Errors
+    ///
+    /// [`TooSmallWorkspace`] if the given workspace is not bigger than the degree of the resulting curve.
+    ///
+    /// [`TooSmallWorkspace`]: BSplineError
+    #[allow(clippy::type_complexity)]
+    pub fn elevate_with_workspace<W>(
+        &self,
+        by: usize,
+        space: W,
+    ) -> Result<BSpline<Sorted<Vec<R>>, Vec<E::Output>, W>, BSplineError>
+    where
+        W: Space<E::Output>,
+    {
+        let degree = self.degree + by;
+        if space.len() <= degree {
+            return Err(TooSmallWorkspace::new(space.len(), degree).into());
+        }
+        let (elements, knots) = self.elevated(by);
+        Ok(BSpline {
+            elements,
+            knots: Sorted::new_unchecked(knots),
+            space,
+            degree,
+        })
+    }
+
+    /// Calculates the elements and knots of the curve with a degree elevated by the given amount.
+    ///
+    /// The curve is decomposed into the bezier curves of its knot spans, which are elevated and
+    /// joined to a clamped curve in which every inner knot occurs as often as the elevated degree.
+    /// Afterwards, the inner knots are removed until the continuity at each knot is the same as before.
+    fn elevated(&self, by: usize) -> (Vec<E::Output>, Vec<R>) {
+        let degree = self.degree;
+        let elevated = degree + by;
+        let mut spans = self.bezier_spans();
+        if spans.is_empty() {
+            // the domain consists of a single point, such there is no shape to keep
+            let start = self.knots.gen(degree - 1);
+            let mut workspace = self.workspace(degree);
+            spans.push(([start, start], workspace.as_mut()[..=degree].to_vec()));
+        }
+        // the knots contain the first and last knot, as it is common in the literature
+        let mut knots = Vec::with_capacity((spans.len() + 1) * elevated + 2);
+        let mut elements = Vec::with_capacity(spans.len() * elevated + 1);
+        let mut removals = Vec::with_capacity(spans.len());
+        for (i, ([start, end], bezier)) in spans.into_iter().enumerate() {
+            let mut bezier = bezier;
+            for _ in 0..by {
+                bezier = elevate_bezier(&bezier);
+            }
+            if i == 0 {
+                knots.extend(repeat(start).take(elevated + 1));
+                elements.push(bezier[0]);
+            } else {
+                let multiplicity = self.knots.iter().filter(|&knot| knot == start).count();
+                if multiplicity > degree {
+                    // the curve is not continuous at this knot, such both elements are needed
+                    knots.push(start);
+                    elements.push(bezier[0]);
+                } else {
+                    removals.push((start, degree - multiplicity));
+                }
+            }
+            elements.extend_from_slice(&bezier[1..]);
+            knots.extend(repeat(end).take(elevated));
+        }
+        knots.push(knots[knots.len() - 1]);
+        for (knot, times) in removals {
+            remove_knot(&mut elements, &mut knots, elevated, knot, times);
+        }
+        knots.pop();
+        knots.remove(0);
+        (elements, knots)
+    }
+}
+
+/// Elevates the degree of the bezier curve given by its elements by one.
+fn elevate_bezier<T, R>(elements: &[T]) -> Vec<T>
+where
+    T: Merge<R> + Copy,
+    R: Real + FromPrimitive,
+{
+    let order = R::from_usize(elements.len()).unwrap();
+    let mut elevated = Vec::with_capacity(elements.len() + 1);
+    elevated.push(elements[0]);
+    for (i, pair) in elements.windows(2).enumerate() {
+        let factor = R::from_usize(i + 1).unwrap() / order;
+        elevated.push(pair[0].merge(pair[1], R::one() - factor));
+    }
+    elevated.push(elements[elements.len() - 1]);
+    elevated
+}
+
+/// Removes the given inner knot the given number of times without changing the shape of the curve.
+///
+/// This is the knot removal algorithm of Tiller, without checking if the knot is removable.
+/// As such, the curve has to be continuously differentiable at the knot often enough
+/// for the knot to be removed. In contrast to the rest of the library, the knots have to
+/// contain the first and last knot.
+fn remove_knot<T, R>(
+    elements: &mut Vec<T>,
+    knots: &mut Vec<R>,
+    degree: usize,
+    knot: R,
+    times: usize,
+) where
+    T: Merge<R> + Copy,
+    R: Real,
+{
+    if times == 0 {
+        return;
+    }
+    let order = degree + 1;
+    // index of the last occurrence of the knot and its multiplicity
+    let index = knots.partition_point(|&val| val <= knot) - 1;
+    let multiplicity = knots[..=index]
+        .iter()
+        .rev()
+        .take_while(|&&val| val == knot)
+        .count();
+    let mut temp = vec![elements[0]; degree - multiplicity + 2 * times + 1];
+    for t in 0..times {
+        // the elements influenced by the removal
+        let first = index - degree - t;
+        let last = index - multiplicity + t;
+        // the elements are calculated from both sides, starting with the elements not influenced
+        let offset = first - 1;
+        temp[0] = elements[offset];
+        temp[last + 1 - offset] = elements[last + 1];
+        let (mut i, mut j) = (first, last);
+        let (mut ii, mut jj) = (1, last - offset);
+        while j > i + t {
+            let alpha_i = (knot - knots[i]) / (knots[i + order + t] - knots[i]);
+            let alpha_j = (knot - knots[j - t]) / (knots[j + order] - knots[j - t]);
+            temp[ii] = temp[ii - 1].merge(elements[i], alpha_i.recip());
+            temp[jj] = temp[jj + 1].merge(elements[j], (R::one() - alpha_j).recip());
+            i += 1;
+            ii += 1;
+            j -= 1;
+            jj -= 1;
+        }
+        let (mut i, mut j) = (first, last);
+        while j > i + t {
+            elements[i] = temp[i - offset];
+            elements[j] = temp[j - offset];
+            i += 1;
+            j -= 1;
+        }
+    }
+    knots.drain(index + 1 - times..=index);
+    // the elements which became obsolete
+    let mut start = (2 * index - multiplicity - degree) / 2;
+    let mut end = start;
+    for k in 1..times {
+        if k % 2 == 1 {
+            end += 1;
+        } else {
+            start -= 1;
+        }
+    }
+    elements.drain(start..=end);
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::BSpline;
+    use crate::{ConstSpace, Curve, DiscreteGenerator};
+
+    #[test]
+    fn clamped() {
+        let elements: [f64; 6] = [0.0, 5.0, 3.0, 10.0, 7.0, 1.0];
+        let spline = BSpline::builder()
+            .clamped()
+            .elements(elements)
+            .knots([0.0, 1.0, 2.5, 4.0])
+            .constant::<4>()
+            .build()
+            .unwrap();
+        for by in 0..4 {
+            let elevated = spline.elevate(by);
+            assert_eq!(elevated.degree(), 3 + by);
+            assert_eq!(elevated.elements.len(), 6 + 3 * by);
+            assert_eq!(elevated.domain(), spline.domain());
+            // clamped curves keep their end points
+            assert!((elevated.elements[0] - 0.0).abs() < 1e-10);
+            assert!((elevated.elements[5 + 3 * by] - 1.0).abs() < 1e-10);
+            for (a, b) in spline.take(41).zip(elevated.take(41)) {
+                assert!((a - b).abs() < 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn open() {
+        let elements: [f64; 6] = [0.0, 5.0, 3.0, 10.0, 7.0, 1.0];
+        let spline = BSpline::builder()
+            .elements(elements)
+            .knots([0.0, 1.0, 2.0, 3.5, 4.0, 5.0, 6.0])
+            .constant::<3>()
+            .build()
+            .unwrap();
+        let elevated = spline.elevate(2);
+        assert_eq!(elevated.degree(), 4);
+        assert_eq!(elevated.domain(), spline.domain());
+        for (a, b) in spline.take(41).zip(elevated.take(41)) {
+            assert!((a - b).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn multiple_knots() {
+        // the curve is only once continuously differentiable at 1.0 and only continuous at 3.0
+        let elements: [f64; 10] = [0.0, 5.0, 3.0, 10.0, 7.0, 1.0, -2.0, 4.0, 6.0, 2.0];
+        let spline = BSpline::builder()
+            .clamped()
+            .elements(elements)
+            .knots([0.0, 1.0, 1.0, 2.0, 3.0, 3.0, 3.0, 4.0])
+            .constant::<4>()
+            .build()
+            .unwrap();
+        for by in 1..4 {
+            let elevated = spline.elevate(by);
+            assert_eq!(elevated.elements.len(), 10 + 4 * by);
+            let multiplicity = |value: f64| elevated.knots.iter().filter(|&k| k == value).count();
+            assert_eq!(multiplicity(1.0), 2 + by);
+            assert_eq!(multiplicity(2.0), 1 + by);
+            assert_eq!(multiplicity(3.0), 3 + by);
+            for (a, b) in spline.take(81).zip(elevated.take(81)) {
+                assert!((a - b).abs() < 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn discontinuous() {
+        // the curve jumps at 1.0
+        let elements: [f64; 6] = [0.0, 5.0, 3.0, 10.0, 7.0, 1.0];
+        let spline = BSpline::builder()
+            .clamped()
+            .elements(elements)
+            .knots([0.0, 1.0, 1.0, 1.0, 2.0])
+            .constant::<3>()
+            .build()
+            .unwrap();
+        let elevated = spline.elevate(2);
+        assert_eq!(elevated.elements.len(), 6 + 2 * 2);
+        for (a, b) in spline.take(41).zip(elevated.take(41)) {
+            assert!((a - b).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn single_span() {
+        let elements: [f64; 3] = [0.0, 3.0, 0.0];
+        let spline = BSpline::builder()
+            .clamped()
+            .elements(elements)
+            .equidistant::<f64>()
+            .degree(2)
+            .normalized()
+            .constant::<3>()
+            .build()
+            .unwrap();
+        let elevated = spline
+            .elevate_with_workspace(2, ConstSpace::<_, 5>::new())
+            .unwrap();
+        // a single clamped span is a bezier curve, such it has the same elements as an elevated bezier curve
+        let expected = [0.0, 1.5, 2.0, 1.5, 0.0];
+        assert_eq!(elevated.elements.len(), expected.len());
+        for (a, b) in elevated.elements.iter().zip(expected.iter()) {
+            assert!((a - b).abs() < 1e-10);
+        }
+        assert!(spline
+            .elevate_with_workspace(2, ConstSpace::<_, 4>::new())
+            .is_err());
+    }
+}
@@ -28,6 +28,28 @@ pub enum BSplineError {
     IncongruousElementsKnots(IncongruousElementsKnots),
     /// Error returned when elements and degree are ill-matched.
     IncongruousElementsDegree(IncongruousElementsDegree),
+    /// Error returned if the number of knots and elements of an interpolation are not equal.
+    KnotElementInequality(KnotElementInequality),
+    /// Error returned if there are too few elements for the chosen end condition of an interpolation.
+    IncongruousElementsCondition(IncongruousElementsCondition),
+    /// Error returned if knots of an interpolation are not strictly increasing.
+    DuplicateKnots(DuplicateKnots),
+    /// Error returned if the first and last elements of a periodic interpolation are not equal.
+    UnequalEnds(UnequalEnds),
+    /// Error returned if a knot to insert lies outside of the domain of the curve.
+    KnotOutsideDomain(KnotOutsideDomain),
+    /// Error returned if a knot would be inserted more often than the degree of the curve allows.
+    TooHighMultiplicity(TooHighMultiplicity),
+    /// Error returned if the samples of a fitting do not determine all elements of the curve.
+    Underdetermined(Underdetermined),
+    /// Error returned if a fitting should have more elements than there are samples.
+    TooFewSamples(TooFewSamples),
+    /// Error returned if bezier curves which should be joined have different degrees.
+    DegreeMismatch(DegreeMismatch),
+    /// Error returned if the number of bezier curves and knots which should be joined are not matching together.
+    IncongruousBeziersKnots(IncongruousBeziersKnots),
+    /// Error returned if a bezier curve which should be joined does not start where the curve before ends.
+    DiscontinuousBeziers(DiscontinuousBeziers),
 }
 
 impl fmt::Display for BSplineError {
@@ -40,6 +62,17 @@ impl fmt::Display for BSplineError {
             BSplineError::TooFewKnots(inner) => inner.fmt(f),
             BSplineError::IncongruousElementsKnots(inner) => inner.fmt(f),
             BSplineError::IncongruousElementsDegree(inner) => inner.fmt(f),
+            BSplineError::KnotElementInequality(inner) => inner.fmt(f),
+            BSplineError::IncongruousElementsCondition(inner) => inner.fmt(f),
+            BSplineError::DuplicateKnots(inner) => inner.fmt(f),
+            BSplineError::UnequalEnds(inner) => inner.fmt(f),
+            BSplineError::KnotOutsideDomain(inner) => inner.fmt(f),
+            BSplineError::TooHighMultiplicity(inner) => inner.fmt(f),
+            BSplineError::Underdetermined(inner) => inner.fmt(f),
+            BSplineError::TooFewSamples(inner) => inner.fmt(f),
+            BSplineError::DegreeMismatch(inner) => inner.fmt(f),
+            BSplineError::IncongruousBeziersKnots(inner) => inner.fmt(f),
+            BSplineError::DiscontinuousBeziers(inner) => inner.fmt(f),
         }
     }
 }
@@ -86,6 +119,72 @@ impl From<IncongruousElementsDegree> for BSplineError {
     }
 }
 
+impl From<KnotElementInequality> for BSplineError {
+    fn from(from: KnotElementInequality) -> Self {
+        BSplineError::KnotElementInequality(from)
+    }
+}
+
+impl From<IncongruousElementsCondition> for BSplineError {
+    fn from(from: IncongruousElementsCondition) -> Self {
+        BSplineError::IncongruousElementsCondition(from)
+    }
+}
+
+impl From<DuplicateKnots> for BSplineError {
+    fn from(from: DuplicateKnots) -> Self {
+        BSplineError::DuplicateKnots(from)
+    }
+}
+
+impl From<UnequalEnds> for BSplineError {
+    fn from(from: UnequalEnds) -> Self {
+        BSplineError::UnequalEnds(from)
+    }
+}
+
+impl From<KnotOutsideDomain> for BSplineError {
+    fn from(from: KnotOutsideDomain) -> Self {
+        BSplineError::KnotOutsideDomain(from)
+    }
+}
+
+impl From<TooHighMultiplicity> for BSplineError {
+    fn from(from: TooHighMultiplicity) -> Self {
+        BSplineError::TooHighMultiplicity(from)
+    }
+}
+
+impl From<Underdetermined> for BSplineError {
+    fn from(from: Underdetermined) -> Self {
+        BSplineError::Underdetermined(from)
+    }
+}
+
+impl From<TooFewSamples> for BSplineError {
+    fn from(from: TooFewSamples) -> Self {
+        BSplineError::TooFewSamples(from)
+    }
+}
+
+impl From<DegreeMismatch> for BSplineError {
+    fn from(from: DegreeMismatch) -> Self {
+        BSplineError::DegreeMismatch(from)
+    }
+}
+
+impl From<IncongruousBeziersKnots> for BSplineError {
+    fn from(from: IncongruousBeziersKnots) -> Self {
+        BSplineError::IncongruousBeziersKnots(from)
+    }
+}
+
+impl From<DiscontinuousBeziers> for BSplineError {
+    fn from(from: DiscontinuousBeziers) -> Self {
+        BSplineError::DiscontinuousBeziers(from)
+    }
+}
+
 #[cfg(feature = "std")]
 impl Error for BSplineError {}
 
@@ -124,6 +223,7 @@ enum BSplineBuildMode {
     Open,
     Clamped,
     Legacy,
+    Closed,
 }
 
 /// Error returned when the number of elements and knots are ill-matched.
@@ -160,6 +260,14 @@ impl IncongruousElementsKnots {
             mode: BSplineBuildMode::Legacy,
         }
     }
+    /// Invalid values for a closed bspline
+    pub fn closed(elements: usize, knots: usize) -> Self {
+        IncongruousElementsKnots {
+            elements,
+            knots,
+            mode: BSplineBuildMode::Closed,
+        }
+    }
 }
 
 impl fmt::Display for IncongruousElementsKnots {
@@ -189,6 +297,14 @@ impl fmt::Display for IncongruousElementsKnots {
                     self.elements, self.knots
                 )
             }
+            BSplineBuildMode::Closed => {
+                write!(
+                    f,
+                    "Found {} elements and {} knots, but for a closed bspline 
+                    there must be exactly one knot more than there are elements.",
+                    self.elements, self.knots
+                )
+            }
         }
     }
 }
@@ -230,6 +346,14 @@ impl IncongruousElementsDegree {
             mode: BSplineBuildMode::Legacy,
         }
     }
+    /// Invalid values for a closed bspline
+    pub fn closed(elements: usize, degree: usize) -> Self {
+        IncongruousElementsDegree {
+            elements,
+            degree,
+            mode: BSplineBuildMode::Closed,
+        }
+    }
 }
 
 impl fmt::Display for IncongruousElementsDegree {
@@ -260,9 +384,341 @@ impl fmt::Display for IncongruousElementsDegree {
                     self.elements, self.degree
                 )
             }
+            BSplineBuildMode::Closed => {
+                write!(
+                    f,
+                    "Found {} elements and a degree of {}, but for a closed bspline 
+                    there must be more elements than the degree of the spline.",
+                    self.elements, self.degree
+                )
+            }
         }
     }
 }
 
 #[cfg(feature = "std")]
 impl Error for IncongruousElementsDegree {}
+
+/// Error returned if the number of elements and the number of knots of an interpolation are not matching.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct KnotElementInequality {
+    /// The number of elements found.
+    elements: usize,
+    /// The number of knots found.
+    knots: usize,
+}
+
+impl KnotElementInequality {
+    /// Create a new error with the number of elements and knots found.
+    pub fn new(elements: usize, knots: usize) -> Self {
+        KnotElementInequality { elements, knots }
+    }
+}
+
+impl fmt::Display for KnotElementInequality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "For an interpolation there has to be as many knots as elements, however we found {} elements and {} knots.",
+            self.elements, self.knots
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for KnotElementInequality {}
+
+/// Error returned if there are too few elements for the end condition of an interpolation.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct IncongruousElementsCondition {
+    /// The number of elements found.
+    elements: usize,
+    /// The number of elements necessary.
+    necessary: usize,
+}
+
+impl IncongruousElementsCondition {
+    /// Create a new error with the number of elements found and the number of elements necessary.
+    pub fn new(elements: usize, necessary: usize) -> Self {
+        IncongruousElementsCondition {
+            elements,
+            necessary,
+        }
+    }
+}
+
+impl fmt::Display for IncongruousElementsCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Found {} elements, but the chosen end condition needs at least {} elements.",
+            self.elements, self.necessary
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for IncongruousElementsCondition {}
+
+/// Error returned if knots of an interpolation are not strictly increasing.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct DuplicateKnots {
+    index: usize,
+}
+
+impl DuplicateKnots {
+    /// Create a new error in which the knots at index and index + 1 are equal.
+    pub fn new(index: usize) -> Self {
+        DuplicateKnots { index }
+    }
+}
+
+impl fmt::Display for DuplicateKnots {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Knots have to be strictly increasing, but the knots at index {} and {} are equal.",
+            self.index,
+            self.index + 1
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for DuplicateKnots {}
+
+/// Error returned if the first and last elements of a periodic interpolation are not equal.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct UnequalEnds {
+    last: usize,
+}
+
+impl UnequalEnds {
+    /// Create a new error in which the element at index last is not equal to the first element.
+    pub fn new(last: usize) -> Self {
+        UnequalEnds { last }
+    }
+}
+
+impl fmt::Display for UnequalEnds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "A periodic interpolation needs equal first and last elements, but the elements at index 0 and {} are not equal.",
+            self.last
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for UnequalEnds {}
+
+/// Error returned if a knot to insert lies outside of the domain of the curve.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct KnotOutsideDomain {
+    index: usize,
+}
+
+impl KnotOutsideDomain {
+    /// Create a new error in which the knot at index is outside of the domain.
+    pub fn new(index: usize) -> Self {
+        KnotOutsideDomain { index }
+    }
+}
+
+impl fmt::Display for KnotOutsideDomain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Knots to insert have to lie within the domain of the curve, but the knot at index {} does not.",
+            self.index
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for KnotOutsideDomain {}
+
+/// Error returned if a knot would be inserted more often than the degree of the curve allows.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct TooHighMultiplicity {
+    multiplicity: usize,
+    degree: usize,
+}
+
+impl TooHighMultiplicity {
+    /// Create a new error with the resulting multiplicity of the knot and the degree of the curve.
+    pub fn new(multiplicity: usize, degree: usize) -> Self {
+        TooHighMultiplicity {
+            multiplicity,
+            degree,
+        }
+    }
+}
+
+impl fmt::Display for TooHighMultiplicity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "A knot would have a multiplicity of {}, but the multiplicity of a knot may not exceed the degree of the curve, which is {}.",
+            self.multiplicity, self.degree
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for TooHighMultiplicity {}
+
+/// Error returned if the samples of a fitting do not determine all elements of the curve.
+///
+/// Every element needs enough samples within the knot spans it influences.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Underdetermined {
+    index: usize,
+}
+
+impl Underdetermined {
+    /// Create a new error in which the element at index is not determined by the samples.
+    pub fn new(index: usize) -> Self {
+        Underdetermined { index }
+    }
+}
+
+impl fmt::Display for Underdetermined {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "The samples do not determine the element at index {}, as too few samples lie within the knot spans it influences.",
+            self.index
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for Underdetermined {}
+
+/// Error returned if a fitting should have more elements than there are samples.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct TooFewSamples {
+    /// The number of elements requested.
+    elements: usize,
+    /// The number of samples found.
+    samples: usize,
+}
+
+impl TooFewSamples {
+    /// Create a new error with the number of elements requested and the number of samples found.
+    pub fn new(elements: usize, samples: usize) -> Self {
+        TooFewSamples { elements, samples }
+    }
+}
+
+impl fmt::Display for TooFewSamples {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "A fitting needs at least as many samples as elements, however {} elements were requested but only {} samples found.",
+            self.elements, self.samples
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for TooFewSamples {}
+
+/// Error returned if bezier curves which should be joined have different degrees.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct DegreeMismatch {
+    index: usize,
+    degree: usize,
+    expected: usize,
+}
+
+impl DegreeMismatch {
+    /// Create a new error in which the curve at index has the given degree instead of the expected one.
+    pub fn new(index: usize, degree: usize, expected: usize) -> Self {
+        DegreeMismatch {
+            index,
+            degree,
+            expected,
+        }
+    }
+}
+
+impl fmt::Display for DegreeMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "The bezier curve at index {} has a degree of {}, but all curves have to have the same degree of {}.",
+            self.index, self.degree, self.expected
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for DegreeMismatch {}
+
+/// Error returned if the number of bezier curves and knots which should be joined are not matching together.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct IncongruousBeziersKnots {
+    beziers: usize,
+    knots: usize,
+}
+
+impl IncongruousBeziersKnots {
+    /// Create a new error with the number of bezier curves and knots found.
+    pub fn new(beziers: usize, knots: usize) -> Self {
+        IncongruousBeziersKnots { beziers, knots }
+    }
+}
+
+impl fmt::Display for IncongruousBeziersKnots {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Found {} bezier curves and {} knots, but there has to be exactly one knot more than bezier curves.",
+            self.beziers, self.knots
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for IncongruousBeziersKnots {}
+
+/// Error returned if a bezier curve which should be joined does not start where the curve before ends.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct DiscontinuousBeziers {
+    index: usize,
+}
+
+impl DiscontinuousBeziers {
+    /// Create a new error in which the first element of the curve at index is not equal to the last element of the curve before.
+    pub fn new(index: usize) -> Self {
+        DiscontinuousBeziers { index }
+    }
+}
+
+impl fmt::Display for DiscontinuousBeziers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "The bezier curve at index {} does not start with the last element of the bezier curve at index {}.",
+            self.index,
+            self.index - 1
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for DiscontinuousBeziers {}
@@ -0,0 +1,627 @@
+//! Least-squares fitting of bsplines to samples.
+
+use super::error::{
+    BSplineError, InvalidDegree, KnotElementInequality, TooFewElements, TooFewKnots, TooFewSamples,
+    Underdetermined,
+};
+use super::BSpline;
+use crate::{DiscreteGenerator, DynSpace, Sorted, SortedGenerator};
+use core::fmt::Debug;
+use core::ops::{Add, Mul, Sub};
+use num_traits::real::Real;
+use num_traits::FromPrimitive;
+use topology_traits::QuasiMetric;
+
+/// Methods to assign parameters to samples which do not have any.
+///
+/// All methods return parameters from 0.0 to 1.0, starting with 0.0 for the first sample
+/// and ending with 1.0 for the last sample.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Parameterization {
+    /// All samples are equally far apart.
+    Uniform,
+    /// The distance of the parameters is proportional to the distance of the samples.
+    ChordLength,
+    /// The distance of the parameters is proportional to the square root of the distance of the samples.
+    ///
+    /// This lies in between the other methods and usually avoids loops and cusps at sharp turns.
+    Centripetal,
+}
+
+impl Parameterization {
+    /// Returns the parameters of the given samples.
+    ///
+    /// The distance between samples is measured with their [`QuasiMetric`] implementation.
+    /// If the elements do not implement [`QuasiMetric`], consider using [`parameters_with()`] instead.
+    ///
+    /// [`QuasiMetric`]: crate::QuasiMetric
+    /// [`parameters_with()`]: Parameterization::parameters_with()
+    pub fn parameters<E, T, R>(self, elements: E) -> Vec<R>
+    where
+        E: DiscreteGenerator<Output = T>,
+        T: QuasiMetric<R> + Copy,
+        R: Real + FromPrimitive,
+    {
+        self.parameters_with(elements, QuasiMetric::distance)
+    }
+
+    /// Returns the parameters of the given samples, measuring their distance with the given metric.
+    ///
+    /// If all samples are equal, uniform parameters are returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::bspline::Parameterization;
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// let distance = |a: f64, b: f64| (a - b).abs();
+    /// let samples = [0.0, 1.0, 5.0];
+    /// let chord = Parameterization::ChordLength.parameters_with(samples, distance);
+    /// assert_eq!(chord, vec![0.0, 0.2, 1.0]);
+    /// let centripetal = Parameterization::Centripetal.parameters_with(samples, distance);
+    /// assert_f64_near!(centripetal[1], 1.0 / 3.0);
+    /// ```
+    pub fn parameters_with<E, T, R, F>(self, elements: E, metric: F) -> Vec<R>
+    where
+        E: DiscreteGenerator<Output = T>,
+        T: Copy,
+        R: Real + FromPrimitive,
+        F: Fn(T, T) -> R,
+    {
+        let len = elements.len();
+        if len < 2 {
+            return vec![R::zero(); len];
+        }
+        let mut parameters = Vec::with_capacity(len);
+        parameters.push(R::zero());
+        let mut total = R::zero();
+        for i in 1..len {
+            let step = match self {
+                Parameterization::Uniform => R::one(),
+                Parameterization::ChordLength => metric(elements.gen(i - 1), elements.gen(i)),
+                Parameterization::Centripetal => {
+                    metric(elements.gen(i - 1), elements.gen(i)).sqrt()
+                }
+            };
+            total = total + step;
+            parameters.push(total);
+        }
+        if total.is_zero() {
+            return Parameterization::Uniform.parameters_with(elements, metric);
+        }
+        for parameter in parameters.iter_mut() {
+            *parameter = *parameter / total;
+        }
+        parameters[len - 1] = R::one();
+        parameters
+    }
+}
+
+/// Least-squares fitting of a bspline to samples.
+///
+/// This struct is created by [`BSpline::fit()`] and [`BSpline::fit_weighted()`].
+/// The bspline is created by either giving its knots with [`knots()`] or the number of
+/// its elements with [`clamped()`].
+///
+/// [`BSpline::fit()`]: BSpline::fit()
+/// [`BSpline::fit_weighted()`]: BSpline::fit_weighted()
+/// [`knots()`]: Fitting::knots()
+/// [`clamped()`]: Fitting::clamped()
+#[derive(Debug, Clone)]
+pub struct Fitting<R, T> {
+    pub(super) parameters: Vec<R>,
+    pub(super) elements: Vec<T>,
+    pub(super) weights: Vec<R>,
+    pub(super) fixed_ends: bool,
+}
+
+impl<R, T> BSpline<Sorted<Vec<R>>, Vec<T>, DynSpace<T>>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<R, Output = T> + Copy,
+    R: Real + FromPrimitive + Debug,
+{
+    /// Fit a bspline to the given samples, such that the sum of the squared distances
+    /// of the curve to the samples is minimal.
+    ///
+    /// Every element is sampled at the parameter with the same index. If the samples
+    /// do not have any parameters, they can be generated with [`Parameterization`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`KnotElementInequality`] if the number of parameters is not equal to the number of elements.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bspline::{BSpline, BSplineError}, Generator, Curve};
+    /// #
+    /// # fn main() -> Result<(), BSplineError> {
+    /// let parameters: Vec<f64> = (0..=20).map(|i| i as f64 / 20.0).collect();
+    /// let samples: Vec<f64> = parameters.iter().map(|x| x * x).collect();
+    /// let spline = BSpline::fit(parameters, samples)?.clamped(5, 3)?;
+    /// assert_eq!(spline.degree(), 3);
+    /// assert_eq!(spline.domain(), [0.0, 1.0]);
+    /// // a parabola can be represented exactly
+    /// assert!((spline.gen(0.3) - 0.09).abs() < 1e-10);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`KnotElementInequality`]: BSplineError
+    pub fn fit<P, E>(parameters: P, elements: E) -> Result<Fitting<R, T>, BSplineError>
+    where
+        P: DiscreteGenerator<Output = R>,
+        E: DiscreteGenerator<Output = T>,
+    {
+        BSpline::fit_weighted(
+            parameters,
+            elements.iter().map(|element| (element, R::one())),
+        )
+    }
+
+    /// Fit a bspline to the given weighted samples, such that the weighted sum of the squared distances
+    /// of the curve to the samples is minimal.
+    ///
+    /// Samples with bigger weights are approximated more closely. Weights should not be negative.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`KnotElementInequality`] if the number of parameters is not equal to the number of elements.
+    ///
+    /// [`KnotElementInequality`]: BSplineError
+    pub fn fit_weighted<P, I>(
+        parameters: P,
+        elements_with_weights: I,
+    ) -> Result<Fitting<R, T>, BSplineError>
+    where
+        P: DiscreteGenerator<Output = R>,
+        I: IntoIterator<Item = (T, R)>,
+    {
+        let (elements, weights): (Vec<T>, Vec<R>) = elements_with_weights.into_iter().unzip();
+        if parameters.len() != elements.len() {
+            return Err(KnotElementInequality::new(elements.len(), parameters.len()).into());
+        }
+        Ok(Fitting {
+            parameters: parameters.iter().collect(),
+            elements,
+            weights,
+            fixed_ends: false,
+        })
+    }
+}
+
+impl<R, T> Fitting<R, T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<R, Output = T> + Copy,
+    R: Real + FromPrimitive + Debug,
+{
+    /// Fix the first and last element of the bspline to the first and last sample.
+    ///
+    /// With clamped knots, the curve then starts and ends at these samples.
+    pub fn fixed_ends(mut self) -> Self {
+        self.fixed_ends = true;
+        self
+    }
+
+    /// Fit a bspline of the given degree with the given knots to the samples.
+    ///
+    /// The bspline has `knots.len() - degree + 1` elements. Samples outside of the domain of
+    /// the knots are approximated by the extrapolation of the curve.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidDegree`] if the degree is 0.
+    /// Returns [`TooFewKnots`] if there are less than `2 * degree` knots.
+    /// Returns [`NotSorted`] if the knots are not sorted.
+    /// Returns [`TooFewElements`] if there are no samples.
+    /// Returns [`Underdetermined`] if the samples do not determine all elements of the bspline.
+    ///
+    /// [`InvalidDegree`]: BSplineError
+    /// [`TooFewKnots`]: BSplineError
+    /// [`NotSorted`]: BSplineError
+    /// [`TooFewElements`]: BSplineError
+    /// [`Underdetermined`]: BSplineError
+    #[allow(clippy::type_complexity)]
+    pub fn knots<K>(
+        self,
+        knots: K,
+        degree: usize,
+    ) -> Result<BSpline<Sorted<Vec<R>>, Vec<T>, DynSpace<T>>, BSplineError>
+    where
+        K: DiscreteGenerator<Output = R>,
+    {
+        if degree == 0 {
+            return Err(InvalidDegree::new(degree).into());
+        }
+        if knots.len() < 2 * degree {
+            return Err(TooFewKnots::new(knots.len()).into());
+        }
+        if self.elements.is_empty() {
+            return Err(TooFewElements::new(0).into());
+        }
+        let knots = Sorted::new(knots.iter().collect::<Vec<R>>())?;
+        let (matrix, rhs) = normal_equations(
+            &self.parameters,
+            &self.elements,
+            &self.weights,
+            &knots,
+            degree,
+        );
+        let system = System::new(matrix, degree, self.fixed_ends)?;
+        let first = self.elements[0];
+        let last = self.elements[self.elements.len() - 1];
+        let elements = system.solve(rhs, first, last);
+        Ok(BSpline {
+            elements,
+            knots,
+            space: DynSpace::new(degree + 1),
+            degree,
+        })
+    }
+
+    /// Fit a clamped bspline of the given degree with the given number of elements to the samples.
+    ///
+    /// The domain of the bspline reaches from the smallest to the biggest parameter.
+    /// The inner knots are placed such that every knot span contains about the same number of samples.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidDegree`] if the degree is 0.
+    /// Returns [`TooFewElements`] if there are less than `degree + 1` elements.
+    /// Returns [`TooFewSamples`] if there are more elements than samples.
+    /// Returns [`Underdetermined`] if the samples do not determine all elements of the bspline.
+    ///
+    /// [`InvalidDegree`]: BSplineError
+    /// [`TooFewElements`]: BSplineError
+    /// [`TooFewSamples`]: BSplineError
+    /// [`Underdetermined`]: BSplineError
+    #[allow(clippy::type_complexity)]
+    pub fn clamped(
+        self,
+        len: usize,
+        degree: usize,
+    ) -> Result<BSpline<Sorted<Vec<R>>, Vec<T>, DynSpace<T>>, BSplineError> {
+        if degree == 0 {
+            return Err(InvalidDegree::new(degree).into());
+        }
+        if len <= degree {
+            return Err(TooFewElements::new(len).into());
+        }
+        if len > self.parameters.len() {
+            return Err(TooFewSamples::new(len, self.parameters.len()).into());
+        }
+        let knots = averaged_knots(&self.parameters, len, degree);
+        self.knots(knots, degree)
+    }
+}
+
+/// Clamped knots for a bspline with `len` elements, such that every span contains about the same number of parameters.
+fn averaged_knots<R>(parameters: &[R], len: usize, degree: usize) -> Vec<R>
+where
+    R: Real + FromPrimitive,
+{
+    let mut sorted = parameters.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal));
+    let first = sorted[0];
+    let last = sorted[sorted.len() - 1];
+    let mut knots = vec![first; degree];
+    let spans = len - degree;
+    let step = R::from_usize(sorted.len()).unwrap() / R::from_usize(spans).unwrap();
+    for j in 1..spans {
+        let position = R::from_usize(j).unwrap() * step;
+        let index = position
+            .floor()
+            .to_usize()
+            .unwrap()
+            .clamp(1, sorted.len() - 1);
+        let factor = position - R::from_usize(index).unwrap();
+        knots.push(sorted[index - 1] * (R::one() - factor) + sorted[index] * factor);
+    }
+    knots.extend(core::iter::repeat(last).take(degree));
+    knots
+}
+
+/// Returns the index of the knot span containing the scalar together with the values of
+/// the `degree + 1` basis functions which do not vanish at the scalar.
+///
+/// The basis function with index `j` belongs to the element with index `span - degree + j`.
+pub(super) fn basis<K, R>(knots: &K, degree: usize, scalar: R) -> (usize, Vec<R>)
+where
+    K: SortedGenerator<Output = R>,
+    R: Real + Debug,
+{
+    let span = knots.strict_upper_bound_clamped(scalar, degree, knots.len() - degree);
+    let mut values = vec![R::zero(); degree + 1];
+    let mut left = vec![R::zero(); degree + 1];
+    let mut right = vec![R::zero(); degree + 1];
+    values[0] = R::one();
+    for j in 1..=degree {
+        left[j] = scalar - knots.gen(span - j);
+        right[j] = knots.gen(span + j - 1) - scalar;
+        let mut saved = R::zero();
+        for r in 0..j {
+            let temp = values[r] / (right[r + 1] + left[j - r]);
+            values[r] = saved + right[r + 1] * temp;
+            saved = left[j - r] * temp;
+        }
+        values[j] = saved;
+    }
+    (span, values)
+}
+
+/// Returns the weighted normal equations of the least-squares problem as matrix and right hand side.
+pub(super) fn normal_equations<K, T, R>(
+    parameters: &[R],
+    elements: &[T],
+    weights: &[R],
+    knots: &K,
+    degree: usize,
+) -> (Vec<Vec<R>>, Vec<T>)
+where
+    K: SortedGenerator<Output = R>,
+    T: Add<Output = T> + Mul<R, Output = T> + Copy,
+    R: Real + Debug,
+{
+    let len = knots.len() - degree + 1;
+    let mut matrix = vec![vec![R::zero(); len]; len];
+    let mut rhs: Vec<Option<T>> = vec![None; len];
+    for ((&parameter, &element), &weight) in parameters.iter().zip(elements).zip(weights) {
+        let (span, values) = basis(knots, degree, parameter);
+        let offset = span - degree;
+        for (i, value) in values.iter().enumerate() {
+            let factor = value * weight;
+            for (j, other) in values.iter().enumerate() {
+                matrix[offset + i][offset + j] = matrix[offset + i][offset + j] + factor * other;
+            }
+            let contribution = element * factor;
+            rhs[offset + i] = Some(match rhs[offset + i] {
+                Some(sum) => sum + contribution,
+                None => contribution,
+            });
+        }
+    }
+    let zero = elements[0] * R::zero();
+    let rhs = IntoIterator::into_iter(rhs)
+        .map(|sum| sum.unwrap_or(zero))
+        .collect();
+    (matrix, rhs)
+}
+
+/// The symmetric positive definite system of equations of a fitting.
+///
+/// If the ends are fixed, the first and last unknown are given and the system only gets solved for the others.
+pub(super) struct System<R> {
+    /// The complete matrix of the system.
+    matrix: Vec<Vec<R>>,
+    /// The Cholesky decomposition of the matrix of the unknowns, stored in the lower triangle.
+    factor: Vec<Vec<R>>,
+    bandwidth: usize,
+    fixed_ends: bool,
+}
+
+impl<R> System<R>
+where
+    R: Real,
+{
+    /// Decompose the given symmetric matrix whose entries vanish outside of the given bandwidth.
+    ///
+    /// # Errors
+    ///
+    /// [`Underdetermined`] if the matrix of the unknowns is (numerically) singular.
+    pub(super) fn new(
+        matrix: Vec<Vec<R>>,
+        bandwidth: usize,
+        fixed_ends: bool,
+    ) -> Result<Self, Underdetermined> {
+        let len = matrix.len();
+        let (factor, offset) = if fixed_ends {
+            let reduced = matrix[1..len - 1]
+                .iter()
+                .map(|row| row[1..len - 1].to_vec())
+                .collect();
+            (reduced, 1)
+        } else {
+            (matrix.clone(), 0)
+        };
+        let factor =
+            cholesky(factor, bandwidth).map_err(|index| Underdetermined::new(index + offset))?;
+        Ok(System {
+            matrix,
+            factor,
+            bandwidth,
+            fixed_ends,
+        })
+    }
+
+    /// Solve the system for the given right hand side.
+    ///
+    /// The first and last value are only used if the ends are fixed.
+    pub(super) fn solve<X>(&self, rhs: Vec<X>, first: X, last: X) -> Vec<X>
+    where
+        X: Sub<Output = X> + Mul<R, Output = X> + Copy,
+    {
+        if !self.fixed_ends {
+            return substitute(&self.factor, rhs, self.bandwidth);
+        }
+        // move the known values to the right hand side and solve for the inner ones
+        let len = rhs.len();
+        let reduced = (1..len - 1)
+            .map(|i| rhs[i] - first * self.matrix[i][0] - last * self.matrix[i][len - 1])
+            .collect();
+        let mut result = Vec::with_capacity(len);
+        result.push(first);
+        result.extend(substitute(&self.factor, reduced, self.bandwidth));
+        result.push(last);
+        result
+    }
+}
+
+/// Cholesky decomposition of a symmetric positive definite matrix whose entries vanish outside of
+/// the given bandwidth. The decomposition is stored in the lower triangle.
+///
+/// Returns the index of the first row which makes the matrix (numerically) singular as error.
+fn cholesky<R>(mut matrix: Vec<Vec<R>>, bandwidth: usize) -> Result<Vec<Vec<R>>, usize>
+where
+    R: Real,
+{
+    let threshold = R::epsilon().sqrt();
+    for i in 0..matrix.len() {
+        let start = i.saturating_sub(bandwidth);
+        for j in start..=i {
+            let lower = start.max(j.saturating_sub(bandwidth));
+            let sum = matrix[i][lower..j]
+                .iter()
+                .zip(&matrix[j][lower..j])
+                .fold(matrix[i][j], |sum, (&a, &b)| sum - a * b);
+            if i == j {
+                if sum <= matrix[i][i] * threshold || sum <= R::zero() {
+                    return Err(i);
+                }
+                matrix[i][i] = sum.sqrt();
+            } else {
+                matrix[i][j] = sum / matrix[j][j];
+            }
+        }
+    }
+    Ok(matrix)
+}
+
+/// Solve a system of equations with the given Cholesky decomposition by forward and backward substitution.
+fn substitute<X, R>(factor: &[Vec<R>], mut rhs: Vec<X>, bandwidth: usize) -> Vec<X>
+where
+    X: Sub<Output = X> + Mul<R, Output = X> + Copy,
+    R: Real,
+{
+    let len = rhs.len();
+    for i in 0..len {
+        for k in i.saturating_sub(bandwidth)..i {
+            rhs[i] = rhs[i] - rhs[k] * factor[i][k];
+        }
+        rhs[i] = rhs[i] * factor[i][i].recip();
+    }
+    for i in (0..len).rev() {
+        for k in i + 1..len.min(i + bandwidth + 1) {
+            rhs[i] = rhs[i] - rhs[k] * factor[k][i];
+        }
+        rhs[i] = rhs[i] * factor[i][i].recip();
+    }
+    rhs
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Curve, Generator};
+
+    #[test]
+    fn reproduces_bspline() {
+        let original = BSpline::builder()
+            .elements([1.0, -2.0, 0.5, 3.0, 2.0, -1.0])
+            .knots([0.0, 0.0, 0.0, 1.0, 2.0, 4.0, 4.0, 4.0])
+            .constant::<4>()
+            .build()
+            .unwrap();
+        let parameters: Vec<f64> = (0..=40).map(|i| i as f64 / 10.0).collect();
+        let samples: Vec<f64> = parameters.iter().map(|x| original.gen(x)).collect();
+        let fitted = BSpline::fit(&parameters, &samples)
+            .unwrap()
+            .knots([0.0, 0.0, 0.0, 1.0, 2.0, 4.0, 4.0, 4.0], 3)
+            .unwrap();
+        for (a, b) in fitted.elements.iter().zip([1.0, -2.0, 0.5, 3.0, 2.0, -1.0]) {
+            assert!((a - b).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn noisy_line() {
+        // alternating noise around the line 2x + 1 cancels out
+        let parameters: Vec<f64> = (0..=30).map(|i| i as f64 / 30.0).collect();
+        let samples: Vec<f64> = parameters
+            .iter()
+            .enumerate()
+            .map(|(i, x)| 2.0 * x + 1.0 + if i % 2 == 0 { 0.1 } else { -0.1 })
+            .collect();
+        let spline = BSpline::fit(&parameters, &samples)
+            .unwrap()
+            .clamped(4, 1)
+            .unwrap();
+        assert_eq!(spline.domain(), [0.0, 1.0]);
+        for x in [0.2, 0.5, 0.8] {
+            assert!((spline.gen(x) - (2.0 * x + 1.0)).abs() < 0.05);
+        }
+    }
+
+    #[test]
+    fn weights_and_ends() {
+        let parameters = [0.0, 0.25, 0.5, 0.75, 1.0];
+        let samples = [0.0, 1.0, 0.0, 1.0, 0.0];
+        let fixed = BSpline::fit(parameters, samples)
+            .unwrap()
+            .fixed_ends()
+            .clamped(3, 2)
+            .unwrap();
+        assert!(fixed.gen(0.0).abs() < 1e-12);
+        assert!(fixed.gen(1.0).abs() < 1e-12);
+        // a heavy weight pulls the curve towards the sample
+        let light = BSpline::fit(parameters, samples)
+            .unwrap()
+            .clamped(3, 2)
+            .unwrap();
+        let heavy = BSpline::fit_weighted(
+            parameters,
+            samples
+                .iter()
+                .enumerate()
+                .map(|(i, s)| (s, if i == 2 { 100.0 } else { 1.0 })),
+        )
+        .unwrap()
+        .clamped(3, 2)
+        .unwrap();
+        assert!(heavy.gen(0.5).abs() < light.gen(0.5).abs());
+    }
+
+    #[test]
+    fn parameterization() {
+        let samples = [[0.0, 0.0], [3.0, 4.0], [3.0, 5.0]];
+        let metric =
+            |a: [f64; 2], b: [f64; 2]| ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2)).sqrt();
+        assert_eq!(
+            Parameterization::Uniform.parameters_with(samples, metric),
+            vec![0.0, 0.5, 1.0]
+        );
+        let chord = Parameterization::ChordLength.parameters_with(samples, metric);
+        assert_f64_near!(chord[1], 5.0 / 6.0);
+        let centripetal = Parameterization::Centripetal.parameters_with(samples, metric);
+        assert_f64_near!(centripetal[1], 5.0f64.sqrt() / (5.0f64.sqrt() + 1.0));
+        assert_eq!(
+            Parameterization::ChordLength
+                .parameters_with([1.0, 1.0, 1.0], |a: f64, b: f64| (a - b).abs()),
+            vec![0.0, 0.5, 1.0]
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let fitting = BSpline::fit([0.0, 0.5, 1.0], [1.0, 2.0, 3.0]).unwrap();
+        assert!(BSpline::fit([0.0, 1.0], [1.0, 2.0, 3.0]).is_err());
+        assert!(matches!(
+            fitting.clone().clamped(4, 2),
+            Err(BSplineError::TooFewSamples(_))
+        ));
+        assert!(matches!(
+            fitting.clone().clamped(3, 3),
+            Err(BSplineError::TooFewElements(_))
+        ));
+        assert!(fitting.clone().clamped(3, 0).is_err());
+        assert!(fitting.clone().knots([0.0, 1.0], 3).is_err());
+        // no samples within the middle spans
+        assert!(fitting
+            .clone()
+            .knots([0.0, 0.1, 0.2, 0.3, 0.4, 1.0], 1)
+            .is_err());
+        assert!(fitting.clamped(3, 2).is_ok());
+    }
+}
@@ -0,0 +1,498 @@
+//! Cubic spline interpolation, returning bsplines which pass through the given elements.
+
+use super::error::{
+    BSplineError, DuplicateKnots, IncongruousElementsCondition, KnotElementInequality,
+    TooFewElements, UnequalEnds,
+};
+use super::BSpline;
+use crate::{DiscreteGenerator, DynSpace, Sorted, SortedGenerator};
+use core::ops::{Add, Mul, Sub};
+use num_traits::real::Real;
+use num_traits::FromPrimitive;
+use topology_traits::Merge;
+
+/// The condition at the borders of an interpolating cubic spline.
+///
+/// An interpolating cubic spline is twice continuously differentiable and passes through all elements.
+/// As these requirements leave two degrees of freedom, an additional condition has to be chosen.
+/// Curves which should form a seamless loop are created with [`BSpline::interpolate_periodic()`] instead.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum EndCondition<T> {
+    /// The second derivative at the first and last knot is zero.
+    Natural,
+    /// The first derivative at the first and last knot are the given values, in this order.
+    Clamped(T, T),
+    /// The third derivative is continuous at the second and the second-to-last knot.
+    ///
+    /// This needs at least 4 elements.
+    NotAKnot,
+}
+
+impl<R, T> BSpline<Sorted<Vec<R>>, Vec<T>, DynSpace<T>>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<R, Output = T> + Copy,
+    R: Real + FromPrimitive,
+{
+    /// Create a cubic bspline which passes through all elements at their knots.
+    ///
+    /// The curve is twice continuously differentiable and its behaviour at the borders
+    /// is defined by the given [`EndCondition`]. The resulting curve has the domain from the
+    /// first to the last knot.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TooFewElements`] if less than 2 elements are given.
+    /// Returns [`IncongruousElementsCondition`] if there are too few elements for the chosen condition.
+    /// Returns [`KnotElementInequality`] if the number of knots is not equal to the number of elements.
+    /// Returns [`DuplicateKnots`] if two knots are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bspline::{BSpline, BSplineError, EndCondition}, Generator, Curve, Sorted};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BSplineError> {
+    /// let knots = Sorted::new([0.0,1.0,2.0,3.0])?;
+    /// let spline = BSpline::interpolate([0.0,1.0,8.0,27.0], knots, EndCondition::NotAKnot)?;
+    /// assert_f64_near!(spline.gen(2.0), 8.0);
+    /// // not-a-knot splines reproduce cubic polynomials
+    /// assert_f64_near!(spline.gen(1.5), 3.375, 20);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`TooFewElements`]: BSplineError
+    /// [`IncongruousElementsCondition`]: BSplineError
+    /// [`KnotElementInequality`]: BSplineError
+    /// [`DuplicateKnots`]: BSplineError
+    pub fn interpolate<E, K>(
+        elements: E,
+        knots: K,
+        condition: EndCondition<T>,
+    ) -> Result<Self, BSplineError>
+    where
+        E: DiscreteGenerator<Output = T>,
+        K: SortedGenerator<Output = R>,
+    {
+        Self::interpolate_with(elements, knots, Some(condition))
+    }
+
+    /// Create a closed cubic bspline which passes through all elements at their knots.
+    ///
+    /// The curve is a seamless loop: it is twice continuously differentiable and its first and
+    /// second derivatives at the first and last knot are equal. As the curve starts and ends at
+    /// the same place, the first and last elements have to be equal.
+    /// The resulting curve has the domain from the first to the last knot.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IncongruousElementsCondition`] if less than 4 elements are given.
+    /// Returns [`UnequalEnds`] if the first and last elements are not equal.
+    /// Returns [`KnotElementInequality`] if the number of knots is not equal to the number of elements.
+    /// Returns [`DuplicateKnots`] if two knots are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bspline::{BSpline, BSplineError}, Generator, Curve, Sorted};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BSplineError> {
+    /// let knots = Sorted::new([0.0,1.0,2.0,3.0,4.0])?;
+    /// let spline = BSpline::interpolate_periodic([0.0,1.0,0.0,-1.0,0.0], knots)?;
+    /// assert_f64_near!(spline.gen(1.0), 1.0);
+    /// // start and end are joined smoothly
+    /// let [_, start, _] = spline.gen_with_derivatives::<3>(0.0);
+    /// let [_, end, _] = spline.gen_with_derivatives::<3>(4.0);
+    /// assert_f64_near!(start, end);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`IncongruousElementsCondition`]: BSplineError
+    /// [`UnequalEnds`]: BSplineError
+    /// [`KnotElementInequality`]: BSplineError
+    /// [`DuplicateKnots`]: BSplineError
+    pub fn interpolate_periodic<E, K>(elements: E, knots: K) -> Result<Self, BSplineError>
+    where
+        E: DiscreteGenerator<Output = T>,
+        K: SortedGenerator<Output = R>,
+        T: PartialEq,
+    {
+        if elements.len() >= 4 && elements.first() != elements.last() {
+            return Err(UnequalEnds::new(elements.len() - 1).into());
+        }
+        Self::interpolate_with(elements, knots, None)
+    }
+
+    /// Interpolate with the given end condition or periodic if no condition is given.
+    fn interpolate_with<E, K>(
+        elements: E,
+        knots: K,
+        condition: Option<EndCondition<T>>,
+    ) -> Result<Self, BSplineError>
+    where
+        E: DiscreteGenerator<Output = T>,
+        K: SortedGenerator<Output = R>,
+    {
+        if elements.len() < 2 {
+            return Err(TooFewElements::new(elements.len()).into());
+        }
+        if knots.len() != elements.len() {
+            return Err(KnotElementInequality::new(elements.len(), knots.len()).into());
+        }
+        if matches!(condition, None | Some(EndCondition::NotAKnot)) && elements.len() < 4 {
+            return Err(IncongruousElementsCondition::new(elements.len(), 4).into());
+        }
+        let intervals = knots.len() - 1;
+        let mut widths = Vec::with_capacity(intervals);
+        for i in 0..intervals {
+            let width = knots.gen(i + 1) - knots.gen(i);
+            if width.is_zero() {
+                return Err(DuplicateKnots::new(i).into());
+            }
+            widths.push(width);
+        }
+        let slopes = slopes(&elements, &widths, condition);
+        let knots = match condition {
+            Some(_) => clamped_knots(&knots),
+            None => periodic_knots(&knots),
+        };
+        let three = R::from_usize(3).unwrap();
+        let control_points = (0..elements.len() + 2)
+            .map(|j| {
+                let piece = j.saturating_sub(1).min(intervals - 1);
+                let start = knots[piece + 2];
+                let width = widths[piece];
+                let first = elements.gen(piece);
+                let last = elements.gen(piece + 1);
+                let bezier = [
+                    first,
+                    first + slopes[piece] * (width / three),
+                    last - slopes[piece + 1] * (width / three),
+                    last,
+                ];
+                blossom(
+                    bezier,
+                    [
+                        (knots[j] - start) / width,
+                        (knots[j + 1] - start) / width,
+                        (knots[j + 2] - start) / width,
+                    ],
+                )
+            })
+            .collect();
+        Ok(BSpline {
+            elements: control_points,
+            knots: Sorted::new_unchecked(knots),
+            space: DynSpace::new(4),
+            degree: 3,
+        })
+    }
+}
+
+/// Calculate the first derivatives at all knots of the interpolating cubic spline.
+///
+/// If no end condition is given, the spline is periodic.
+fn slopes<E, T, R>(elements: &E, widths: &[R], condition: Option<EndCondition<T>>) -> Vec<T>
+where
+    E: DiscreteGenerator<Output = T>,
+    T: Add<Output = T> + Sub<Output = T> + Mul<R, Output = T> + Copy,
+    R: Real + FromPrimitive,
+{
+    let intervals = widths.len();
+    let two = R::from_usize(2).unwrap();
+    let three = R::from_usize(3).unwrap();
+    let differences: Vec<T> = (0..intervals)
+        .map(|i| (elements.gen(i + 1) - elements.gen(i)) * widths[i].recip())
+        .collect();
+    // the continuity of the second derivative at knot i gives us the equation
+    // h_i * s_{i-1} + 2(h_{i-1} + h_i) * s_i + h_{i-1} * s_{i+1} = 3 (h_i * d_{i-1} + h_{i-1} * d_i)
+    let row = |before: usize, after: usize| {
+        (
+            widths[after],
+            two * (widths[before] + widths[after]),
+            widths[before],
+            (differences[before] * widths[after] + differences[after] * widths[before]) * three,
+        )
+    };
+    if condition.is_none() {
+        let mut lower = Vec::with_capacity(intervals);
+        let mut diagonal = Vec::with_capacity(intervals);
+        let mut upper = Vec::with_capacity(intervals);
+        let mut rhs = Vec::with_capacity(intervals);
+        for i in 0..intervals {
+            let (a, b, c, d) = row((i + intervals - 1) % intervals, i);
+            lower.push(a);
+            diagonal.push(b);
+            upper.push(c);
+            rhs.push(d);
+        }
+        let mut slopes = solve_cyclic_tridiagonal(&lower, &diagonal, &upper, rhs);
+        slopes.push(slopes[0]);
+        return slopes;
+    }
+    let mut lower = Vec::with_capacity(intervals + 1);
+    let mut diagonal = Vec::with_capacity(intervals + 1);
+    let mut upper = Vec::with_capacity(intervals + 1);
+    let mut rhs = Vec::with_capacity(intervals + 1);
+    let last = intervals - 1;
+    // first row
+    let (b, c, d) = match condition {
+        Some(EndCondition::Natural) => (two, R::one(), differences[0] * three),
+        Some(EndCondition::Clamped(start, _)) => (R::one(), R::zero(), start),
+        _ => {
+            let sum = widths[0] + widths[1];
+            (
+                widths[1],
+                sum,
+                (differences[0] * ((widths[0] + two * sum) * widths[1])
+                    + differences[1] * (widths[0] * widths[0]))
+                    * sum.recip(),
+            )
+        }
+    };
+    lower.push(R::zero());
+    diagonal.push(b);
+    upper.push(c);
+    rhs.push(d);
+    for i in 1..intervals {
+        let (a, b, c, d) = row(i - 1, i);
+        lower.push(a);
+        diagonal.push(b);
+        upper.push(c);
+        rhs.push(d);
+    }
+    // last row
+    let (a, b, d) = match condition {
+        Some(EndCondition::Natural) => (R::one(), two, differences[last] * three),
+        Some(EndCondition::Clamped(_, end)) => (R::zero(), R::one(), end),
+        _ => {
+            let sum = widths[last - 1] + widths[last];
+            (
+                sum,
+                widths[last - 1],
+                (differences[last - 1] * (widths[last] * widths[last])
+                    + differences[last] * ((two * sum + widths[last]) * widths[last - 1]))
+                    * sum.recip(),
+            )
+        }
+    };
+    lower.push(a);
+    diagonal.push(b);
+    upper.push(R::zero());
+    rhs.push(d);
+    solve_tridiagonal(&lower, &diagonal, &upper, rhs)
+}
+
+/// Solve a tridiagonal system of equations with the Thomas algorithm.
+///
+/// `lower[0]` and `upper[len-1]` are ignored.
+fn solve_tridiagonal<T, R>(lower: &[R], diagonal: &[R], upper: &[R], mut rhs: Vec<T>) -> Vec<T>
+where
+    T: Sub<Output = T> + Mul<R, Output = T> + Copy,
+    R: Real,
+{
+    let len = diagonal.len();
+    let mut factors = Vec::with_capacity(len);
+    let mut pivot = diagonal[0];
+    factors.push(upper[0] / pivot);
+    rhs[0] = rhs[0] * pivot.recip();
+    for i in 1..len {
+        pivot = diagonal[i] - lower[i] * factors[i - 1];
+        factors.push(upper[i] / pivot);
+        rhs[i] = (rhs[i] - rhs[i - 1] * lower[i]) * pivot.recip();
+    }
+    for i in (0..len - 1).rev() {
+        rhs[i] = rhs[i] - rhs[i + 1] * factors[i];
+    }
+    rhs
+}
+
+/// Solve a cyclic tridiagonal system of equations with the Sherman-Morrison formula.
+///
+/// `lower[0]` is the entry in the top right corner, `upper[len-1]` the one in the bottom left corner.
+fn solve_cyclic_tridiagonal<T, R>(lower: &[R], diagonal: &[R], upper: &[R], rhs: Vec<T>) -> Vec<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<R, Output = T> + Copy,
+    R: Real,
+{
+    let len = diagonal.len();
+    let corner_top = lower[0];
+    let corner_bottom = upper[len - 1];
+    let gamma = -diagonal[0];
+    let mut modified = diagonal.to_vec();
+    modified[0] = diagonal[0] - gamma;
+    modified[len - 1] = diagonal[len - 1] - corner_bottom * corner_top / gamma;
+    let solution = solve_tridiagonal(lower, &modified, upper, rhs);
+    let mut correction = vec![R::zero(); len];
+    correction[0] = gamma;
+    correction[len - 1] = corner_bottom;
+    let correction = solve_tridiagonal(lower, &modified, upper, correction);
+    let factor = (solution[0] + solution[len - 1] * (corner_top / gamma))
+        * (R::one() + correction[0] + corner_top * correction[len - 1] / gamma).recip();
+    IntoIterator::into_iter(solution)
+        .zip(correction)
+        .map(|(value, correction)| value - factor * correction)
+        .collect()
+}
+
+/// Evaluate the blossom of the cubic bezier curve at the given (normalized) inputs.
+fn blossom<T, R>(bezier: [T; 4], inputs: [R; 3]) -> T
+where
+    T: Merge<R> + Copy,
+    R: Real,
+{
+    let mut points = bezier;
+    for (level, input) in IntoIterator::into_iter(inputs).enumerate() {
+        for i in 0..3 - level {
+            points[i] = points[i].merge(points[i + 1], input);
+        }
+    }
+    points[0]
+}
+
+/// Knots of a cubic clamped bspline with the given knots as breakpoints.
+fn clamped_knots<K, R>(knots: &K) -> Vec<R>
+where
+    K: SortedGenerator<Output = R>,
+    R: Copy,
+{
+    let first = knots.first().unwrap();
+    let last = knots.last().unwrap();
+    let mut result = vec![first, first];
+    result.extend(knots.iter());
+    result.extend([last, last]);
+    result
+}
+
+/// Knots of a cubic periodic bspline with the given knots as breakpoints.
+fn periodic_knots<K, R>(knots: &K) -> Vec<R>
+where
+    K: SortedGenerator<Output = R>,
+    R: Real,
+{
+    let len = knots.len();
+    let period = knots.gen(len - 1) - knots.gen(0);
+    let mut result = vec![knots.gen(len - 3) - period, knots.gen(len - 2) - period];
+    result.extend(knots.iter());
+    result.extend([knots.gen(1) + period, knots.gen(2) + period]);
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Curve, Generator};
+
+    #[test]
+    fn natural() {
+        let knots = Sorted::new([0.0, 1.0, 3.0, 4.0, 6.0]).unwrap();
+        let elements = [1.0, -2.0, 0.5, 3.0, 2.0];
+        let spline = BSpline::interpolate(elements, knots, EndCondition::Natural).unwrap();
+        assert_eq!(spline.domain(), [0.0, 6.0]);
+        for (knot, element) in knots.iter().zip(elements) {
+            assert!((spline.gen(knot) - element).abs() < 1e-12);
+        }
+        let [_, _, second] = spline.gen_with_derivatives::<3>(0.0);
+        assert!(second.abs() < 1e-12);
+        let [_, _, second] = spline.gen_with_derivatives::<3>(6.0);
+        assert!(second.abs() < 1e-12);
+        // the curve is twice continuously differentiable
+        let eps = 1e-9;
+        for knot in [1.0, 3.0, 4.0] {
+            let before = spline.gen_with_derivatives::<3>(knot - eps);
+            let after = spline.gen_with_derivatives::<3>(knot + eps);
+            for (a, b) in before.iter().zip(after.iter()) {
+                assert!((a - b).abs() < 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn natural_line() {
+        let knots = Sorted::new([0.0, 2.0]).unwrap();
+        let spline = BSpline::interpolate([1.0, 5.0], knots, EndCondition::Natural).unwrap();
+        assert_f64_near!(spline.gen(0.5), 2.0);
+        assert_f64_near!(spline.gen(1.5), 4.0);
+    }
+
+    #[test]
+    fn clamped() {
+        let cubic = |x: f64| x * x * x - 2.0 * x * x + 0.5;
+        let derivative = |x: f64| 3.0 * x * x - 4.0 * x;
+        let breakpoints = [-1.0, 0.0, 0.5, 2.0, 2.5];
+        let elements = breakpoints.map(cubic);
+        let knots = Sorted::new(breakpoints).unwrap();
+        let spline = BSpline::interpolate(
+            elements,
+            knots,
+            EndCondition::Clamped(derivative(-1.0), derivative(2.5)),
+        )
+        .unwrap();
+        for x in [-1.0, -0.7, 0.2, 1.0, 1.7, 2.4, 2.5] {
+            assert!((spline.gen(x) - cubic(x)).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn not_a_knot() {
+        let cubic = |x: f64| -x * x * x + x * x + 3.0 * x - 1.0;
+        let breakpoints = [0.0, 0.3, 1.0, 1.5, 2.5, 3.0];
+        let elements = breakpoints.map(cubic);
+        let knots = Sorted::new(breakpoints).unwrap();
+        let spline = BSpline::interpolate(elements, knots, EndCondition::NotAKnot).unwrap();
+        for x in [0.0, 0.1, 0.7, 1.2, 2.0, 2.9, 3.0] {
+            assert!((spline.gen(x) - cubic(x)).abs() < 1e-12);
+        }
+        let minimal = [0.0, 1.0, 2.0, 3.0];
+        let knots = Sorted::new(minimal).unwrap();
+        let spline =
+            BSpline::interpolate(minimal.map(cubic), knots, EndCondition::NotAKnot).unwrap();
+        assert!((spline.gen(0.5) - cubic(0.5)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn periodic() {
+        let knots = Sorted::new([0.0, 1.0, 2.5, 3.0, 4.0]).unwrap();
+        let elements = [0.0, 1.0, -1.0, 2.0, 0.0];
+        let spline = BSpline::interpolate_periodic(elements, knots).unwrap();
+        assert_eq!(spline.domain(), [0.0, 4.0]);
+        for (knot, element) in knots.iter().zip(elements) {
+            assert!((spline.gen(knot) - element).abs() < 1e-12);
+        }
+        let start = spline.gen_with_derivatives::<3>(0.0);
+        let end = spline.gen_with_derivatives::<3>(4.0);
+        for (a, b) in start.iter().zip(end.iter()) {
+            assert!((a - b).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn errors() {
+        let knots = Sorted::new([0.0, 1.0, 2.0]).unwrap();
+        assert!(
+            BSpline::interpolate([1.0], Sorted::new([0.0]).unwrap(), EndCondition::Natural)
+                .is_err()
+        );
+        assert!(BSpline::interpolate([1.0, 2.0], knots, EndCondition::Natural).is_err());
+        assert!(BSpline::interpolate([1.0, 2.0, 3.0], knots, EndCondition::NotAKnot).is_err());
+        assert!(BSpline::interpolate_periodic([1.0, 2.0, 1.0], knots).is_err());
+        let four = Sorted::new([0.0, 1.0, 2.0, 3.0]).unwrap();
+        assert!(matches!(
+            BSpline::interpolate_periodic([1.0, 2.0, 3.0, 2.0], four),
+            Err(BSplineError::UnequalEnds(_))
+        ));
+        assert!(BSpline::interpolate(
+            [1.0, 2.0, 3.0],
+            Sorted::new([0.0, 1.0, 1.0]).unwrap(),
+            EndCondition::Natural
+        )
+        .is_err());
+        assert!(BSpline::interpolate([1.0, 2.0, 3.0], knots, EndCondition::Natural).is_ok());
+    }
+}
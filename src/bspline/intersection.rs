@@ -0,0 +1,287 @@
+//! Intersections of bsplines with other bsplines, lines and rays, calculated on the bezier curves of their knot spans.
+
+use super::BSpline;
+use crate::base::{intersections, line_intersections, ray_intersections, Piece};
+use crate::weights::{Homogeneous, Weighted};
+use crate::{Components, DiscreteGenerator, SortedGenerator, Space};
+use core::fmt::Debug;
+use num_traits::real::Real;
+use num_traits::FromPrimitive;
+use topology_traits::Merge;
+
+impl<K, E, S, R> BSpline<K, E, S>
+where
+    E: DiscreteGenerator,
+    E::Output: Merge<R> + Components<R> + Copy,
+    S: Space<E::Output>,
+    K: SortedGenerator<Output = R>,
+    R: Real + FromPrimitive + Debug,
+{
+    /// Returns the pairs of parameters at which this curve and the given curve intersect,
+    /// sorted by the parameter of this curve.
+    ///
+    /// Both curves are decomposed into the bezier curves of their knot spans, which are intersected pairwise.
+    /// See [`Bezier::intersect()`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// The elements are points in two dimensions as given in the examples of [`Components`].
+    ///
+    /// ```rust
+    /// # use core::ops::{Add, Mul};
+    /// # use enterpolation::{bspline::{BSpline, BSplineError}, Components, Generator};
+    /// #
+    /// # #[derive(Debug, Copy, Clone, Default, PartialEq)]
+    /// # struct Point(f64, f64);
+    /// # impl Add for Point {
+    /// #     type Output = Self;
+    /// #     fn add(self, o: Self) -> Self { Point(self.0 + o.0, self.1 + o.1) }
+    /// # }
+    /// # impl Mul<f64> for Point {
+    /// #     type Output = Self;
+    /// #     fn mul(self, s: f64) -> Self { Point(self.0 * s, self.1 * s) }
+    /// # }
+    /// # impl Components<f64> for Point {
+    /// #     fn dimension(&self) -> usize { 2 }
+    /// #     fn component(&self, index: usize) -> f64 { [self.0, self.1][index] }
+    /// #     fn set_component(&mut self, index: usize, value: f64) {
+    /// #         match index { 0 => self.0 = value, _ => self.1 = value }
+    /// #     }
+    /// # }
+    /// # fn main() -> Result<(), BSplineError> {
+    /// let zigzag = BSpline::builder()
+    ///                 .clamped()
+    ///                 .elements([Point(0.0,0.0),Point(1.0,2.0),Point(2.0,-2.0),Point(3.0,2.0),Point(4.0,0.0)])
+    ///                 .equidistant::<f64>()
+    ///                 .degree(2)
+    ///                 .normalized()
+    ///                 .constant::<3>()
+    ///                 .build()?;
+    /// let line = BSpline::builder()
+    ///                 .clamped()
+    ///                 .elements([Point(0.0,0.5),Point(4.0,0.5)])
+    ///                 .equidistant::<f64>()
+    ///                 .degree(1)
+    ///                 .normalized()
+    ///                 .constant::<2>()
+    ///                 .build()?;
+    /// let intersections = zigzag.intersect(&line, 1e-9);
+    /// assert_eq!(intersections.len(), 4);
+    /// for (t, s) in intersections {
+    ///     let (point, other) = (zigzag.gen(t), line.gen(s));
+    ///     assert!((point.0 - other.0).abs() < 1e-8);
+    ///     assert!((point.1 - 0.5).abs() < 1e-8);
+    /// }
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Bezier::intersect()`]: crate::bezier::Bezier::intersect()
+    pub fn intersect<L, F, W>(&self, other: &BSpline<L, F, W>, tolerance: R) -> Vec<(R, R)>
+    where
+        F: DiscreteGenerator<Output = E::Output>,
+        W: Space<E::Output>,
+        L: SortedGenerator<Output = R>,
+    {
+        intersections(&self.pieces(), &other.pieces(), tolerance)
+    }
+
+    /// Returns the parameters at which the curve intersects the line through the given origin
+    /// in the given direction.
+    ///
+    /// Every intersection is returned as pair of the parameter of the curve and the parameter of the line.
+    /// Intersections at knots, which are shared by two knot spans, are only returned once.
+    /// See [`Bezier::intersect_line()`] for more information.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the elements have less than two components.
+    ///
+    /// [`Bezier::intersect_line()`]: crate::bezier::Bezier::intersect_line()
+    pub fn intersect_line(&self, origin: E::Output, direction: E::Output) -> Vec<(R, R)> {
+        line_intersections(&self.pieces(), origin, direction)
+    }
+
+    /// Returns the parameters at which the curve intersects the ray starting at the given origin
+    /// in the given direction.
+    ///
+    /// See [`Bezier::intersect_ray()`] for more information.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the elements have less than two components.
+    ///
+    /// [`Bezier::intersect_ray()`]: crate::bezier::Bezier::intersect_ray()
+    pub fn intersect_ray(&self, origin: E::Output, direction: E::Output) -> Vec<(R, R)> {
+        ray_intersections(&self.pieces(), origin, direction)
+    }
+}
+
+impl<K, E, S, T, R> Weighted<BSpline<K, E, S>>
+where
+    E: DiscreteGenerator<Output = Homogeneous<T, R>>,
+    E::Output: Merge<R> + Copy,
+    S: Space<E::Output>,
+    K: SortedGenerator<Output = R>,
+    T: Components<R> + Copy,
+    R: Real + FromPrimitive + Debug,
+{
+    /// Returns the knot spans of the rational curve as rational pieces.
+    fn rational_pieces(&self) -> Vec<Piece<T, R>> {
+        self.inner_ref()
+            .pieces()
+            .into_iter()
+            .map(Piece::rational)
+            .collect()
+    }
+
+    /// Returns the pairs of parameters at which this rational curve and the given rational curve intersect,
+    /// sorted by the parameter of this curve.
+    ///
+    /// See [`BSpline::intersect()`] for more information. All weights are expected to be positive.
+    pub fn intersect<L, F, W>(
+        &self,
+        other: &Weighted<BSpline<L, F, W>>,
+        tolerance: R,
+    ) -> Vec<(R, R)>
+    where
+        F: DiscreteGenerator<Output = E::Output>,
+        W: Space<E::Output>,
+        L: SortedGenerator<Output = R>,
+    {
+        let others: Vec<_> = other
+            .inner_ref()
+            .pieces()
+            .into_iter()
+            .map(Piece::rational)
+            .collect();
+        intersections(&self.rational_pieces(), &others, tolerance)
+    }
+
+    /// Returns the parameters at which the rational curve intersects the line through the given origin
+    /// in the given direction.
+    ///
+    /// See [`BSpline::intersect_line()`] for more information.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the elements have less than two components.
+    pub fn intersect_line(&self, origin: T, direction: T) -> Vec<(R, R)> {
+        line_intersections(&self.rational_pieces(), origin, direction)
+    }
+
+    /// Returns the parameters at which the rational curve intersects the ray starting at the given origin
+    /// in the given direction.
+    ///
+    /// See [`BSpline::intersect_ray()`] for more information.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the elements have less than two components.
+    pub fn intersect_ray(&self, origin: T, direction: T) -> Vec<(R, R)> {
+        ray_intersections(&self.rational_pieces(), origin, direction)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::BSpline;
+    use crate::test_util::{distance, Point};
+    use crate::Generator;
+
+    #[test]
+    fn curves() {
+        let wave = BSpline::builder()
+            .clamped()
+            .elements([
+                Point(0.0, 0.0),
+                Point(1.0, 2.0),
+                Point(2.0, -2.0),
+                Point(3.0, 2.0),
+                Point(4.0, -2.0),
+                Point(5.0, 0.0),
+            ])
+            .equidistant::<f64>()
+            .degree(3)
+            .normalized()
+            .constant::<4>()
+            .build()
+            .unwrap();
+        let other = BSpline::builder()
+            .clamped()
+            .elements([Point(0.0, 0.3), Point(2.5, 0.5), Point(5.0, -0.3)])
+            .knots([0.0, 1.0, 2.0])
+            .constant::<2>()
+            .build()
+            .unwrap();
+        let tolerance = 1e-10;
+        let intersections = wave.intersect(&other, tolerance);
+        // the wave starts below and ends above the polyline
+        assert_eq!(intersections.len(), 5);
+        for pair in intersections.windows(2) {
+            assert!(pair[0].0 < pair[1].0);
+        }
+        for (t, s) in intersections {
+            assert!(distance(wave.gen(t), other.gen(s)) < 1e-9);
+        }
+    }
+
+    #[test]
+    fn lines() {
+        // the zigzag passes through its knot at (2.0, 0.0)
+        let zigzag = BSpline::builder()
+            .clamped()
+            .elements([Point(0.0, 1.0), Point(2.0, 0.0), Point(4.0, 1.0)])
+            .knots([0.0, 1.0, 2.0])
+            .constant::<2>()
+            .build()
+            .unwrap();
+        let intersections = zigzag.intersect_line(Point(0.0, 0.0), Point(1.0, 0.0));
+        assert_eq!(intersections, vec![(1.0, 2.0)]);
+        let intersections = zigzag.intersect_line(Point(1.0, 0.0), Point(0.0, 1.0));
+        assert_eq!(intersections.len(), 1);
+        assert_f64_near!(intersections[0].0, 0.5);
+        assert_f64_near!(intersections[0].1, 0.5);
+        let intersections = zigzag.intersect_ray(Point(2.0, 0.5), Point(1.0, 0.0));
+        assert_eq!(intersections.len(), 1);
+        assert_f64_near!(intersections[0].0, 1.5);
+        assert_f64_near!(intersections[0].1, 1.0);
+    }
+
+    #[test]
+    fn weighted() {
+        // the upper half of the unit circle
+        let half = 0.5_f64.sqrt();
+        let circle = BSpline::builder()
+            .clamped()
+            .elements_with_weights([
+                (Point(1.0, 0.0), 1.0),
+                (Point(1.0, 1.0), half),
+                (Point(0.0, 1.0), 1.0),
+                (Point(-1.0, 1.0), half),
+                (Point(-1.0, 0.0), 1.0),
+            ])
+            .knots([0.0, 1.0, 1.0, 2.0])
+            .constant::<3>()
+            .build()
+            .unwrap();
+        let intersections = circle.intersect_line(Point(0.0, 0.5), Point(1.0, 0.0));
+        assert_eq!(intersections.len(), 2);
+        for (t, s) in intersections {
+            assert!(distance(circle.gen(t), Point(s, 0.5)) < 1e-9);
+        }
+        let chord = BSpline::builder()
+            .clamped()
+            .elements_with_weights([(Point(-1.0, -1.0), 1.0), (Point(1.0, 1.0), 1.0)])
+            .knots([0.0, 1.0])
+            .constant::<2>()
+            .build()
+            .unwrap();
+        let intersections = circle.intersect(&chord, 1e-10);
+        assert_eq!(intersections.len(), 1);
+        let (t, s) = intersections[0];
+        assert!((t - 0.5).abs() < 1e-9);
+        assert!(distance(chord.gen(s), Point(half, half)) < 1e-9);
+    }
+}
@@ -34,23 +34,114 @@
 //!
 //! [`BSplineBuilder`]: BSplineBuilder
 mod adaptors;
+#[cfg(feature = "std")]
+mod bounds;
 mod builder;
+#[cfg(feature = "std")]
+mod conics;
+#[cfg(all(feature = "std", feature = "bezier"))]
+mod conversion;
+#[cfg(feature = "std")]
+mod elevation;
 mod error;
+#[cfg(feature = "std")]
+mod fitting;
+#[cfg(feature = "std")]
+mod interpolation;
+#[cfg(feature = "std")]
+mod intersection;
+#[cfg(all(feature = "std", feature = "bezier"))]
+mod offset;
+#[cfg(feature = "std")]
+mod refinement;
+#[cfg(feature = "std")]
+mod smoothing;
 
-pub use adaptors::{BorderBuffer, BorderDeletion};
+pub use adaptors::{BorderBuffer, BorderDeletion, Periodic};
 pub use builder::{BSplineBuilder, BSplineDirector};
 pub use error::{
-    BSplineError, IncongruousElementsDegree, IncongruousElementsKnots, InvalidDegree, NotSorted,
-    TooFewElements, TooSmallWorkspace,
+    BSplineError, DegreeMismatch, DiscontinuousBeziers, DuplicateKnots, IncongruousBeziersKnots,
+    IncongruousElementsCondition, IncongruousElementsDegree, IncongruousElementsKnots,
+    InvalidDegree, KnotElementInequality, KnotOutsideDomain, NotSorted, TooFewElements,
+    TooFewSamples, TooHighMultiplicity, TooSmallWorkspace, Underdetermined, UnequalEnds,
 };
+#[cfg(feature = "std")]
+pub use fitting::{Fitting, Parameterization};
+#[cfg(feature = "std")]
+pub use interpolation::EndCondition;
+#[cfg(feature = "std")]
+pub use smoothing::{Smoothing, SmoothingSpline, Smoothness};
 
 use crate::builder::Unknown;
+use crate::frames::Differentiable;
+use crate::weights::{project_derivatives, Homogeneous, Weighted};
 use crate::{Curve, DiscreteGenerator, Generator, SortedGenerator, Space};
+#[cfg(feature = "std")]
+use crate::{DynSpace, Sorted};
 use builder::Open;
 use num_traits::real::Real;
+use num_traits::FromPrimitive;
 use topology_traits::Merge;
 
 use core::fmt::Debug;
+use core::ops::{Add, Div, Mul, Sub};
+
+/// De Boor's algorithm on the given elements.
+///
+/// The elements are assumed to be the `degree + 1` elements which influence the span of `index`.
+/// All knots are shifted by `offset`, which allows to use this function for the
+/// derivatives of a bspline, as these live on the same knots without their first `offset` knots.
+/// This mutates the elements, such copying them first is necessary!
+fn de_boor<K, T, R>(
+    elements: &mut [T],
+    knots: &K,
+    offset: usize,
+    index: usize,
+    degree: usize,
+    scalar: R,
+) -> T
+where
+    K: SortedGenerator<Output = R>,
+    T: Merge<R> + Copy,
+    R: Real,
+{
+    for r in 1..=degree {
+        for j in 0..=(degree - r) {
+            let i = j + r + index - degree + offset;
+            let factor =
+                (scalar - knots.gen(i - 1)) / (knots.gen(i + degree - r) - knots.gen(i - 1));
+            elements[j] = elements[j].merge(elements[j + 1], factor);
+        }
+    }
+    elements[0]
+}
+
+/// Evaluates the blossom of the polynomial piece of the knot span `index` at the given inputs.
+///
+/// This is de Boor's algorithm, in which every step uses the next input instead of always the same one.
+/// The elements are assumed to be the `degree + 1` elements which influence the span of `index`.
+#[cfg(feature = "std")]
+fn blossom<K, T, R>(elements: &mut [T], knots: &K, index: usize, degree: usize, inputs: &[R]) -> T
+where
+    K: SortedGenerator<Output = R>,
+    T: Merge<R> + Copy,
+    R: Real,
+{
+    for (r, &input) in inputs
+        .iter()
+        .enumerate()
+        .take(degree)
+        .map(|(r, i)| (r + 1, i))
+    {
+        for j in 0..=(degree - r) {
+            let i = j + r + index - degree;
+            let factor =
+                (input - knots.gen(i - 1)) / (knots.gen(i + degree - r) - knots.gen(i - 1));
+            elements[j] = elements[j].merge(elements[j + 1], factor);
+        }
+    }
+    elements[0]
+}
 
 /// BSpline curve.
 ///
@@ -70,7 +161,7 @@ impl BSpline<Unknown, Unknown, Unknown> {
     /// Get a builder for bsplines.
     ///
     /// The builder takes:
-    /// - a mode, either [`open()`], which is default, [`clamped()`] or [`legacy()`]
+    /// - a mode, either [`open()`], which is default, [`clamped()`], [`legacy()`] or [`closed()`]
     /// - elements with [`elements()`] or [`elements_with_weights()`]
     /// - knots with [`knots()`] or [`equidistant()`]
     /// - the kind of workspace to use with [`dynamic()`], [`constant()`] or [`workspace()`]
@@ -105,6 +196,7 @@ impl BSpline<Unknown, Unknown, Unknown> {
     /// [`open()`]: BSplineBuilder::open()
     /// [`clamped()`]: BSplineBuilder::clamped()
     /// [`legacy()`]: BSplineBuilder::legacy()
+    /// [`closed()`]: BSplineBuilder::closed()
     /// [`elements()`]: BSplineBuilder::elements()
     /// [`elements_with_weights()`]: BSplineBuilder::elements_with_weights()
     /// [`knots()`]: BSplineBuilder::knots()
@@ -133,39 +225,91 @@ where
     }
 }
 
-impl<K, E, S, R> Generator<R> for BSpline<K, E, S>
+#[cfg(feature = "std")]
+impl<K, E, S, R> BSpline<K, E, S>
 where
     E: DiscreteGenerator,
-    S: Space<E::Output>,
     E::Output: Merge<R> + Copy,
-    R: Real + Debug,
+    S: Space<E::Output>,
     K: SortedGenerator<Output = R>,
+    R: Real,
 {
-    type Output = E::Output;
-    fn gen(&self, scalar: R) -> E::Output {
+    /// Decomposes the curve into the bezier curves of the non-empty knot spans of its domain.
+    ///
+    /// Returns the borders of every span together with the elements of the bezier curve equal to it.
+    /// These are given by the blossom of the span evaluated at its borders, which is the same as
+    /// inserting the borders until they occur as often as the degree of the curve.
+    fn bezier_spans(&self) -> Vec<([R; 2], Vec<E::Output>)> {
+        let degree = self.degree;
+        (degree..=self.knots.len() - degree)
+            .filter_map(|index| {
+                let start = self.knots.gen(index - 1);
+                let end = self.knots.gen(index);
+                if start >= end {
+                    return None;
+                }
+                let mut workspace = self.workspace(index);
+                let workspace = workspace.as_mut();
+                let mut inputs = vec![start; degree];
+                let elements = (0..=degree)
+                    .map(|j| {
+                        if j > 0 {
+                            inputs[degree - j] = end;
+                        }
+                        let mut elements = workspace[..=degree].to_vec();
+                        blossom(&mut elements, &self.knots, index, degree, &inputs)
+                    })
+                    .collect();
+                Some(([start, end], elements))
+            })
+            .collect()
+    }
+}
+
+impl<K, E, S> BSpline<K, E, S>
+where
+    K: SortedGenerator,
+    K::Output: PartialOrd + Copy,
+{
+    /// Returns the index of the knot span in which the given scalar lies.
+    fn knot_index(&self, scalar: K::Output) -> usize {
         // we do NOT calculaute a possible multiplicity of the scalar, as we assume
         // the chance of hitting a knot is almost zero.
         let lower_cut = self.degree;
         let upper_cut = self.knots.len() - self.degree;
         // The strict_upper_bound is easier to calculate and behaves nicely on the edges of the array.
         // Such it is more ergonomic than using upper_border.
-        let index = self
-            .knots
-            .strict_upper_bound_clamped(scalar, lower_cut, upper_cut);
+        self.knots
+            .strict_upper_bound_clamped(scalar, lower_cut, upper_cut)
+    }
 
+    /// Returns the degree of the curve.
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+}
+
+impl<K, E, S, R> Generator<R> for BSpline<K, E, S>
+where
+    E: DiscreteGenerator,
+    S: Space<E::Output>,
+    E::Output: Merge<R> + Copy,
+    R: Real + Debug,
+    K: SortedGenerator<Output = R>,
+{
+    type Output = E::Output;
+    fn gen(&self, scalar: R) -> E::Output {
+        let index = self.knot_index(scalar);
         //copy elements into workspace
         let mut workspace = self.workspace(index);
-        let elements = workspace.as_mut();
-
-        for r in 1..=self.degree {
-            for j in 0..=(self.degree - r) {
-                let i = j + r + index - self.degree;
-                let factor = (scalar - self.knots.gen(i - 1))
-                    / (self.knots.gen(i + self.degree - r) - self.knots.gen(i - 1));
-                elements[j] = elements[j].merge(elements[j + 1], factor);
-            }
-        }
-        elements[0]
+        de_boor(
+            workspace.as_mut(),
+            &self.knots,
+            0,
+            index,
+            self.degree,
+            scalar,
+        )
     }
 }
 
@@ -185,6 +329,207 @@ where
     }
 }
 
+impl<K, E, S, R> BSpline<K, E, S>
+where
+    E: DiscreteGenerator,
+    S: Space<E::Output>,
+    E::Output: Merge<R> + Mul<R, Output = E::Output> + Sub<Output = E::Output> + Copy,
+    R: Real + FromPrimitive + Debug,
+    K: SortedGenerator<Output = R>,
+{
+    /// Generate the value and its tangent, in this order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bspline::{BSpline, BSplineError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BSplineError> {
+    /// let bspline = BSpline::builder()
+    ///                 .clamped()
+    ///                 .elements([0.0,1.0,4.0])
+    ///                 .equidistant::<f64>()
+    ///                 .degree(2)
+    ///                 .normalized()
+    ///                 .constant::<3>()
+    ///                 .build()?;
+    /// // this bspline is the same as the polynomial 2x^2 + 2x
+    /// let [value, tangent] = bspline.gen_with_tangent(0.5);
+    /// assert_f64_near!(value, 1.5);
+    /// assert_f64_near!(tangent, 4.0);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn gen_with_tangent(&self, scalar: R) -> [E::Output; 2] {
+        self.gen_with_derivatives(scalar)
+    }
+
+    /// Generate the value and its derivatives, the order hereby is from value, then first derivative, then second and so on.
+    ///
+    /// All derivatives of higher order than the degree of the curve are zero.
+    pub fn gen_with_derivatives<const N: usize>(&self, scalar: R) -> [E::Output; N] {
+        let index = self.knot_index(scalar);
+        // the workspace holds the elements of the derivatives of the current order,
+        // starting with the elements of the curve itself.
+        let mut workspace = self.workspace(index);
+        let elements = workspace.as_mut();
+        // take a zero out vector which can be copied to initialise the array (and have the right default)
+        let mut derivatives = [elements[0] * R::zero(); N];
+        for (order, derivative) in derivatives.iter_mut().enumerate().take(self.degree + 1) {
+            let degree = self.degree - order;
+            if order > 0 {
+                // difference folding to get the elements of the next derivative
+                let factor = R::from_usize(degree + 1).unwrap();
+                for j in 0..=degree {
+                    let span = self.knots.gen(index + j)
+                        - self.knots.gen(index + j + order - 1 - self.degree);
+                    elements[j] = if span.is_zero() {
+                        elements[j] * R::zero()
+                    } else {
+                        (elements[j + 1] - elements[j]) * (factor / span)
+                    };
+                }
+            }
+            let mut evaluation = self.space.workspace();
+            let evaluation = evaluation.as_mut();
+            evaluation[..=degree].copy_from_slice(&elements[..=degree]);
+            *derivative = de_boor(
+                evaluation,
+                &self.knots,
+                order,
+                index - order,
+                degree,
+                scalar,
+            );
+        }
+        derivatives
+    }
+}
+
+impl<K, E, S, R> Differentiable<R> for BSpline<K, E, S>
+where
+    E: DiscreteGenerator,
+    S: Space<E::Output>,
+    E::Output: Merge<R> + Mul<R, Output = E::Output> + Sub<Output = E::Output> + Copy,
+    R: Real + FromPrimitive + Debug,
+    K: SortedGenerator<Output = R>,
+{
+    fn gen_with_derivatives<const N: usize>(&self, scalar: R) -> [E::Output; N] {
+        BSpline::gen_with_derivatives(self, scalar)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, E, S, R> BSpline<K, E, S>
+where
+    E: DiscreteGenerator,
+    S: Space<E::Output>,
+    E::Output: Mul<R, Output = E::Output> + Sub<Output = E::Output> + Copy,
+    R: Real + FromPrimitive,
+    K: SortedGenerator<Output = R>,
+{
+    /// Create the derivative of this curve.
+    ///
+    /// The derivative of a bspline is again a bspline with a degree of one less than the original curve.
+    /// Its elements are the scaled differences of the elements of the original curve and
+    /// the knots are the same, except the first and the last one.
+    ///
+    /// # Errors
+    ///
+    /// [`InvalidDegree`] if the curve has a degree of 1, as curves of degree 0 are not supported.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bspline::{BSpline, BSplineError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BSplineError> {
+    /// let bspline = BSpline::builder()
+    ///                 .clamped()
+    ///                 .elements([0.0,1.0,4.0])
+    ///                 .equidistant::<f64>()
+    ///                 .degree(2)
+    ///                 .normalized()
+    ///                 .constant::<3>()
+    ///                 .build()?;
+    /// let derivative = bspline.derivative()?;
+    /// assert_eq!(derivative.degree(), 1);
+    /// assert_f64_near!(derivative.gen(0.5), bspline.gen_with_tangent(0.5)[1]);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`InvalidDegree`]: BSplineError
+    #[allow(clippy::type_complexity)]
+    pub fn derivative(
+        &self,
+    ) -> Result<BSpline<Sorted<Vec<R>>, Vec<E::Output>, DynSpace<E::Output>>, BSplineError> {
+        if self.degree < 2 {
+            return Err(InvalidDegree::new(self.degree - 1).into());
+        }
+        let factor = R::from_usize(self.degree).unwrap();
+        let elements = (0..self.elements.len() - 1)
+            .map(|i| {
+                let span = self.knots.gen(i + self.degree) - self.knots.gen(i);
+                let first = self.elements.gen(i);
+                if span.is_zero() {
+                    return first * R::zero();
+                }
+                (self.elements.gen(i + 1) - first) * (factor / span)
+            })
+            .collect();
+        let knots = (1..self.knots.len() - 1)
+            .map(|i| self.knots.gen(i))
+            .collect();
+        Ok(BSpline {
+            elements,
+            knots: Sorted::new_unchecked(knots),
+            space: DynSpace::new(self.degree),
+            degree: self.degree - 1,
+        })
+    }
+}
+
+impl<K, E, S, T, R> Weighted<BSpline<K, E, S>>
+where
+    E: DiscreteGenerator<Output = Homogeneous<T, R>>,
+    S: Space<E::Output>,
+    T: Add<Output = T> + Mul<R, Output = T> + Sub<Output = T> + Div<R, Output = T> + Copy,
+    R: Real + FromPrimitive + Debug,
+    K: SortedGenerator<Output = R>,
+{
+    /// Generate the value and its tangent, in this order.
+    pub fn gen_with_tangent(&self, scalar: R) -> [T; 2] {
+        self.gen_with_derivatives(scalar)
+    }
+
+    /// Generate the value and its derivatives, the order hereby is from value, then first derivative, then second and so on.
+    ///
+    /// The derivatives of the rational curve are calculated with the quotient rule
+    /// from the derivatives of the underlying homogeneous curve.
+    pub fn gen_with_derivatives<const N: usize>(&self, scalar: R) -> [T; N] {
+        let homogeneous: [Homogeneous<T, R>; N] = self.inner_ref().gen_with_derivatives(scalar);
+        project_derivatives(homogeneous)
+    }
+}
+
+impl<K, E, S, T, R> Differentiable<R> for Weighted<BSpline<K, E, S>>
+where
+    E: DiscreteGenerator<Output = Homogeneous<T, R>>,
+    S: Space<E::Output>,
+    T: Add<Output = T> + Mul<R, Output = T> + Sub<Output = T> + Div<R, Output = T> + Copy,
+    R: Real + FromPrimitive + Debug,
+    K: SortedGenerator<Output = R>,
+{
+    fn gen_with_derivatives<const N: usize>(&self, scalar: R) -> [T; N] {
+        Weighted::gen_with_derivatives(self, scalar)
+    }
+}
+
 impl<K, E, S> BSpline<K, E, S>
 where
     E: DiscreteGenerator,
@@ -285,8 +630,8 @@ mod test {
             .constant::<2>()
             .build()
             .unwrap();
-        for i in 0..expect.len() {
-            assert_f32_near!(spline.gen(expect[i].0), expect[i].1);
+        for (input, output) in expect {
+            assert_f32_near!(spline.gen(input), output);
         }
     }
     #[test]
@@ -310,8 +655,8 @@ mod test {
             .constant::<3>()
             .build()
             .unwrap();
-        for i in 0..expect.len() {
-            assert_f32_near!(spline.gen(expect[i].0), expect[i].1);
+        for (input, output) in expect {
+            assert_f32_near!(spline.gen(input), output);
         }
     }
     #[test]
@@ -334,8 +679,8 @@ mod test {
             .constant::<4>()
             .build()
             .unwrap();
-        for i in 0..expect.len() {
-            assert_f32_near!(spline.gen(expect[i].0), expect[i].1);
+        for (input, output) in expect {
+            assert_f32_near!(spline.gen(input), output);
         }
     }
     #[test]
@@ -361,8 +706,8 @@ mod test {
             .constant::<5>()
             .build()
             .unwrap();
-        for i in 0..expect.len() {
-            assert_f32_near!(spline.gen(expect[i].0), expect[i].1);
+        for (input, output) in expect {
+            assert_f32_near!(spline.gen(input), output);
         }
     }
     #[test]
@@ -388,8 +733,84 @@ mod test {
             .constant::<5>()
             .build()
             .unwrap();
-        for i in 0..expect.len() {
-            assert_f64_near!(spline.gen(expect[i].0), expect[i].1);
+        for (input, output) in expect {
+            assert_f64_near!(spline.gen(input), output);
+        }
+    }
+
+    #[test]
+    fn derivatives() {
+        let points = [0.0, 0.0, 0.0, 6.0, 0.0, 0.0, 0.0];
+        let knots = [-2.0, -2.0, -2.0, -1.0, 0.0, 1.0, 2.0, 2.0, 2.0];
+        let spline = BSpline::builder()
+            .elements(points)
+            .knots(knots)
+            .constant::<4>()
+            .build()
+            .unwrap();
+        let eps = 1e-6;
+        for t in [-1.9, -1.5, -0.6, 0.2, 0.5, 1.5, 1.9] {
+            let [value, first, second, third, fourth] = spline.gen_with_derivatives::<5>(t);
+            assert_f64_near!(value, spline.gen(t));
+            let difference = (spline.gen(t + eps) - spline.gen(t - eps)) / (2.0 * eps);
+            assert!((first - difference).abs() < 1e-6);
+            let [_, next] = spline.gen_with_tangent(t + eps);
+            let [_, prev] = spline.gen_with_tangent(t - eps);
+            assert!((second - (next - prev) / (2.0 * eps)).abs() < 1e-6);
+            let third_difference = (spline.gen_with_derivatives::<3>(t + eps)[2]
+                - spline.gen_with_derivatives::<3>(t - eps)[2])
+                / (2.0 * eps);
+            assert!((third - third_difference).abs() < 1e-6);
+            assert_f64_near!(fourth, 0.0);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn derivative_curve() {
+        let points = [0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0];
+        let knots = [0.0, 0.0, 0.0, 0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 5.0, 5.0, 5.0];
+        let spline = BSpline::builder()
+            .elements(points)
+            .knots(knots)
+            .constant::<5>()
+            .build()
+            .unwrap();
+        let derivative = spline.derivative().unwrap();
+        assert_eq!(derivative.degree(), 3);
+        assert_eq!(derivative.domain(), spline.domain());
+        let second = derivative.derivative().unwrap();
+        for t in [0.0, 0.4, 1.0, 2.5, 3.2, 4.1, 5.0] {
+            let [_, first_expected, second_expected] = spline.gen_with_derivatives::<3>(t);
+            assert!((derivative.gen(t) - first_expected).abs() < 1e-12);
+            assert!((second.gen(t) - second_expected).abs() < 1e-12);
+        }
+        let linear = BSpline::builder()
+            .elements([0.0, 1.0])
+            .knots([0.0, 1.0])
+            .constant::<2>()
+            .build()
+            .unwrap();
+        assert!(linear.derivative().is_err());
+    }
+
+    #[test]
+    fn weighted_derivatives() {
+        let spline = BSpline::builder()
+            .elements_with_weights([(1.0, 1.0), (3.0, 0.5), (-2.0, 2.0), (0.5, 1.5)])
+            .knots([0.0, 0.0, 1.0, 2.0, 2.0])
+            .constant::<3>()
+            .build()
+            .unwrap();
+        let eps = 1e-6;
+        for t in [0.1, 0.5, 0.9, 1.3, 1.8] {
+            let [value, first, second] = spline.gen_with_derivatives::<3>(t);
+            assert_f64_near!(value, spline.gen(t));
+            let difference = (spline.gen(t + eps) - spline.gen(t - eps)) / (2.0 * eps);
+            assert!((first - difference).abs() < 1e-6);
+            let [_, next] = spline.gen_with_tangent(t + eps);
+            let [_, prev] = spline.gen_with_tangent(t - eps);
+            assert!((second - (next - prev) / (2.0 * eps)).abs() < 1e-5);
         }
     }
 }
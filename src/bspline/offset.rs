@@ -0,0 +1,186 @@
+//! Offset curves of planar bsplines, approximated by paths of cubic bezier curves.
+
+use super::BSpline;
+use crate::bezier::{offset_curve, Bezier};
+use crate::weights::{Homogeneous, Weighted};
+use crate::{Components, ConstSpace, DiscreteGenerator, SortedGenerator, Space};
+use core::fmt::Debug;
+use core::ops::{Add, Div, Mul, Sub};
+use num_traits::real::Real;
+use num_traits::FromPrimitive;
+use topology_traits::Merge;
+
+impl<K, E, S, R> BSpline<K, E, S>
+where
+    E: DiscreteGenerator,
+    E::Output: Components<R>
+        + Merge<R>
+        + Mul<R, Output = E::Output>
+        + Sub<Output = E::Output>
+        + Default
+        + Copy,
+    S: Space<E::Output>,
+    K: SortedGenerator<Output = R>,
+    R: Real + FromPrimitive + Debug,
+{
+    /// Returns a path of cubic bezier curves approximating the offset curve in the given distance.
+    ///
+    /// The domain is split at the knots, at which the curvature may jump. Positive distances
+    /// offset to the left of the curve. See [`Bezier::offset()`] for more information.
+    ///
+    /// Where the curve has a corner, the offset curve has a gap, which is not closed.
+    ///
+    /// # Examples
+    ///
+    /// The elements are points in two dimensions as given in the examples of [`Components`].
+    ///
+    /// ```rust
+    /// # use core::ops::{Add, Mul, Sub};
+    /// # use enterpolation::{bspline::{BSpline, BSplineError}, Components, Generator};
+    /// #
+    /// # #[derive(Debug, Copy, Clone, Default, PartialEq)]
+    /// # struct Point(f64, f64);
+    /// # impl Add for Point {
+    /// #     type Output = Self;
+    /// #     fn add(self, o: Self) -> Self { Point(self.0 + o.0, self.1 + o.1) }
+    /// # }
+    /// # impl Sub for Point {
+    /// #     type Output = Self;
+    /// #     fn sub(self, o: Self) -> Self { Point(self.0 - o.0, self.1 - o.1) }
+    /// # }
+    /// # impl Mul<f64> for Point {
+    /// #     type Output = Self;
+    /// #     fn mul(self, s: f64) -> Self { Point(self.0 * s, self.1 * s) }
+    /// # }
+    /// # impl Components<f64> for Point {
+    /// #     fn dimension(&self) -> usize { 2 }
+    /// #     fn component(&self, index: usize) -> f64 { [self.0, self.1][index] }
+    /// #     fn set_component(&mut self, index: usize, value: f64) {
+    /// #         match index { 0 => self.0 = value, _ => self.1 = value }
+    /// #     }
+    /// # }
+    /// # fn main() -> Result<(), BSplineError> {
+    /// // a polyline along the x-axis
+    /// let line = BSpline::builder()
+    ///                 .clamped()
+    ///                 .elements([Point(0.0,0.0),Point(1.0,0.0),Point(3.0,0.0)])
+    ///                 .knots([0.0,1.0,2.0])
+    ///                 .constant::<2>()
+    ///                 .build()?;
+    /// let path = line.offset(0.5, 1e-6);
+    /// // one cubic bezier curve for every knot span
+    /// assert_eq!(path.len(), 2);
+    /// assert_eq!(path[0].gen(0.0), Point(0.0,0.5));
+    /// assert_eq!(path[1].gen(1.0), Point(3.0,0.5));
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Bezier::offset()`]: crate::bezier::Bezier::offset()
+    #[allow(clippy::type_complexity)]
+    pub fn offset(
+        &self,
+        distance: R,
+        tolerance: R,
+    ) -> Vec<Bezier<R, [E::Output; 4], ConstSpace<E::Output, 4>>> {
+        offset_curve(self, &self.breakpoints(), distance, tolerance)
+    }
+}
+
+impl<K, E, S, T, R> Weighted<BSpline<K, E, S>>
+where
+    E: DiscreteGenerator<Output = Homogeneous<T, R>>,
+    S: Space<E::Output>,
+    K: SortedGenerator<Output = R>,
+    T: Components<R>
+        + Default
+        + Add<Output = T>
+        + Mul<R, Output = T>
+        + Sub<Output = T>
+        + Div<R, Output = T>
+        + Copy,
+    R: Real + FromPrimitive + Debug,
+{
+    /// Returns a path of cubic bezier curves approximating the offset curve of the rational curve
+    /// in the given distance.
+    ///
+    /// See [`BSpline::offset()`] for more information.
+    pub fn offset(&self, distance: R, tolerance: R) -> Vec<Bezier<R, [T; 4], ConstSpace<T, 4>>> {
+        offset_curve(self, &self.inner_ref().breakpoints(), distance, tolerance)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::BSpline;
+    use crate::test_util::{distance, Point};
+    use crate::{Curve, Generator};
+
+    #[test]
+    fn offset() {
+        let spline = BSpline::builder()
+            .clamped()
+            .elements([
+                Point(0.0, 0.0),
+                Point(1.0, 2.0),
+                Point(2.0, -1.0),
+                Point(4.0, 1.0),
+                Point(5.0, 0.0),
+            ])
+            .knots([0.0, 1.0, 2.0, 3.0])
+            .constant::<3>()
+            .build()
+            .unwrap();
+        assert_eq!(spline.breakpoints(), vec![0.0, 1.0, 2.0, 3.0]);
+        let offset = 0.1;
+        let tolerance = 1e-5;
+        let path = spline.offset(offset, tolerance);
+        assert!(path.len() >= 3);
+        let samples: Vec<Point> = spline.take(20001).collect();
+        for pair in path.windows(2) {
+            assert!(distance(pair[0].gen(1.0), pair[1].gen(0.0)) < 2.0 * tolerance);
+        }
+        for bezier in path.iter() {
+            for point in bezier.take(11) {
+                let closest = samples
+                    .iter()
+                    .map(|&sample| distance(sample, point))
+                    .fold(f64::MAX, f64::min);
+                assert!((closest - offset).abs() < 1e-3);
+            }
+        }
+        let [start, _] = spline.domain();
+        // the spline starts up and to the right
+        let normal = Point(-2.0, 1.0) * (offset / 5.0_f64.sqrt());
+        assert!(distance(path[0].gen(0.0), spline.gen(start) + normal) < 1e-12);
+    }
+
+    #[test]
+    fn weighted() {
+        // the upper half of the unit circle, running counterclockwise
+        let half = 0.5_f64.sqrt();
+        let circle = BSpline::builder()
+            .clamped()
+            .elements_with_weights([
+                (Point(1.0, 0.0), 1.0),
+                (Point(1.0, 1.0), half),
+                (Point(0.0, 1.0), 1.0),
+                (Point(-1.0, 1.0), half),
+                (Point(-1.0, 0.0), 1.0),
+            ])
+            .knots([0.0, 1.0, 1.0, 2.0])
+            .constant::<3>()
+            .build()
+            .unwrap();
+        let tolerance = 1e-6;
+        let path = circle.offset(-0.25, tolerance);
+        for bezier in path.iter() {
+            for point in bezier.take(11) {
+                assert!((distance(point, Point(0.0, 0.0)) - 1.25).abs() < 2.0 * tolerance);
+            }
+        }
+        assert!(distance(path[0].gen(0.0), Point(1.25, 0.0)) < 1e-12);
+        assert!(distance(path[path.len() - 1].gen(1.0), Point(-1.25, 0.0)) < 1e-12);
+    }
+}
@@ -0,0 +1,385 @@
+//! Knot insertion and refinement, which add elements to a bspline without changing its shape.
+
+use super::error::{BSplineError, KnotOutsideDomain, TooHighMultiplicity};
+use super::{blossom, BSpline};
+use crate::weights::{Homogeneous, Weighted};
+use crate::{DiscreteGenerator, DynSpace, Sorted, SortedGenerator, Space};
+use core::cmp::Ordering;
+use num_traits::real::Real;
+use topology_traits::Merge;
+
+/// Type alias for bsplines which own their elements and knots.
+pub(super) type OwnedBSpline<R, T> = BSpline<Sorted<Vec<R>>, Vec<T>, DynSpace<T>>;
+/// Type alias for weighted bsplines which own their elements and knots.
+pub(super) type OwnedWeightedBSpline<T, R> = Weighted<OwnedBSpline<R, Homogeneous<T, R>>>;
+
+impl<K, E, S, R> BSpline<K, E, S>
+where
+    E: DiscreteGenerator,
+    E::Output: Merge<R> + Copy,
+    S: Space<E::Output>,
+    K: SortedGenerator<Output = R>,
+    R: Real,
+{
+    /// Insert a knot into the curve without changing its shape.
+    ///
+    /// This is done with Boehm's algorithm, which adds one element to the curve.
+    /// Inserting knots allows to edit a curve more locally.
+    ///
+    /// # Errors
+    ///
+    /// [`KnotOutsideDomain`] if the knot does not lie within the domain of the curve.
+    /// [`TooHighMultiplicity`] if the knot would occur more often than the degree of the curve.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bspline::{BSpline, BSplineError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BSplineError> {
+    /// let bspline = BSpline::builder()
+    ///                 .clamped()
+    ///                 .elements([0.0,5.0,3.0,10.0])
+    ///                 .equidistant::<f64>()
+    ///                 .degree(3)
+    ///                 .normalized()
+    ///                 .constant::<4>()
+    ///                 .build()?;
+    /// let refined = bspline.insert_knot(0.3)?;
+    /// assert_eq!(refined.degree(), 3);
+    /// assert_f64_near!(refined.gen(0.7), bspline.gen(0.7));
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`KnotOutsideDomain`]: BSplineError
+    /// [`TooHighMultiplicity`]: BSplineError
+    pub fn insert_knot(&self, knot: R) -> Result<OwnedBSpline<R, E::Output>, BSplineError> {
+        self.insert_knot_times(knot, 1)
+    }
+
+    /// Insert a knot multiple times into the curve without changing its shape.
+    ///
+    /// Every insertion adds one element to the curve. Inserting a knot such that its multiplicity
+    /// equals the degree of the curve causes the curve to pass through one of its elements.
+    ///
+    /// # Errors
+    ///
+    /// [`KnotOutsideDomain`] if the knot does not lie within the domain of the curve.
+    /// [`TooHighMultiplicity`] if the knot would occur more often than the degree of the curve.
+    ///
+    /// [`KnotOutsideDomain`]: BSplineError
+    /// [`TooHighMultiplicity`]: BSplineError
+    pub fn insert_knot_times(
+        &self,
+        knot: R,
+        times: usize,
+    ) -> Result<OwnedBSpline<R, E::Output>, BSplineError> {
+        self.check_domain(knot, 0)?;
+        let mut knots: Vec<R> = self.knots.iter().collect();
+        let mut elements: Vec<E::Output> = self.elements.iter().collect();
+        let multiplicity = knots.iter().filter(|&val| val == knot).count() + times;
+        if multiplicity > self.degree {
+            return Err(TooHighMultiplicity::new(multiplicity, self.degree).into());
+        }
+        for _ in 0..times {
+            boehm(&mut elements, &mut knots, self.degree, knot);
+        }
+        Ok(BSpline {
+            elements,
+            knots: Sorted::new_unchecked(knots),
+            space: DynSpace::new(self.degree + 1),
+            degree: self.degree,
+        })
+    }
+
+    /// Insert all given knots at once into the curve without changing its shape.
+    ///
+    /// The knots do not have to be sorted. All elements of the refined curve are calculated
+    /// directly from the elements of this curve, as it is done in the Oslo algorithm.
+    /// This is faster than inserting many knots one by one.
+    ///
+    /// # Errors
+    ///
+    /// [`KnotOutsideDomain`] if a knot does not lie within the domain of the curve.
+    /// [`TooHighMultiplicity`] if a knot would occur more often than the degree of the curve.
+    ///
+    /// [`KnotOutsideDomain`]: BSplineError
+    /// [`TooHighMultiplicity`]: BSplineError
+    pub fn refine<G>(&self, knots: G) -> Result<OwnedBSpline<R, E::Output>, BSplineError>
+    where
+        G: DiscreteGenerator<Output = R>,
+    {
+        let mut inserted: Vec<R> = knots.iter().collect();
+        for (index, knot) in inserted.iter().enumerate() {
+            self.check_domain(knot, index)?;
+        }
+        inserted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        let merged = merge_sorted(&self.knots, &inserted);
+        // check the multiplicity of all knots which got inserted
+        let mut start = 0;
+        for end in 1..=merged.len() {
+            if end < merged.len() && merged[end] == merged[start] {
+                continue;
+            }
+            if end - start > self.degree && inserted.iter().any(|knot| knot == merged[start]) {
+                return Err(TooHighMultiplicity::new(end - start, self.degree).into());
+            }
+            start = end;
+        }
+        let degree = self.degree;
+        let len = self.elements.len() + inserted.len();
+        let upper = self.knots.len() - degree;
+        let elements = (0..len)
+            .map(|j| {
+                // find a non-empty span of the refined curve which is influenced by the element
+                let span = (j.max(degree)..=(j + degree).min(len - 1))
+                    .find(|&k| merged[k - 1] < merged[k])
+                    .unwrap_or_else(|| j.max(degree).min(len - 1));
+                // this span is part of a span of the original curve
+                let index = self
+                    .knots
+                    .strict_upper_bound_clamped(merged[span - 1], degree, upper);
+                let mut workspace = self.workspace(index);
+                blossom(
+                    workspace.as_mut(),
+                    &self.knots,
+                    index,
+                    degree,
+                    &merged[j..j + degree],
+                )
+            })
+            .collect();
+        Ok(BSpline {
+            elements,
+            knots: Sorted::new_unchecked(merged),
+            space: DynSpace::new(self.degree + 1),
+            degree: self.degree,
+        })
+    }
+
+    /// Returns an error if the knot does not lie within the domain.
+    fn check_domain(&self, knot: R, index: usize) -> Result<(), KnotOutsideDomain> {
+        let start = self.knots.gen(self.degree - 1);
+        let end = self.knots.gen(self.knots.len() - self.degree);
+        if knot >= start && knot <= end {
+            Ok(())
+        } else {
+            Err(KnotOutsideDomain::new(index))
+        }
+    }
+}
+
+impl<K, E, S, T, R> Weighted<BSpline<K, E, S>>
+where
+    E: DiscreteGenerator<Output = Homogeneous<T, R>>,
+    E::Output: Merge<R> + Copy,
+    S: Space<E::Output>,
+    K: SortedGenerator<Output = R>,
+    R: Real,
+{
+    /// Insert a knot into the curve without changing its shape.
+    ///
+    /// The insertion is done with the homogeneous elements, such the weights are changed accordingly.
+    ///
+    /// # Errors
+    ///
+    /// [`KnotOutsideDomain`] if the knot does not lie within the domain of the curve.
+    /// [`TooHighMultiplicity`] if the knot would occur more often than the degree of the curve.
+    ///
+    /// [`KnotOutsideDomain`]: BSplineError
+    /// [`TooHighMultiplicity`]: BSplineError
+    pub fn insert_knot(&self, knot: R) -> Result<OwnedWeightedBSpline<T, R>, BSplineError> {
+        self.inner_ref().insert_knot(knot).map(Weighted::new)
+    }
+
+    /// Insert a knot multiple times into the curve without changing its shape.
+    ///
+    /// The insertion is done with the homogeneous elements, such the weights are changed accordingly.
+    ///
+    /// # Errors
+    ///
+    /// [`KnotOutsideDomain`] if the knot does not lie within the domain of the curve.
+    /// [`TooHighMultiplicity`] if the knot would occur more often than the degree of the curve.
+    ///
+    /// [`KnotOutsideDomain`]: BSplineError
+    /// [`TooHighMultiplicity`]: BSplineError
+    pub fn insert_knot_times(
+        &self,
+        knot: R,
+        times: usize,
+    ) -> Result<OwnedWeightedBSpline<T, R>, BSplineError> {
+        self.inner_ref()
+            .insert_knot_times(knot, times)
+            .map(Weighted::new)
+    }
+
+    /// Insert all given knots at once into the curve without changing its shape.
+    ///
+    /// The refinement is done with the homogeneous elements, such the weights are changed accordingly.
+    ///
+    /// # Errors
+    ///
+    /// [`KnotOutsideDomain`] if a knot does not lie within the domain of the curve.
+    /// [`TooHighMultiplicity`] if a knot would occur more often than the degree of the curve.
+    ///
+    /// [`KnotOutsideDomain`]: BSplineError
+    /// [`TooHighMultiplicity`]: BSplineError
+    pub fn refine<G>(&self, knots: G) -> Result<OwnedWeightedBSpline<T, R>, BSplineError>
+    where
+        G: DiscreteGenerator<Output = R>,
+    {
+        self.inner_ref().refine(knots).map(Weighted::new)
+    }
+}
+
+/// Boehm's algorithm to insert a single knot.
+///
+/// Replaces the `degree - 1` elements influenced by the new knot with `degree` new elements.
+fn boehm<T, R>(elements: &mut Vec<T>, knots: &mut Vec<R>, degree: usize, knot: R)
+where
+    T: Merge<R> + Copy,
+    R: Real,
+{
+    let position = knots.partition_point(|&val| val <= knot);
+    let index = position.max(degree).min(knots.len() - degree);
+    let inserted: Vec<T> = (index + 1 - degree..=index)
+        .map(|i| {
+            let factor = (knot - knots[i - 1]) / (knots[i + degree - 1] - knots[i - 1]);
+            elements[i - 1].merge(elements[i], factor)
+        })
+        .collect();
+    elements.splice(index + 1 - degree..index, inserted);
+    knots.insert(position, knot);
+}
+
+/// Merges the sorted knots with the sorted values given.
+fn merge_sorted<K, R>(knots: &K, values: &[R]) -> Vec<R>
+where
+    K: DiscreteGenerator<Output = R>,
+    R: Real,
+{
+    let mut merged = Vec::with_capacity(knots.len() + values.len());
+    let mut values = values.iter().copied().peekable();
+    for knot in knots.iter() {
+        while let Some(value) = values.next_if(|&value| value < knot) {
+            merged.push(value);
+        }
+        merged.push(knot);
+    }
+    merged.extend(values);
+    merged
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::BSpline;
+    use crate::weights::Homogeneous;
+    use crate::{Curve, Generator};
+
+    #[test]
+    fn insert_knot() {
+        let elements: [f64; 5] = [0.0, 5.0, 3.0, 10.0, 7.0];
+        let spline = BSpline::builder()
+            .clamped()
+            .elements(elements)
+            .knots([0.0, 1.0, 3.0])
+            .constant::<4>()
+            .build()
+            .unwrap();
+        let refined = spline.insert_knot(0.5).unwrap();
+        assert_eq!(refined.degree(), 3);
+        assert_eq!(refined.domain(), spline.domain());
+        for (a, b) in spline.take(31).zip(refined.take(31)) {
+            assert!((a - b).abs() < 1e-10);
+        }
+        // inserting an existing knot also works
+        let refined = spline.insert_knot(1.0).unwrap();
+        for (a, b) in spline.take(31).zip(refined.take(31)) {
+            assert!((a - b).abs() < 1e-10);
+        }
+        // inserting a knot up to the degree makes the curve pass through an element
+        let refined = spline.insert_knot_times(2.0, 3).unwrap();
+        assert_eq!(refined.elements.len(), 8);
+        assert!((refined.elements[4] - spline.gen(2.0)).abs() < 1e-10);
+        for (a, b) in spline.take(31).zip(refined.take(31)) {
+            assert!((a - b).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn refine() {
+        let elements: [f64; 6] = [0.0, 5.0, 3.0, 10.0, 7.0, 1.0];
+        let spline = BSpline::builder()
+            .elements(elements)
+            .knots([0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0])
+            .constant::<4>()
+            .build()
+            .unwrap();
+        let refined = spline.refine([4.5, 2.5, 3.0, 2.5, 3.25]).unwrap();
+        assert_eq!(refined.elements.len(), 11);
+        assert_eq!(refined.domain(), spline.domain());
+        // refinement and insertion one by one yield the same result
+        let inserted = spline
+            .insert_knot(2.5)
+            .and_then(|s| s.insert_knot_times(2.5, 1))
+            .and_then(|s| s.insert_knot(3.0))
+            .and_then(|s| s.insert_knot(3.25))
+            .and_then(|s| s.insert_knot(4.5))
+            .unwrap();
+        for (a, b) in inserted.elements.iter().zip(refined.elements.iter()) {
+            assert!((a - b).abs() < 1e-10);
+        }
+        for (a, b) in spline.take(31).zip(refined.take(31)) {
+            assert!((a - b).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn weighted() {
+        let elements: [(f64, f64); 4] = [(0.0, 1.0), (5.0, 2.0), (3.0, 0.5), (10.0, 1.0)];
+        let spline = BSpline::builder()
+            .clamped()
+            .elements_with_weights(elements)
+            .equidistant::<f64>()
+            .degree(2)
+            .normalized()
+            .constant::<3>()
+            .build()
+            .unwrap();
+        let refined = spline.insert_knot(0.3).unwrap();
+        for (a, b) in spline.take(21).zip(refined.take(21)) {
+            assert!((a - b).abs() < 1e-10);
+        }
+        let refined = spline.refine([0.2, 0.7, 0.7]).unwrap();
+        let homogeneous: Homogeneous<f64, f64> = refined.inner_ref().gen(0.0);
+        assert!((homogeneous.rational() - 1.0).abs() < 1e-10);
+        for (a, b) in spline.take(21).zip(refined.take(21)) {
+            assert!((a - b).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn errors() {
+        let spline = BSpline::builder()
+            .clamped()
+            .elements([0.0, 5.0, 3.0, 10.0])
+            .equidistant::<f64>()
+            .degree(2)
+            .normalized()
+            .constant::<3>()
+            .build()
+            .unwrap();
+        assert!(spline.insert_knot(-0.1).is_err());
+        assert!(spline.insert_knot(1.1).is_err());
+        assert!(spline.insert_knot(f64::NAN).is_err());
+        assert!(spline.insert_knot(0.0).is_err());
+        assert!(spline.insert_knot_times(0.25, 2).is_ok());
+        assert!(spline.insert_knot_times(0.25, 3).is_err());
+        assert!(spline.insert_knot_times(0.5, 2).is_err());
+        assert!(spline.refine([0.2, 0.2, 0.2]).is_err());
+        assert!(spline.refine([0.2, 1.5]).is_err());
+    }
+}
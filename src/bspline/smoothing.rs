@@ -0,0 +1,817 @@
+//! Penalized least-squares smoothing of samples with bsplines.
+
+use super::error::{BSplineError, InvalidDegree, TooFewElements, TooFewKnots, Underdetermined};
+use super::fitting::{basis, normal_equations, Fitting, System};
+use super::BSpline;
+use crate::{DiscreteGenerator, DynSpace, Sorted, SortedGenerator};
+use core::fmt::Debug;
+use core::ops::{Add, Mul, Sub};
+use num_traits::real::Real;
+use num_traits::FromPrimitive;
+use topology_traits::QuasiMetric;
+
+/// The range of weights of the curvature energy searched by the generalized cross-validation,
+/// given as decimal exponents relative to the ratio of the traces of the system matrices.
+const SEARCH_RANGE: [i32; 2] = [-8, 8];
+/// Number of samples per decade of weights searched by the generalized cross-validation.
+const SEARCH_STEPS: i32 = 2;
+/// Number of golden-section steps refining the best weight found by the search.
+const REFINEMENT_STEPS: usize = 24;
+
+/// The weight of the curvature energy of a smoothing spline.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Smoothness<R> {
+    /// The given weight is used. A weight of 0.0 results in the least-squares fitting.
+    ///
+    /// Weights which are huge compared to the samples make the system of equations numerically singular,
+    /// which is reported as `Underdetermined` error.
+    Fixed(R),
+    /// The weight minimizing the generalized cross-validation score is used.
+    ///
+    /// This score estimates how well the curve would predict samples which are not part of the fitting,
+    /// such that it neither follows the noise nor ignores the features of the samples.
+    CrossValidation,
+}
+
+/// Penalized least-squares smoothing of samples with a bspline.
+///
+/// This struct is created by [`Fitting::smoothing()`] and [`Fitting::smoothing_with()`].
+/// The smoothing spline is created by either giving its knots with [`knots()`] or the number of
+/// its elements with [`clamped()`].
+///
+/// [`Fitting::smoothing()`]: Fitting::smoothing()
+/// [`Fitting::smoothing_with()`]: Fitting::smoothing_with()
+/// [`knots()`]: Smoothing::knots()
+/// [`clamped()`]: Smoothing::clamped()
+#[derive(Debug, Clone)]
+pub struct Smoothing<R, T, F> {
+    fitting: Fitting<R, T>,
+    smoothness: Smoothness<R>,
+    metric: F,
+}
+
+/// A bspline smoothing samples together with measures of the quality of the fit.
+///
+/// This struct is created by [`Smoothing::knots()`] and [`Smoothing::clamped()`].
+///
+/// [`Smoothing::knots()`]: Smoothing::knots()
+/// [`Smoothing::clamped()`]: Smoothing::clamped()
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct SmoothingSpline<R, T> {
+    spline: BSpline<Sorted<Vec<R>>, Vec<T>, DynSpace<T>>,
+    lambda: R,
+    degrees_of_freedom: R,
+    residuals: Vec<T>,
+    score: R,
+}
+
+impl<R, T> SmoothingSpline<R, T> {
+    /// Returns the smoothing bspline.
+    #[allow(clippy::type_complexity)]
+    pub fn spline(&self) -> &BSpline<Sorted<Vec<R>>, Vec<T>, DynSpace<T>> {
+        &self.spline
+    }
+
+    /// Returns the smoothing bspline, dropping all measures of the fit.
+    #[allow(clippy::type_complexity)]
+    pub fn into_spline(self) -> BSpline<Sorted<Vec<R>>, Vec<T>, DynSpace<T>> {
+        self.spline
+    }
+
+    /// Returns the weight of the curvature energy which was used.
+    pub fn lambda(&self) -> R
+    where
+        R: Copy,
+    {
+        self.lambda
+    }
+
+    /// Returns the effective degrees of freedom, the trace of the matrix mapping the samples to the fitted values.
+    ///
+    /// This lies in between 2.0 for a straight line and the number of elements of the bspline for a
+    /// least-squares fitting. Fixed ends count as degrees of freedom, as they are given by the samples.
+    pub fn effective_degrees_of_freedom(&self) -> R
+    where
+        R: Copy,
+    {
+        self.degrees_of_freedom
+    }
+
+    /// Returns the differences of the samples and the curve at their parameters, in the order of the samples.
+    pub fn residuals(&self) -> &[T] {
+        &self.residuals
+    }
+
+    /// Returns the generalized cross-validation score of the fit.
+    ///
+    /// This is the weighted sum of the squared residuals divided by the number of samples
+    /// and by the squared fraction of degrees of freedom left for the residuals.
+    /// Smaller scores predict a better fit.
+    pub fn score(&self) -> R
+    where
+        R: Copy,
+    {
+        self.score
+    }
+}
+
+impl<R, T> Fitting<R, T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<R, Output = T> + Copy,
+    R: Real + FromPrimitive + Debug,
+{
+    /// Smooth the samples instead of only fitting them.
+    ///
+    /// The resulting bspline minimizes the weighted sum of the squared distances to the samples
+    /// plus lambda times its curvature energy, the integral of its squared second derivative.
+    /// The distance between elements is measured with their [`QuasiMetric`] implementation.
+    ///
+    /// If the elements do not implement [`QuasiMetric`], consider using [`smoothing_with()`] instead.
+    ///
+    /// [`QuasiMetric`]: crate::QuasiMetric
+    /// [`smoothing_with()`]: Fitting::smoothing_with()
+    pub fn smoothing(self, smoothness: Smoothness<R>) -> Smoothing<R, T, fn(T, T) -> R>
+    where
+        T: QuasiMetric<R>,
+    {
+        let metric: fn(T, T) -> R = QuasiMetric::distance;
+        self.smoothing_with(smoothness, metric)
+    }
+
+    /// Smooth the samples instead of only fitting them, measuring distances with the given metric.
+    ///
+    /// The resulting bspline minimizes the weighted sum of the squared distances to the samples
+    /// plus lambda times its curvature energy, the integral of its squared second derivative.
+    /// The metric is only used for the residuals of the generalized cross-validation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bspline::{BSpline, BSplineError, Smoothness}, Generator};
+    /// #
+    /// # fn main() -> Result<(), BSplineError> {
+    /// let parameters: Vec<f64> = (0..50).map(|i| i as f64 / 49.0).collect();
+    /// // a line with some deterministic noise
+    /// let samples: Vec<f64> = parameters
+    ///     .iter()
+    ///     .map(|x| 2.0 * x + 0.1 * (37.0 * x).sin())
+    ///     .collect();
+    /// let smoothing = BSpline::fit(parameters, samples)?
+    ///     .smoothing_with(Smoothness::CrossValidation, |a: f64, b: f64| (a - b).abs())
+    ///     .clamped(20, 3)?;
+    /// assert!(smoothing.effective_degrees_of_freedom() < 20.0);
+    /// assert_eq!(smoothing.residuals().len(), 50);
+    /// let spline = smoothing.into_spline();
+    /// assert!((spline.gen(0.5) - 1.0).abs() < 0.1);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn smoothing_with<F>(self, smoothness: Smoothness<R>, metric: F) -> Smoothing<R, T, F>
+    where
+        F: Fn(T, T) -> R,
+    {
+        Smoothing {
+            fitting: self,
+            smoothness,
+            metric,
+        }
+    }
+}
+
+impl<R, T, F> Smoothing<R, T, F>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<R, Output = T> + Copy,
+    R: Real + FromPrimitive + Debug,
+    F: Fn(T, T) -> R,
+{
+    /// Smooth the samples with a bspline of the given degree with the given knots.
+    ///
+    /// The bspline has `knots.len() - degree + 1` elements. In contrast to fitting, there may be more
+    /// elements than samples, as the curvature energy determines the elements not determined by the samples.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidDegree`] if the degree is less than 2, as the curvature energy would vanish.
+    /// Returns [`TooFewKnots`] if there are less than `2 * degree` knots.
+    /// Returns [`NotSorted`] if the knots are not sorted.
+    /// Returns [`TooFewElements`] if there are no samples.
+    /// Returns [`Underdetermined`] if the samples together with the curvature energy do not determine the bspline.
+    ///
+    /// [`InvalidDegree`]: BSplineError
+    /// [`TooFewKnots`]: BSplineError
+    /// [`NotSorted`]: BSplineError
+    /// [`TooFewElements`]: BSplineError
+    /// [`Underdetermined`]: BSplineError
+    pub fn knots<K>(self, knots: K, degree: usize) -> Result<SmoothingSpline<R, T>, BSplineError>
+    where
+        K: DiscreteGenerator<Output = R>,
+    {
+        if degree < 2 {
+            return Err(InvalidDegree::new(degree).into());
+        }
+        if knots.len() < 2 * degree {
+            return Err(TooFewKnots::new(knots.len()).into());
+        }
+        if self.fitting.elements.is_empty() {
+            return Err(TooFewElements::new(0).into());
+        }
+        let knots = Sorted::new(knots.iter().collect::<Vec<R>>())?;
+        let problem = Problem::new(&self.fitting, &knots, degree);
+        let (lambda, solution) = match self.smoothness {
+            Smoothness::Fixed(lambda) => (lambda, problem.solve(lambda, &self.metric)?),
+            Smoothness::CrossValidation => problem.cross_validation(&self.metric)?,
+        };
+        Ok(SmoothingSpline {
+            spline: BSpline {
+                elements: solution.elements,
+                knots,
+                space: DynSpace::new(degree + 1),
+                degree,
+            },
+            lambda,
+            degrees_of_freedom: solution.degrees_of_freedom,
+            residuals: solution.residuals,
+            score: solution.score,
+        })
+    }
+
+    /// Smooth the samples with a clamped bspline of the given degree with the given number of elements.
+    ///
+    /// The domain of the bspline reaches from the smallest to the biggest parameter
+    /// and the inner knots are equidistant.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidDegree`] if the degree is less than 2, as the curvature energy would vanish.
+    /// Returns [`TooFewElements`] if there are less than `degree + 1` elements or no samples.
+    /// Returns [`Underdetermined`] if the samples together with the curvature energy do not determine the bspline.
+    ///
+    /// [`InvalidDegree`]: BSplineError
+    /// [`TooFewElements`]: BSplineError
+    /// [`Underdetermined`]: BSplineError
+    pub fn clamped(self, len: usize, degree: usize) -> Result<SmoothingSpline<R, T>, BSplineError> {
+        if degree < 2 {
+            return Err(InvalidDegree::new(degree).into());
+        }
+        if len <= degree || self.fitting.parameters.is_empty() {
+            return Err(TooFewElements::new(len).into());
+        }
+        let parameters = &self.fitting.parameters;
+        let first = parameters.iter().fold(parameters[0], |min, p| min.min(p));
+        let last = parameters.iter().fold(parameters[0], |max, p| max.max(p));
+        let spans = R::from_usize(len - degree).unwrap();
+        let mut knots = vec![first; degree];
+        knots.extend((1..len - degree).map(|j| {
+            let factor = R::from_usize(j).unwrap() / spans;
+            first * (R::one() - factor) + last * factor
+        }));
+        knots.extend(core::iter::repeat(last).take(degree));
+        self.knots(knots, degree)
+    }
+}
+
+/// The solution of the smoothing problem for a specific weight.
+struct Solution<R, T> {
+    elements: Vec<T>,
+    residuals: Vec<T>,
+    degrees_of_freedom: R,
+    score: R,
+}
+
+/// The normal equations of a smoothing problem, independent of the weight of the curvature energy.
+struct Problem<'a, R, T> {
+    fitting: &'a Fitting<R, T>,
+    /// The knot span and values of the non-vanishing basis functions of every sample.
+    bases: Vec<(usize, Vec<R>)>,
+    gram: Vec<Vec<R>>,
+    penalty: Vec<Vec<R>>,
+    rhs: Vec<T>,
+    degree: usize,
+}
+
+impl<'a, R, T> Problem<'a, R, T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<R, Output = T> + Copy,
+    R: Real + FromPrimitive + Debug,
+{
+    fn new<K>(fitting: &'a Fitting<R, T>, knots: &K, degree: usize) -> Self
+    where
+        K: SortedGenerator<Output = R>,
+    {
+        let (gram, rhs) = normal_equations(
+            &fitting.parameters,
+            &fitting.elements,
+            &fitting.weights,
+            knots,
+            degree,
+        );
+        Problem {
+            fitting,
+            bases: fitting
+                .parameters
+                .iter()
+                .map(|parameter| basis(knots, degree, parameter))
+                .collect(),
+            gram,
+            penalty: curvature_energy(knots, degree),
+            rhs,
+            degree,
+        }
+    }
+
+    /// Solve the problem with the given weight of the curvature energy.
+    fn solve<F>(&self, lambda: R, metric: &F) -> Result<Solution<R, T>, Underdetermined>
+    where
+        F: Fn(T, T) -> R,
+    {
+        let matrix = self
+            .gram
+            .iter()
+            .zip(&self.penalty)
+            .map(|(gram, penalty)| {
+                gram.iter()
+                    .zip(penalty)
+                    .map(|(g, &p)| g + p * lambda)
+                    .collect()
+            })
+            .collect();
+        let fitting = self.fitting;
+        let system = System::new(matrix, self.degree, fitting.fixed_ends)?;
+        let samples = fitting.elements.len();
+        let elements = system.solve(
+            self.rhs.clone(),
+            fitting.elements[0],
+            fitting.elements[samples - 1],
+        );
+        let mut residuals = Vec::with_capacity(samples);
+        let mut squares = R::zero();
+        let mut degrees_of_freedom = R::zero();
+        let len = self.rhs.len();
+        for (i, (span, values)) in self.bases.iter().enumerate() {
+            let offset = span - self.degree;
+            let fitted = values
+                .iter()
+                .enumerate()
+                .skip(1)
+                .fold(elements[offset] * values[0], |sum, (j, value)| {
+                    sum + elements[offset + j] * value
+                });
+            let element = fitting.elements[i];
+            let distance = metric(element, fitted);
+            squares = squares + distance * distance * fitting.weights[i];
+            residuals.push(element - fitted);
+            // the influence of the sample on its own fitted value
+            let mut unit = vec![R::zero(); len];
+            for (j, value) in values.iter().enumerate() {
+                unit[offset + j] = value * fitting.weights[i];
+            }
+            let first = if i == 0 { R::one() } else { R::zero() };
+            let last = if i == samples - 1 {
+                R::one()
+            } else {
+                R::zero()
+            };
+            let influence = system.solve(unit, first, last);
+            degrees_of_freedom = values
+                .iter()
+                .enumerate()
+                .fold(degrees_of_freedom, |sum, (j, value)| {
+                    sum + influence[offset + j] * value
+                });
+        }
+        let count = R::from_usize(samples).unwrap();
+        let remaining = R::one() - degrees_of_freedom / count;
+        let score = if remaining <= R::epsilon() {
+            R::max_value()
+        } else {
+            squares / count / (remaining * remaining)
+        };
+        Ok(Solution {
+            elements,
+            residuals,
+            degrees_of_freedom,
+            score,
+        })
+    }
+
+    /// Solve the problem with the weight of the curvature energy minimizing the generalized cross-validation score.
+    ///
+    /// The weights are searched on a logarithmic scale, after which the best one is refined by golden-section search.
+    fn cross_validation<F>(&self, metric: &F) -> Result<(R, Solution<R, T>), Underdetermined>
+    where
+        F: Fn(T, T) -> R,
+    {
+        let trace =
+            |matrix: &[Vec<R>]| (0..matrix.len()).fold(R::zero(), |sum, i| sum + matrix[i][i]);
+        let penalty = trace(&self.penalty);
+        let scale = if penalty.is_zero() {
+            R::one()
+        } else {
+            trace(&self.gram) / penalty
+        };
+        let ten = R::from_usize(10).unwrap();
+        let steps = R::from_i32(SEARCH_STEPS).unwrap();
+        let lambda = |exponent: R| scale * ten.powf(exponent);
+        let score = |exponent: R| {
+            self.solve(lambda(exponent), metric)
+                .map_or(R::max_value(), |solution| solution.score)
+        };
+        let mut best: Option<(R, Solution<R, T>)> = None;
+        let mut error = None;
+        for step in SEARCH_RANGE[0] * SEARCH_STEPS..=SEARCH_RANGE[1] * SEARCH_STEPS {
+            let exponent = R::from_i32(step).unwrap() / steps;
+            match self.solve(lambda(exponent), metric) {
+                Ok(solution) => {
+                    if best
+                        .as_ref()
+                        .map_or(true, |(_, best)| solution.score < best.score)
+                    {
+                        best = Some((exponent, solution));
+                    }
+                }
+                Err(err) => error = Some(err),
+            }
+        }
+        let (exponent, solution) = match best {
+            Some(best) => best,
+            None => return Err(error.unwrap_or_else(|| Underdetermined::new(0))),
+        };
+        // golden-section search of the exponent around the best sample
+        let ratio = (R::from_usize(5).unwrap().sqrt() - R::one()) / (R::one() + R::one());
+        let mut lower = exponent - steps.recip();
+        let mut upper = exponent + steps.recip();
+        let mut left = upper - (upper - lower) * ratio;
+        let mut right = lower + (upper - lower) * ratio;
+        let mut left_score = score(left);
+        let mut right_score = score(right);
+        for _ in 0..REFINEMENT_STEPS {
+            if left_score <= right_score {
+                upper = right;
+                right = left;
+                right_score = left_score;
+                left = upper - (upper - lower) * ratio;
+                left_score = score(left);
+            } else {
+                lower = left;
+                left = right;
+                left_score = right_score;
+                right = lower + (upper - lower) * ratio;
+                right_score = score(right);
+            }
+        }
+        let refined = if left_score <= right_score {
+            left
+        } else {
+            right
+        };
+        if left_score.min(right_score) < solution.score {
+            if let Ok(refined_solution) = self.solve(lambda(refined), metric) {
+                return Ok((lambda(refined), refined_solution));
+            }
+        }
+        Ok((lambda(exponent), solution))
+    }
+}
+
+/// Returns the matrix of the curvature energy, the integral of the product of the second derivatives
+/// of every pair of basis functions over the domain.
+///
+/// The integral is calculated exactly with Gauss-Legendre quadrature on every knot span.
+fn curvature_energy<K, R>(knots: &K, degree: usize) -> Vec<Vec<R>>
+where
+    K: SortedGenerator<Output = R>,
+    R: Real + FromPrimitive,
+{
+    let len = knots.len() - degree + 1;
+    let mut matrix = vec![vec![R::zero(); len]; len];
+    let two = R::one() + R::one();
+    let nodes = gauss_legendre::<R>(degree - 1);
+    for span in degree..=knots.len() - degree {
+        let start = knots.gen(span - 1);
+        let end = knots.gen(span);
+        if end <= start {
+            continue;
+        }
+        let middle = (start + end) / two;
+        let half = (end - start) / two;
+        let offset = span - degree;
+        for (node, weight) in nodes.iter() {
+            let second = basis_derivatives(knots, degree, span, middle + node * half, 2)
+                .pop()
+                .unwrap();
+            for (i, a) in second.iter().enumerate() {
+                for (j, b) in second.iter().enumerate() {
+                    matrix[offset + i][offset + j] =
+                        matrix[offset + i][offset + j] + a * b * weight * half;
+                }
+            }
+        }
+    }
+    matrix
+}
+
+/// Returns the nodes and weights of the Gauss-Legendre quadrature with the given number of nodes on [-1.0,1.0].
+fn gauss_legendre<R>(len: usize) -> Vec<(R, R)>
+where
+    R: Real + FromPrimitive,
+{
+    let pi = R::from_f64(core::f64::consts::PI).unwrap();
+    let size = R::from_usize(len).unwrap();
+    (0..len)
+        .map(|i| {
+            let quarter = R::from_f64(0.25).unwrap();
+            let mut x = (pi * (R::from_usize(i).unwrap() + R::one() - quarter)
+                / (size + quarter + quarter))
+                .cos();
+            let mut derivative = R::one();
+            // newton iteration on the legendre polynomial of the given degree
+            for _ in 0..100 {
+                let mut current = x;
+                let mut previous = R::one();
+                for k in 2..=len {
+                    let k = R::from_usize(k).unwrap();
+                    let next = ((k + k - R::one()) * x * current - (k - R::one()) * previous) / k;
+                    previous = current;
+                    current = next;
+                }
+                derivative = size * (x * current - previous) / (x * x - R::one());
+                let step = current / derivative;
+                x = x - step;
+                if step.abs() <= R::epsilon() {
+                    break;
+                }
+            }
+            let weight = (R::one() + R::one()) / ((R::one() - x * x) * derivative * derivative);
+            (x, weight)
+        })
+        .collect()
+}
+
+/// Returns the values of the non-vanishing basis functions and their derivatives up to the given order
+/// at the scalar, which lies within the given knot span.
+///
+/// The derivative of order `k` of the basis function `j` is at `[k][j]`,
+/// where the basis function `j` belongs to the element with index `span - degree + j`.
+fn basis_derivatives<K, R>(
+    knots: &K,
+    degree: usize,
+    span: usize,
+    scalar: R,
+    order: usize,
+) -> Vec<Vec<R>>
+where
+    K: SortedGenerator<Output = R>,
+    R: Real + FromPrimitive,
+{
+    // values of the basis functions of all degrees (upper triangle) and the knot differences (lower triangle)
+    let mut table = vec![vec![R::zero(); degree + 1]; degree + 1];
+    let mut left = vec![R::zero(); degree + 1];
+    let mut right = vec![R::zero(); degree + 1];
+    table[0][0] = R::one();
+    for j in 1..=degree {
+        left[j] = scalar - knots.gen(span - j);
+        right[j] = knots.gen(span + j - 1) - scalar;
+        let mut saved = R::zero();
+        for r in 0..j {
+            table[j][r] = right[r + 1] + left[j - r];
+            let temp = table[r][j - 1] / table[j][r];
+            table[r][j] = saved + right[r + 1] * temp;
+            saved = left[j - r] * temp;
+        }
+        table[j][j] = saved;
+    }
+    let mut derivatives = vec![vec![R::zero(); degree + 1]; order + 1];
+    for j in 0..=degree {
+        derivatives[0][j] = table[j][degree];
+    }
+    for r in 0..=degree {
+        // coefficients of the two most recent rows of the derivatives
+        let mut rows = [vec![R::zero(); order + 1], vec![R::zero(); order + 1]];
+        let (mut current, mut next) = (0, 1);
+        rows[current][0] = R::one();
+        for k in 1..=order.min(degree) {
+            let mut derivative = R::zero();
+            let pk = degree - k;
+            if r >= k {
+                let rk = r - k;
+                rows[next][0] = rows[current][0] / table[pk + 1][rk];
+                derivative = rows[next][0] * table[rk][pk];
+            }
+            let first = if r + 1 >= k { 1 } else { k - r };
+            let last = if r <= pk + 1 { k - 1 } else { degree - r };
+            for j in first..=last {
+                let index = r + j - k;
+                rows[next][j] = (rows[current][j] - rows[current][j - 1]) / table[pk + 1][index];
+                derivative = derivative + rows[next][j] * table[index][pk];
+            }
+            if r <= pk {
+                rows[next][k] = -rows[current][k - 1] / table[pk + 1][r];
+                derivative = derivative + rows[next][k] * table[r][pk];
+            }
+            derivatives[k][r] = derivative;
+            core::mem::swap(&mut current, &mut next);
+        }
+    }
+    let mut factor = R::from_usize(degree).unwrap();
+    for (k, row) in derivatives
+        .iter_mut()
+        .enumerate()
+        .skip(1)
+        .take(order.min(degree))
+    {
+        for value in row.iter_mut() {
+            *value = *value * factor;
+        }
+        factor = factor * R::from_usize(degree - k).unwrap();
+    }
+    derivatives
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Curve, Generator};
+
+    fn distance(a: f64, b: f64) -> f64 {
+        (a - b).abs()
+    }
+
+    /// Deterministic noise in between -0.1 and 0.1.
+    fn noise(i: usize) -> f64 {
+        ((i * 7919) % 101) as f64 / 500.0 - 0.1
+    }
+
+    #[test]
+    fn derivatives() {
+        let knots = Sorted::new(vec![0.0, 0.0, 0.0, 0.5, 1.5, 2.0, 3.0, 3.0, 3.0]).unwrap();
+        let degree = 3;
+        let eps = 1e-5;
+        for scalar in [0.2, 0.7, 1.6, 2.9] {
+            let (span, values) = basis(&knots, degree, scalar);
+            let derivatives = basis_derivatives(&knots, degree, span, scalar, 2);
+            let (_, before) = basis(&knots, degree, scalar - eps);
+            let (_, after) = basis(&knots, degree, scalar + eps);
+            for j in 0..=degree {
+                assert!((derivatives[0][j] - values[j]).abs() < 1e-12);
+                let first = (after[j] - before[j]) / (2.0 * eps);
+                assert!((derivatives[1][j] - first).abs() < 1e-6);
+                let second = (after[j] - 2.0 * values[j] + before[j]) / (eps * eps);
+                assert!((derivatives[2][j] - second).abs() < 1e-3);
+            }
+        }
+    }
+
+    #[test]
+    fn quadrature() {
+        let nodes = gauss_legendre::<f64>(3);
+        let integral: f64 = nodes.iter().map(|(x, w)| x.powi(4) * w).sum();
+        assert!((integral - 0.4).abs() < 1e-14);
+        let nodes = gauss_legendre::<f64>(1);
+        assert!(nodes[0].0.abs() < 1e-15);
+        assert!((nodes[0].1 - 2.0).abs() < 1e-15);
+    }
+
+    #[test]
+    fn energy() {
+        // the parabola x^2 has the curvature energy 4 on [0,1]
+        let knots = Sorted::new(vec![0.0, 0.0, 0.0, 0.3, 0.6, 1.0, 1.0, 1.0]).unwrap();
+        let parameters: Vec<f64> = (0..=20).map(|i| i as f64 / 20.0).collect();
+        let samples: Vec<f64> = parameters.iter().map(|x| x * x).collect();
+        let spline = BSpline::fit(&parameters, &samples)
+            .unwrap()
+            .knots(knots.clone(), 3)
+            .unwrap();
+        let matrix = curvature_energy(&knots, 3);
+        let mut energy = 0.0;
+        for (i, row) in matrix.iter().enumerate() {
+            for (j, value) in row.iter().enumerate() {
+                energy += spline.elements[i] * value * spline.elements[j];
+            }
+        }
+        assert!((energy - 4.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn fixed() {
+        let parameters: Vec<f64> = (0..40).map(|i| i as f64 / 39.0).collect();
+        let samples: Vec<f64> = parameters
+            .iter()
+            .enumerate()
+            .map(|(i, x)| (4.0 * x).sin() + noise(i))
+            .collect();
+        let fitting = BSpline::fit(&parameters, &samples).unwrap();
+        // without curvature energy, this is the least-squares fitting
+        let mut knots = vec![0.0; 3];
+        knots.extend((1..7).map(|j| j as f64 / 7.0));
+        knots.extend([1.0; 3]);
+        let least_squares = fitting.clone().knots(knots, 3).unwrap();
+        let unpenalized = fitting
+            .clone()
+            .smoothing_with(Smoothness::Fixed(0.0), distance)
+            .clamped(10, 3)
+            .unwrap();
+        assert!((unpenalized.effective_degrees_of_freedom() - 10.0).abs() < 1e-8);
+        for x in [0.1, 0.45, 0.8] {
+            assert!((unpenalized.spline().gen(x) - least_squares.gen(x)).abs() < 1e-10);
+        }
+        for ((residual, sample), parameter) in unpenalized
+            .residuals()
+            .iter()
+            .zip(&samples)
+            .zip(&parameters)
+        {
+            assert!((sample - residual - least_squares.gen(*parameter)).abs() < 1e-10);
+        }
+        // with a huge curvature energy, this is the regression line
+        let stiff = fitting
+            .smoothing_with(Smoothness::Fixed(1e5), distance)
+            .clamped(10, 3)
+            .unwrap();
+        assert!((stiff.effective_degrees_of_freedom() - 2.0).abs() < 1e-3);
+        assert_eq!(stiff.lambda(), 1e5);
+        let count = parameters.len() as f64;
+        let mean_x = parameters.iter().sum::<f64>() / count;
+        let mean_y = samples.iter().sum::<f64>() / count;
+        let slope = parameters
+            .iter()
+            .zip(&samples)
+            .map(|(x, y)| (x - mean_x) * (y - mean_y))
+            .sum::<f64>()
+            / parameters.iter().map(|x| (x - mean_x).powi(2)).sum::<f64>();
+        let spline = stiff.into_spline();
+        for x in [0.0, 0.5, 1.0] {
+            assert!((spline.gen(x) - (mean_y + slope * (x - mean_x))).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn cross_validation() {
+        let curve = |x: f64| (6.0 * x).sin();
+        let parameters: Vec<f64> = (0..100).map(|i| i as f64 / 99.0).collect();
+        let samples: Vec<f64> = parameters
+            .iter()
+            .enumerate()
+            .map(|(i, x)| curve(x) + noise(i))
+            .collect();
+        let smoothing = BSpline::fit(&parameters, &samples)
+            .unwrap()
+            .smoothing_with(Smoothness::CrossValidation, distance)
+            .clamped(40, 3)
+            .unwrap();
+        let dof = smoothing.effective_degrees_of_freedom();
+        assert!(dof > 3.0 && dof < 20.0);
+        assert!(smoothing.lambda() > 0.0);
+        // the smoothed curve is closer to the original curve than the samples
+        let spline = smoothing.spline();
+        let error: f64 = parameters
+            .iter()
+            .map(|x| (spline.gen(x) - curve(x)).powi(2))
+            .sum();
+        let noise: f64 = (0..100).map(|i| noise(i).powi(2)).sum();
+        assert!(error < noise / 4.0);
+        // any fixed weight does not have a better score
+        for lambda in [1e-6, 1e-4, 1e-2, 1.0] {
+            let other = BSpline::fit(&parameters, &samples)
+                .unwrap()
+                .smoothing_with(Smoothness::Fixed(lambda), distance)
+                .clamped(40, 3)
+                .unwrap();
+            assert!(smoothing.score() <= other.score());
+        }
+    }
+
+    #[test]
+    fn fixed_ends() {
+        let parameters = [0.0, 0.2, 0.4, 0.6, 0.8, 1.0];
+        let samples = [1.0, 0.0, 0.5, 0.0, 0.5, -1.0];
+        let smoothing = BSpline::fit(parameters, samples)
+            .unwrap()
+            .fixed_ends()
+            .smoothing_with(Smoothness::Fixed(0.1), distance)
+            .clamped(8, 3)
+            .unwrap();
+        let spline = smoothing.spline();
+        assert_eq!(spline.domain(), [0.0, 1.0]);
+        assert!((spline.gen(0.0) - 1.0).abs() < 1e-12);
+        assert!((spline.gen(1.0) + 1.0).abs() < 1e-12);
+        assert!(smoothing.residuals()[0].abs() < 1e-12);
+    }
+
+    #[test]
+    fn errors() {
+        let fitting = BSpline::fit([0.0, 0.5, 1.0], [1.0, 2.0, 3.0]).unwrap();
+        let smoothing = fitting.smoothing_with(Smoothness::CrossValidation, distance);
+        assert!(smoothing.clone().clamped(3, 1).is_err());
+        assert!(smoothing.clone().clamped(2, 2).is_err());
+        assert!(smoothing.clone().knots([0.0, 0.5, 1.0], 2).is_err());
+        assert!(smoothing
+            .clone()
+            .knots([0.0, 1.0, 0.5, 1.0, 1.0], 2)
+            .is_err());
+        // more elements than samples are fine
+        assert!(smoothing.clamped(6, 2).is_ok());
+    }
+}
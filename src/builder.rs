@@ -1,13 +1,34 @@
 //! Module with structures, utilities and errors used in many builders
 
-#[cfg(any(feature = "linear", feature = "bezier", feature = "bspline"))]
+#[cfg(any(
+    feature = "linear",
+    feature = "bezier",
+    feature = "bspline",
+    feature = "hermite",
+    feature = "step",
+    feature = "polynomial"
+))]
 use core::fmt;
-#[cfg(any(feature = "linear", feature = "bezier", feature = "bspline"))]
+#[cfg(any(
+    feature = "linear",
+    feature = "bezier",
+    feature = "bspline",
+    feature = "hermite",
+    feature = "step",
+    feature = "polynomial"
+))]
 use core::marker::PhantomData;
 
 #[cfg(all(
     feature = "std",
-    any(feature = "linear", feature = "bezier", feature = "bspline")
+    any(
+        feature = "linear",
+        feature = "bezier",
+        feature = "bspline",
+        feature = "hermite",
+        feature = "step",
+        feature = "polynomial"
+    )
 ))]
 use std::error::Error;
 
@@ -24,7 +45,14 @@ pub struct WithoutWeight;
 pub struct WithWeight;
 
 /// Struct indicator to mark information not yet given.
-#[cfg(any(feature = "linear", feature = "bezier", feature = "bspline"))]
+#[cfg(any(
+    feature = "linear",
+    feature = "bezier",
+    feature = "bspline",
+    feature = "hermite",
+    feature = "step",
+    feature = "polynomial"
+))]
 #[derive(Debug, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Unknown;
@@ -59,12 +87,24 @@ impl<R> InputDomain<R> {
 }
 
 /// Struct indicator to mark which type to use
-#[cfg(any(feature = "linear", feature = "bspline"))]
+#[cfg(any(
+    feature = "linear",
+    feature = "bspline",
+    feature = "hermite",
+    feature = "step",
+    feature = "polynomial"
+))]
 #[derive(Debug, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Type<R = f64>(PhantomData<*const R>);
 
-#[cfg(any(feature = "linear", feature = "bspline"))]
+#[cfg(any(
+    feature = "linear",
+    feature = "bspline",
+    feature = "hermite",
+    feature = "step",
+    feature = "polynomial"
+))]
 impl<R> Type<R> {
     pub const fn new() -> Self {
         Type(PhantomData)
@@ -72,12 +112,12 @@ impl<R> Type<R> {
 }
 
 /// Error returned if if there are no elements.
-#[cfg(feature = "bezier")]
+#[cfg(any(feature = "bezier", feature = "polynomial"))]
 #[derive(Debug, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Empty {}
 
-#[cfg(feature = "bezier")]
+#[cfg(any(feature = "bezier", feature = "polynomial"))]
 impl Empty {
     /// Create a new error.
     pub const fn new() -> Self {
@@ -85,18 +125,30 @@ impl Empty {
     }
 }
 
-#[cfg(feature = "bezier")]
+#[cfg(any(feature = "bezier", feature = "polynomial"))]
+impl Default for Empty {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(any(feature = "bezier", feature = "polynomial"))]
 impl fmt::Display for Empty {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "No elements given, an empty generator is not allowed.")
     }
 }
 
-#[cfg(all(feature = "std", feature = "bezier"))]
+#[cfg(all(feature = "std", any(feature = "bezier", feature = "polynomial")))]
 impl Error for Empty {}
 
 /// Error returned if the elements are to few for the specific interpolation.
-#[cfg(any(feature = "linear", feature = "bspline"))]
+#[cfg(any(
+    feature = "linear",
+    feature = "bspline",
+    feature = "hermite",
+    feature = "step"
+))]
 #[derive(Debug, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct TooFewElements {
@@ -104,17 +156,35 @@ pub struct TooFewElements {
     found: usize,
 }
 
-#[cfg(any(feature = "linear", feature = "bspline"))]
+#[cfg(any(
+    feature = "linear",
+    feature = "bspline",
+    feature = "hermite",
+    feature = "step"
+))]
 impl fmt::Display for TooFewElements {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "To few elements given for the interpolation. {} elements were given, but at least 2 are necessary.", self.found)
     }
 }
 
-#[cfg(all(feature = "std", any(feature = "linear", feature = "bspline")))]
+#[cfg(all(
+    feature = "std",
+    any(
+        feature = "linear",
+        feature = "bspline",
+        feature = "hermite",
+        feature = "step"
+    )
+))]
 impl Error for TooFewElements {}
 
-#[cfg(any(feature = "linear", feature = "bspline"))]
+#[cfg(any(
+    feature = "linear",
+    feature = "bspline",
+    feature = "hermite",
+    feature = "step"
+))]
 impl TooFewElements {
     /// Create a new error and document the number of elements found.
     pub fn new(found: usize) -> Self {
@@ -10,6 +10,9 @@ use num_traits::FromPrimitive;
 mod plateau;
 pub use plateau::Plateau;
 
+mod steps;
+pub use steps::{Jump, Steps};
+
 /// This is just a wrapper for easing functions.
 ///
 /// We expect the domain to be normalized.
@@ -102,6 +105,15 @@ where
     mul
 }
 
+/// Inverse of [`smoothstart()`], such that `smoothstart_inverse(smoothstart(x)) == x` for x in [0.0,1.0].
+pub fn smoothstart_inverse<R, const N: usize>(y: R) -> R
+where
+    R: Real + FromPrimitive,
+{
+    let n = R::from_usize(N).expect("Could not convert N to a real number");
+    y.powf(n.recip())
+}
+
 /// Smoothend, also known as ease-out, smooths out the end of the graph.
 pub fn smoothend<R, const N: usize>(x: R) -> R
 where
@@ -110,6 +122,14 @@ where
     flip(smoothstart::<R, N>(flip(x)))
 }
 
+/// Inverse of [`smoothend()`], such that `smoothend_inverse(smoothend(x)) == x` for x in [0.0,1.0].
+pub fn smoothend_inverse<R, const N: usize>(y: R) -> R
+where
+    R: Real + FromPrimitive,
+{
+    flip(smoothstart_inverse::<R, N>(flip(y)))
+}
+
 /// Smoothstep function, see <https://en.wikipedia.org/wiki/Smoothstep>
 pub fn smoothstep<R>(x: R) -> R
 where
@@ -120,6 +140,16 @@ where
     x * x * (three - two * x)
 }
 
+/// Inverse of [`smoothstep()`], such that `smoothstep_inverse(smoothstep(x)) == x` for x in [0.0,1.0].
+pub fn smoothstep_inverse<R>(y: R) -> R
+where
+    R: Real + FromPrimitive,
+{
+    let two = R::from_usize(2).expect("Could not convert 2 to a real number");
+    let three = R::from_usize(3).expect("Could not convert 3 to a real number");
+    two.recip() - ((R::one() - two * y).asin() / three).sin()
+}
+
 /// A smoother variant of the smoothstep function, see <https://en.wikipedia.org/wiki/Smoothstep>
 pub fn smootherstep<R>(x: R) -> R
 where
@@ -130,3 +160,18 @@ where
     let fifteen = R::from_usize(15).expect("Could not convert 15 to a real number");
     x * x * x * (x * (x * six - fifteen) + ten)
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn inverses() {
+        for i in 0..=20 {
+            let x = i as f64 / 20.0;
+            assert!((smoothstart_inverse::<f64, 3>(smoothstart::<f64, 3>(x)) - x).abs() < 1e-10);
+            assert!((smoothend_inverse::<f64, 2>(smoothend::<f64, 2>(x)) - x).abs() < 1e-10);
+            assert!((smoothstep_inverse(smoothstep(x)) - x).abs() < 1e-10);
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::easing::smoothstep;
+use crate::easing::{smoothstep, smoothstep_inverse};
 use crate::{Curve, Generator};
 use num_traits::real::Real;
 use num_traits::FromPrimitive;
@@ -25,6 +25,15 @@ where
             max: R::one() - halfed,
         }
     }
+
+    /// Returns the input for which the curve generates the given output.
+    ///
+    /// For the outputs 0.0 and 1.0 the borders of the corresponding plateaus are returned.
+    /// Outputs outside of [0.0,1.0] are clamped.
+    pub fn inverse(&self, output: R) -> R {
+        let output = output.max(R::zero()).min(R::one());
+        self.min + smoothstep_inverse(output) * (self.max - self.min)
+    }
 }
 
 /// Overclamp can be imagined as a to clamp working to hard. That is, not only will be values be
@@ -65,3 +74,20 @@ where
         [R::zero(), R::one()]
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::Plateau;
+    use crate::Generator;
+
+    #[test]
+    fn inverse() {
+        let plateau = Plateau::new(0.4);
+        for i in 0..=20 {
+            let x = 0.2 + 0.6 * i as f64 / 20.0;
+            assert!((plateau.inverse(plateau.gen(x)) - x).abs() < 1e-10);
+        }
+        assert!((plateau.inverse(0.0) - 0.2).abs() < 1e-10);
+        assert!((plateau.inverse(2.0) - 0.8).abs() < 1e-10);
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{Curve, Generator};
+use num_traits::real::Real;
+use num_traits::FromPrimitive;
+
+/// Position of the jumps of a [`Steps`] easing function.
+///
+/// These are the same as the jump terms of the `steps()` easing function in CSS.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Jump {
+    /// The first jump happens at the start, such that 0.0 is never returned inside of the domain.
+    Start,
+    /// The last jump happens at the end, such that 1.0 is only returned at the end.
+    End,
+    /// There is no jump at the start or at the end, both 0.0 and 1.0 are held for a step.
+    None,
+    /// There are jumps at the start and at the end.
+    Both,
+}
+
+/// Steps is an easing curve which divides the domain into equally long steps,
+/// returning a constant value for each of them.
+///
+/// This is the same as the `steps()` easing function in CSS.
+///
+/// # Examples
+///
+/// ```rust
+/// # use enterpolation::{easing::{Steps, Jump}, Generator};
+/// let steps = Steps::new(4, Jump::End);
+/// assert_eq!(steps.gen(0.3), 0.25);
+/// assert_eq!(steps.gen(1.0), 1.0);
+/// let steps = Steps::new(4, Jump::Start);
+/// assert_eq!(steps.gen(0.0), 0.25);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Debug, Copy, Clone)]
+pub struct Steps {
+    steps: usize,
+    jump: Jump,
+}
+
+impl Steps {
+    /// Create a new Steps easing curve with the given number of steps and position of the jumps.
+    ///
+    /// As in CSS, there is at least one step and at least two steps if `jump` is [`Jump::None`].
+    /// Smaller numbers of steps are clamped to these minima.
+    pub fn new(steps: usize, jump: Jump) -> Self {
+        let min = if jump == Jump::None { 2 } else { 1 };
+        Steps {
+            steps: steps.max(min),
+            jump,
+        }
+    }
+
+    /// The number of jumps in between 0.0 and 1.0.
+    fn jumps(&self) -> usize {
+        match self.jump {
+            Jump::Start | Jump::End => self.steps,
+            Jump::None => self.steps - 1,
+            Jump::Both => self.steps + 1,
+        }
+    }
+}
+
+impl<R> Generator<R> for Steps
+where
+    R: Real + FromPrimitive,
+{
+    type Output = R;
+    fn gen(&self, input: R) -> R {
+        let steps = R::from_usize(self.steps).expect("Could not convert steps to a real number");
+        let jumps = R::from_usize(self.jumps()).expect("Could not convert jumps to a real number");
+        let mut step = (input * steps).floor();
+        if matches!(self.jump, Jump::Start | Jump::Both) {
+            step = step + R::one();
+        }
+        if input >= R::zero() && step < R::zero() {
+            step = R::zero();
+        }
+        if input <= R::one() && step > jumps {
+            step = jumps;
+        }
+        step / jumps
+    }
+}
+
+impl<R> Curve<R> for Steps
+where
+    R: Real + FromPrimitive,
+{
+    fn domain(&self) -> [R; 2] {
+        [R::zero(), R::one()]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Jump, Steps};
+    use crate::Generator;
+
+    #[test]
+    fn jumps() {
+        let inputs = [0.0, 0.1, 0.3, 0.5, 0.7, 0.99, 1.0];
+        let expected = [
+            (Jump::End, [0.0, 0.0, 0.25, 0.5, 0.5, 0.75, 1.0]),
+            (Jump::Start, [0.25, 0.25, 0.5, 0.75, 0.75, 1.0, 1.0]),
+            (
+                Jump::None,
+                [0.0, 0.0, 1.0 / 3.0, 2.0 / 3.0, 2.0 / 3.0, 1.0, 1.0],
+            ),
+            (Jump::Both, [0.2, 0.2, 0.4, 0.6, 0.6, 0.8, 1.0]),
+        ];
+        for (jump, outputs) in expected {
+            let steps = Steps::new(4, jump);
+            for (input, output) in inputs.iter().zip(outputs) {
+                assert_f64_near!(steps.gen(*input), output);
+            }
+        }
+    }
+
+    #[test]
+    fn clamped() {
+        let inputs = [0.0, 0.3, 0.5, 0.7, 1.0];
+        for (jump, steps) in [(Jump::End, 0), (Jump::Start, 0), (Jump::None, 1)] {
+            let clamped = Steps::new(steps, jump);
+            let min = Steps::new(if jump == Jump::None { 2 } else { 1 }, jump);
+            for input in inputs {
+                assert_f64_near!(clamped.gen(input), min.gen(input));
+            }
+        }
+        assert_f64_near!(Steps::new(0, Jump::Both).gen(0.3), 0.5);
+    }
+}
@@ -0,0 +1,423 @@
+//! Module for curvature, torsion and moving frames of curves in three dimensions.
+//!
+//! Frames are orthonormal bases travelling along a curve, which are needed to sweep
+//! profiles along a path or to orient objects, like a camera, on a rail.
+//! All functions work with curves implementing [`Differentiable`], such as bezier curves and bsplines,
+//! whose elements are three-dimensional vectors described by the [`Vector3`] trait.
+//!
+//! [`frenet()`] calculates the Frenet–Serret frame, which is given by the derivatives of the curve.
+//! It is not defined at points without curvature and may flip at inflections, which is why
+//! [`rotation_minimizing_frames()`] is usually the better choice to sweep along a curve.
+//!
+#![cfg_attr(feature = "bezier", doc = "```rust")]
+#![cfg_attr(not(feature = "bezier"), doc = "```ignore")]
+//! # use core::ops::{Add, Mul, Sub};
+//! # use enterpolation::{bezier::{Bezier, BezierError}, frames::{curvature, frenet, Vector3}};
+//! # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+//! #
+//! # #[derive(Debug, Copy, Clone, Default, PartialEq)]
+//! # struct Vector(f64, f64, f64);
+//! # impl Add for Vector {
+//! #     type Output = Self;
+//! #     fn add(self, o: Self) -> Self { Vector(self.0 + o.0, self.1 + o.1, self.2 + o.2) }
+//! # }
+//! # impl Sub for Vector {
+//! #     type Output = Self;
+//! #     fn sub(self, o: Self) -> Self { Vector(self.0 - o.0, self.1 - o.1, self.2 - o.2) }
+//! # }
+//! # impl Mul<f64> for Vector {
+//! #     type Output = Self;
+//! #     fn mul(self, s: f64) -> Self { Vector(self.0 * s, self.1 * s, self.2 * s) }
+//! # }
+//! impl Vector3<f64> for Vector {
+//!     fn dot(self, other: Self) -> f64 {
+//!         self.0 * other.0 + self.1 * other.1 + self.2 * other.2
+//!     }
+//!     fn cross(self, other: Self) -> Self {
+//!         Vector(
+//!             self.1 * other.2 - self.2 * other.1,
+//!             self.2 * other.0 - self.0 * other.2,
+//!             self.0 * other.1 - self.1 * other.0,
+//!         )
+//!     }
+//! }
+//!
+//! # fn main() -> Result<(), BezierError> {
+//! // the parabola (2t, 4t(1-t), 0)
+//! let parabola = Bezier::builder()
+//!     .elements([Vector(0.0, 0.0, 0.0), Vector(1.0, 2.0, 0.0), Vector(2.0, 0.0, 0.0)])
+//!     .normalized::<f64>()
+//!     .constant()
+//!     .build()?;
+//! assert_f64_near!(curvature(&parabola, 0.5), 2.0);
+//! let frame = frenet(&parabola, 0.5);
+//! assert_eq!(frame.tangent, Vector(1.0, 0.0, 0.0));
+//! assert_eq!(frame.normal, Vector(0.0, -1.0, 0.0));
+//! assert_eq!(frame.binormal, Vector(0.0, 0.0, -1.0));
+//! #
+//! #     Ok(())
+//! # }
+//! ```
+
+use crate::Curve;
+#[cfg(feature = "std")]
+use crate::Stepper;
+use core::ops::{Add, Mul, Sub};
+use num_traits::real::Real;
+#[cfg(feature = "std")]
+use num_traits::FromPrimitive;
+
+/// Trait for vectors in three dimensions.
+///
+/// Implement this trait for the vector type of your math library to use it with the functions of this module.
+pub trait Vector3<R>:
+    Add<Output = Self> + Sub<Output = Self> + Mul<R, Output = Self> + Copy
+{
+    /// Returns the dot product of two vectors.
+    fn dot(self, other: Self) -> R;
+    /// Returns the cross product of two vectors.
+    fn cross(self, other: Self) -> Self;
+}
+
+/// Trait for curves which are able to calculate their derivatives.
+pub trait Differentiable<R>: Curve<R>
+where
+    R: Real,
+{
+    /// Generate the value and its derivatives, the order hereby is from value, then first derivative, then second and so on.
+    fn gen_with_derivatives<const N: usize>(&self, scalar: R) -> [Self::Output; N];
+}
+
+/// Orthonormal basis at a point of a curve.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Frame<T> {
+    /// The point of the curve.
+    pub position: T,
+    /// The normalized direction of the curve.
+    pub tangent: T,
+    /// The normal, which is orthogonal to the tangent.
+    pub normal: T,
+    /// The cross product of tangent and normal.
+    pub binormal: T,
+}
+
+/// Returns the length of the vector.
+fn length<T, R>(vector: T) -> R
+where
+    T: Vector3<R>,
+    R: Real,
+{
+    vector.dot(vector).sqrt()
+}
+
+/// Returns the vector scaled to a length of one.
+fn normalize<T, R>(vector: T) -> T
+where
+    T: Vector3<R>,
+    R: Real,
+{
+    vector * length(vector).recip()
+}
+
+/// Calculates the curvature of the curve at the given scalar.
+///
+/// The curvature is the inverse of the radius of the circle which touches the curve the closest.
+/// Straight parts of the curve have a curvature of zero.
+pub fn curvature<C, R>(curve: &C, scalar: R) -> R
+where
+    C: Differentiable<R> + ?Sized,
+    C::Output: Vector3<R>,
+    R: Real,
+{
+    let [_, first, second] = curve.gen_with_derivatives(scalar);
+    let speed = length(first);
+    length(first.cross(second)) / (speed * speed * speed)
+}
+
+/// Calculates the torsion of the curve at the given scalar.
+///
+/// The torsion describes how fast the curve twists out of the plane in which it is curving.
+/// Planar curves have a torsion of zero. At points without curvature the torsion is not defined and NaN is returned.
+pub fn torsion<C, R>(curve: &C, scalar: R) -> R
+where
+    C: Differentiable<R> + ?Sized,
+    C::Output: Vector3<R>,
+    R: Real,
+{
+    let [_, first, second, third] = curve.gen_with_derivatives(scalar);
+    let cross = first.cross(second);
+    cross.dot(third) / cross.dot(cross)
+}
+
+/// Calculates the Frenet–Serret frame of the curve at the given scalar.
+///
+/// The normal of this frame points to the center of curvature. At points without curvature,
+/// the normal and the binormal are not defined and consist of NaN values.
+pub fn frenet<C, R>(curve: &C, scalar: R) -> Frame<C::Output>
+where
+    C: Differentiable<R> + ?Sized,
+    C::Output: Vector3<R>,
+    R: Real,
+{
+    let [position, first, second] = curve.gen_with_derivatives(scalar);
+    let tangent = normalize(first);
+    let binormal = normalize(first.cross(second));
+    Frame {
+        position,
+        tangent,
+        normal: binormal.cross(tangent),
+        binormal,
+    }
+}
+
+/// Calculates rotation minimizing frames at the given number of equidistant samples of the curve.
+///
+/// In contrast to Frenet–Serret frames, rotation minimizing frames do not twist around the tangent
+/// more than necessary and are defined for all curves whose derivative does not vanish.
+/// The frames are calculated with the double reflection method of Wang, Jüttler, Zheng and Liu,
+/// which approximates the rotation minimizing frame between two samples by two reflections.
+/// More samples therefore result in a better approximation.
+///
+/// The first frame uses the given normal, after removing its part parallel to the tangent.
+/// To start with the Frenet–Serret frame, use the normal given by [`frenet()`] at the start of the domain.
+///
+/// # Panics
+///
+/// Panics if the given samples are 0.
+///
+/// # Examples
+///
+#[cfg_attr(feature = "bezier", doc = "```rust")]
+#[cfg_attr(not(feature = "bezier"), doc = "```ignore")]
+/// # use core::ops::{Add, Mul, Sub};
+/// # use enterpolation::{bezier::{Bezier, BezierError}, frames::{rotation_minimizing_frames, Vector3}};
+/// #
+/// # #[derive(Debug, Copy, Clone, Default, PartialEq)]
+/// # struct Vector(f64, f64, f64);
+/// # impl Add for Vector {
+/// #     type Output = Self;
+/// #     fn add(self, o: Self) -> Self { Vector(self.0 + o.0, self.1 + o.1, self.2 + o.2) }
+/// # }
+/// # impl Sub for Vector {
+/// #     type Output = Self;
+/// #     fn sub(self, o: Self) -> Self { Vector(self.0 - o.0, self.1 - o.1, self.2 - o.2) }
+/// # }
+/// # impl Mul<f64> for Vector {
+/// #     type Output = Self;
+/// #     fn mul(self, s: f64) -> Self { Vector(self.0 * s, self.1 * s, self.2 * s) }
+/// # }
+/// # impl Vector3<f64> for Vector {
+/// #     fn dot(self, o: Self) -> f64 { self.0 * o.0 + self.1 * o.1 + self.2 * o.2 }
+/// #     fn cross(self, o: Self) -> Self {
+/// #         Vector(self.1 * o.2 - self.2 * o.1, self.2 * o.0 - self.0 * o.2, self.0 * o.1 - self.1 * o.0)
+/// #     }
+/// # }
+/// #
+/// # fn main() -> Result<(), BezierError> {
+/// let path = Bezier::builder()
+///     .elements([Vector(0.0, 0.0, 0.0), Vector(1.0, 2.0, 0.0), Vector(2.0, 0.0, 1.0)])
+///     .normalized::<f64>()
+///     .constant()
+///     .build()?;
+/// let frames = rotation_minimizing_frames(&path, 50, Vector(0.0, 0.0, 1.0));
+/// assert_eq!(frames.len(), 50);
+/// for frame in frames {
+///     assert!(frame.tangent.dot(frame.normal).abs() < 1e-10);
+///     assert!((frame.normal.dot(frame.normal) - 1.0).abs() < 1e-10);
+/// }
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+#[cfg(feature = "std")]
+pub fn rotation_minimizing_frames<C, R>(
+    curve: &C,
+    samples: usize,
+    normal: C::Output,
+) -> Vec<Frame<C::Output>>
+where
+    C: Differentiable<R> + ?Sized,
+    C::Output: Vector3<R>,
+    R: Real + FromPrimitive,
+{
+    let [start, end] = curve.domain();
+    let two = R::one() + R::one();
+    let mut frames: Vec<Frame<C::Output>> = Vec::with_capacity(samples);
+    for scalar in Stepper::new(samples, start, end) {
+        let [position, first] = curve.gen_with_derivatives(scalar);
+        let tangent = normalize(first);
+        let normal = match frames.last() {
+            None => normalize(normal - tangent * tangent.dot(normal)),
+            Some(previous) => {
+                // reflect the previous frame at the bisecting plane of both positions
+                let reflection = position - previous.position;
+                let squared = reflection.dot(reflection);
+                let (reflected_normal, reflected_tangent) = if squared.is_zero() {
+                    (previous.normal, previous.tangent)
+                } else {
+                    let factor = two / squared;
+                    (
+                        previous.normal - reflection * (factor * reflection.dot(previous.normal)),
+                        previous.tangent - reflection * (factor * reflection.dot(previous.tangent)),
+                    )
+                };
+                // reflect again such that the reflected tangent becomes the tangent
+                let reflection = tangent - reflected_tangent;
+                let squared = reflection.dot(reflection);
+                if squared.is_zero() {
+                    reflected_normal
+                } else {
+                    reflected_normal
+                        - reflection * (two / squared * reflection.dot(reflected_normal))
+                }
+            }
+        };
+        frames.push(Frame {
+            position,
+            tangent,
+            normal,
+            binormal: tangent.cross(normal),
+        });
+    }
+    frames
+}
+
+#[cfg(all(test, feature = "bezier"))]
+mod test {
+    use super::*;
+    use crate::bezier::Bezier;
+
+    #[derive(Debug, Copy, Clone, Default, PartialEq)]
+    struct Vector(f64, f64, f64);
+
+    impl Add for Vector {
+        type Output = Self;
+        fn add(self, other: Self) -> Self {
+            Vector(self.0 + other.0, self.1 + other.1, self.2 + other.2)
+        }
+    }
+
+    impl Sub for Vector {
+        type Output = Self;
+        fn sub(self, other: Self) -> Self {
+            Vector(self.0 - other.0, self.1 - other.1, self.2 - other.2)
+        }
+    }
+
+    impl Mul<f64> for Vector {
+        type Output = Self;
+        fn mul(self, scalar: f64) -> Self {
+            Vector(self.0 * scalar, self.1 * scalar, self.2 * scalar)
+        }
+    }
+
+    impl Vector3<f64> for Vector {
+        fn dot(self, other: Self) -> f64 {
+            self.0 * other.0 + self.1 * other.1 + self.2 * other.2
+        }
+        fn cross(self, other: Self) -> Self {
+            Vector(
+                self.1 * other.2 - self.2 * other.1,
+                self.2 * other.0 - self.0 * other.2,
+                self.0 * other.1 - self.1 * other.0,
+            )
+        }
+    }
+
+    /// Asserts that the frame is an orthonormal basis with the right orientation.
+    fn assert_orthonormal(frame: &Frame<Vector>) {
+        for vector in [frame.tangent, frame.normal, frame.binormal] {
+            assert!((vector.dot(vector) - 1.0).abs() < 1e-12);
+        }
+        assert!(frame.tangent.dot(frame.normal).abs() < 1e-12);
+        assert!(frame.tangent.dot(frame.binormal).abs() < 1e-12);
+        assert!(frame.normal.dot(frame.binormal).abs() < 1e-12);
+        let orientation = frame.tangent.cross(frame.normal) - frame.binormal;
+        assert!(orientation.dot(orientation) < 1e-20);
+    }
+
+    /// The twisted cubic (t, t^2, t^3).
+    fn twisted_cubic() -> Bezier<f64, [Vector; 4], crate::ConstSpace<Vector, 4>> {
+        Bezier::builder()
+            .elements([
+                Vector(0.0, 0.0, 0.0),
+                Vector(1.0 / 3.0, 0.0, 0.0),
+                Vector(2.0 / 3.0, 1.0 / 3.0, 0.0),
+                Vector(1.0, 1.0, 1.0),
+            ])
+            .normalized::<f64>()
+            .constant()
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn curvature_and_torsion() {
+        let curve = twisted_cubic();
+        for t in [0.0, 0.3, 0.8] {
+            let first = Vector(1.0, 2.0 * t, 3.0 * t * t);
+            let second = Vector(0.0, 2.0, 6.0 * t);
+            let cross = first.cross(second);
+            let expected = cross.dot(cross).sqrt() / first.dot(first).powf(1.5);
+            assert_f64_near!(curvature(&curve, t), expected, 8);
+            assert_f64_near!(torsion(&curve, t), 6.0 * cross.2 / cross.dot(cross), 8);
+        }
+        assert_f64_near!(curvature(&curve, 0.0), 2.0);
+        assert_f64_near!(torsion(&curve, 0.0), 3.0);
+    }
+
+    #[test]
+    fn frenet_frames() {
+        let curve = twisted_cubic();
+        let frame = frenet(&curve, 0.0);
+        assert_eq!(frame.position, Vector(0.0, 0.0, 0.0));
+        assert_eq!(frame.tangent, Vector(1.0, 0.0, 0.0));
+        assert_eq!(frame.normal, Vector(0.0, 1.0, 0.0));
+        assert_eq!(frame.binormal, Vector(0.0, 0.0, 1.0));
+        for t in [0.2, 0.5, 0.9] {
+            assert_orthonormal(&frenet(&curve, t));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn rotation_minimizing() {
+        // planar curves keep the normal of their plane
+        let planar = Bezier::builder()
+            .elements([
+                Vector(0.0, 0.0, 0.0),
+                Vector(1.0, 2.0, 0.0),
+                Vector(2.0, -1.0, 0.0),
+                Vector(3.0, 1.0, 0.0),
+            ])
+            .normalized::<f64>()
+            .constant()
+            .build()
+            .unwrap();
+        let frames = rotation_minimizing_frames(&planar, 20, Vector(0.0, 0.0, 1.0));
+        for frame in frames.iter() {
+            assert_orthonormal(frame);
+            assert!((frame.normal.2 - 1.0).abs() < 1e-12);
+        }
+        // or keep their angle to it
+        let frames = rotation_minimizing_frames(&planar, 20, Vector(0.0, 1.0, 1.0));
+        for frame in frames.iter() {
+            assert_orthonormal(frame);
+            assert!((frame.normal.2 - frames[0].normal.2).abs() < 1e-12);
+        }
+        // the frames do not rotate around the tangent
+        let curve = twisted_cubic();
+        let frames = rotation_minimizing_frames(&curve, 200, frenet(&curve, 0.0).normal);
+        assert_eq!(frames[0].normal, Vector(0.0, 1.0, 0.0));
+        for pair in frames.windows(2) {
+            assert_orthonormal(&pair[1]);
+            let twist = pair[1].normal.dot(pair[0].binormal) - pair[1].binormal.dot(pair[0].normal);
+            assert!(twist.abs() < 1e-5);
+        }
+        // in contrast to the frenet frames, which twist with the torsion
+        let frenet_frames: Vec<_> = (0..200).map(|i| frenet(&curve, i as f64 / 199.0)).collect();
+        let twist = frenet_frames[1].normal.dot(frenet_frames[0].binormal);
+        assert!(twist.abs() > 1e-3);
+    }
+}
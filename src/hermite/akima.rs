@@ -0,0 +1,235 @@
+//! Akima and modified Akima tangent calculations.
+//!
+//! The tangent of an element is a weighted mean of the slopes of its neighbouring segments,
+//! where the weights are given by the change of the slopes further away. This makes the
+//! interpolation local and prevents it from ringing around outliers.
+
+use crate::{DiscreteGenerator, SortedGenerator};
+use core::ops::{Add, Mul, Sub};
+use num_traits::real::Real;
+
+/// Tangent calculation of Akima and modified Akima (makima) splines.
+///
+/// The weights of the slopes are calculated from the distance between slopes,
+/// which is measured with the given function. Slopes are the difference of two elements
+/// divided by the distance of their knots, such they live in the same space as the elements.
+///
+/// The slopes of the first and last two segments are extrapolated linearly.
+/// The tangents are calculated once when building the interpolation.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Akima<F> {
+    distance: F,
+    modified: bool,
+}
+
+impl<F> Akima<F> {
+    /// Create a tangent calculation of an Akima spline with the given distance function.
+    pub const fn new(distance: F) -> Self {
+        Akima {
+            distance,
+            modified: false,
+        }
+    }
+
+    /// Create a tangent calculation of a modified Akima spline with the given distance function.
+    ///
+    /// The modification adds the mean of two slopes to their difference when calculating the weights,
+    /// which avoids overshoots if flat segments meet.
+    pub const fn modified(distance: F) -> Self {
+        Akima {
+            distance,
+            modified: true,
+        }
+    }
+
+    /// Returns true if the tangent calculation is modified.
+    pub fn is_modified(&self) -> bool {
+        self.modified
+    }
+}
+
+impl<F> Akima<F> {
+    /// Calculate the tangents at all elements.
+    pub(crate) fn tangents<K, E, R>(&self, knots: &K, elements: &E) -> Vec<E::Output>
+    where
+        K: SortedGenerator<Output = R>,
+        E: DiscreteGenerator,
+        E::Output:
+            Add<Output = E::Output> + Sub<Output = E::Output> + Mul<R, Output = E::Output> + Copy,
+        F: Fn(E::Output, E::Output) -> R,
+        R: Real,
+    {
+        let slopes: Vec<E::Output> = (0..elements.len() - 1)
+            .map(|index| {
+                let difference = elements.gen(index + 1) - elements.gen(index);
+                let interval = knots.gen(index + 1) - knots.gen(index);
+                if interval.is_zero() {
+                    return difference * R::zero();
+                }
+                difference * interval.recip()
+            })
+            .collect();
+        (0..elements.len())
+            .map(|index| self.tangent(&slopes, index))
+            .collect()
+    }
+
+    /// The weight of the slope next to `second` given by the change from `first` to `second`.
+    fn weight<T, R>(&self, first: T, second: T) -> R
+    where
+        T: Mul<R, Output = T> + Copy,
+        F: Fn(T, T) -> R,
+        R: Real,
+    {
+        let distance = &self.distance;
+        let change = distance(first, second);
+        if self.modified {
+            let two = R::one() + R::one();
+            change + distance(first, second * -R::one()) / two
+        } else {
+            change
+        }
+    }
+
+    /// Calculate the tangent at the given index from the slopes of all segments.
+    fn tangent<T, R>(&self, slopes: &[T], index: usize) -> T
+    where
+        T: Add<Output = T> + Sub<Output = T> + Mul<R, Output = T> + Copy,
+        F: Fn(T, T) -> R,
+        R: Real,
+    {
+        let index = index as isize;
+        let before_before = slope(slopes, index - 2);
+        let before = slope(slopes, index - 1);
+        let after = slope(slopes, index);
+        let after_after = slope(slopes, index + 1);
+        let before_weight = self.weight(after_after, after);
+        let after_weight = self.weight(before, before_before);
+        let sum = before_weight + after_weight;
+        if sum.is_zero() {
+            return (before + after) * (R::one() + R::one()).recip();
+        }
+        (before * before_weight + after * after_weight) * sum.recip()
+    }
+}
+
+/// The slope of the segment starting at the given index.
+///
+/// Slopes of segments outside of the curve are extrapolated linearly.
+fn slope<T, R>(slopes: &[T], index: isize) -> T
+where
+    T: Sub<Output = T> + Mul<R, Output = T> + Copy,
+    R: Real,
+{
+    let segments = slopes.len() as isize;
+    let two = R::one() + R::one();
+    if segments == 1 {
+        return slopes[0];
+    }
+    if index < 0 {
+        return slope(slopes, index + 1) * two - slope(slopes, index + 2);
+    }
+    if index >= segments {
+        return slope(slopes, index - 1) * two - slope(slopes, index - 2);
+    }
+    slopes[index as usize]
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::Hermite;
+    use crate::{Curve, Generator};
+
+    fn distance(a: f64, b: f64) -> f64 {
+        (a - b).abs()
+    }
+
+    #[test]
+    fn interpolates() {
+        let knots = [0.0, 1.0, 1.5, 3.0, 4.0, 6.0];
+        let elements = [1.0, 3.0, 2.0, 2.5, 0.0, 1.0];
+        let akima = Hermite::builder()
+            .elements(elements)
+            .knots(knots)
+            .akima(distance)
+            .build()
+            .unwrap();
+        let makima = Hermite::builder()
+            .elements(elements)
+            .knots(knots)
+            .makima(distance)
+            .build()
+            .unwrap();
+        assert!(akima.tangents != makima.tangents);
+        for (knot, element) in knots.iter().zip(elements.iter()) {
+            assert_f64_near!(akima.gen(*knot), *element);
+            assert_f64_near!(makima.gen(*knot), *element);
+        }
+        // the curve is continuously differentiable
+        let eps = 1e-7;
+        for knot in [1.0, 1.5, 3.0, 4.0] {
+            let left = (akima.gen(knot) - akima.gen(knot - eps)) / eps;
+            let right = (akima.gen(knot + eps) - akima.gen(knot)) / eps;
+            assert!((left - right).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn linear() {
+        let akima = Hermite::builder()
+            .elements([1.0, 3.0, 5.0, 7.0])
+            .equidistant::<f64>()
+            .normalized()
+            .akima(distance)
+            .build()
+            .unwrap();
+        for (i, value) in akima.take(13).enumerate() {
+            assert!((value - (1.0 + i as f64 / 2.0)).abs() < 1e-12);
+        }
+        let short = Hermite::builder()
+            .elements([1.0, 3.0])
+            .equidistant::<f64>()
+            .normalized()
+            .makima(distance)
+            .build()
+            .unwrap();
+        assert_f64_near!(short.gen(0.25), 1.5);
+    }
+
+    #[test]
+    fn outlier() {
+        let mut elements = [0.0; 12];
+        elements[3] = 10.0;
+        let akima = Hermite::builder()
+            .elements(elements)
+            .equidistant::<f64>()
+            .distance(0.0, 1.0)
+            .akima(distance)
+            .build()
+            .unwrap();
+        // the outlier only influences its close neighbourhood
+        for value in akima.slice(6.0..11.0).take(50) {
+            assert_eq!(value, 0.0);
+        }
+    }
+
+    #[test]
+    fn steps() {
+        let elements = [0.0, 0.0, 0.0, 1.0, 1.0, 1.0];
+        for modified in [false, true] {
+            let builder = Hermite::builder()
+                .elements(elements)
+                .equidistant::<f64>()
+                .normalized();
+            let curve = if modified {
+                builder.makima(distance).build().unwrap()
+            } else {
+                builder.akima(distance).build().unwrap()
+            };
+            for value in curve.take(51) {
+                assert!((-1e-12..=1.0 + 1e-12).contains(&value));
+            }
+        }
+    }
+}
@@ -0,0 +1,958 @@
+//! Builder module for hermite interpolations.
+
+use super::error::HermiteError;
+#[cfg(feature = "std")]
+use super::{Akima, Monotone};
+use super::{
+    Alpha, AlphaCatmullRom, CatmullRom, Hermite, KnotElementInequality, KnotSpacing,
+    TangentElementInequality, TooFewElements,
+};
+use crate::builder::{Type, Unknown};
+use crate::{DiscreteGenerator, Equidistant, Sorted, SortedGenerator};
+#[cfg(feature = "std")]
+use core::ops::{Add, Mul, Sub};
+use num_traits::real::Real;
+use num_traits::{FromPrimitive, One, Zero};
+
+/// Builder for hermite interpolation.
+///
+/// This struct helps create hermite interpolations. The difference between this struct and [`HermiteBuilder`]
+/// is that this struct may have other fallible methods and not only the [`build()`] method.
+///
+/// Before building, one has to give information for:
+/// - The elements the interpolation should use with [`elements()`].
+/// - The knots the interpolation uses. This can be seen as the spacing between those elements.
+///   Either by giving them directly with [`knots()`] or by using equidistant knots with [`equidistant()`].
+/// - The tangents at the elements. Either by giving them directly with [`tangents()`] or by calculating them
+///   with [`catmull_rom()`], [`cardinal()`], [`centripetal()`], [`chordal()`], [`alpha()`],
+///   [`monotone()`], [`akima()`] or [`makima()`].
+///
+/// ```rust
+/// # use enterpolation::{hermite::{HermiteDirector, HermiteError}, Generator, Curve};
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// #
+/// # fn main() -> Result<(), HermiteError> {
+/// let hermite = HermiteDirector::new()
+///                 .elements([1.0,5.0,1.0])?
+///                 .equidistant::<f64>()
+///                 .normalized()
+///                 .catmull_rom()
+///                 .build();
+/// let results = [1.0,3.5,5.0,3.5,1.0];
+/// for (value,result) in hermite.take(5).zip(results.iter().copied()){
+///     assert_f64_near!(value, result);
+/// }
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`HermiteBuilder`]: HermiteBuilder
+/// [`build()`]: HermiteDirector::build()
+/// [`elements()`]: HermiteDirector::elements()
+/// [`knots()`]: HermiteDirector::knots()
+/// [`equidistant()`]: HermiteDirector::equidistant()
+/// [`tangents()`]: HermiteDirector::tangents()
+/// [`catmull_rom()`]: HermiteDirector::catmull_rom()
+/// [`cardinal()`]: HermiteDirector::cardinal()
+/// [`centripetal()`]: HermiteDirector::centripetal()
+/// [`chordal()`]: HermiteDirector::chordal()
+/// [`alpha()`]: HermiteDirector::alpha()
+/// [`monotone()`]: HermiteDirector::monotone()
+/// [`akima()`]: HermiteDirector::akima()
+/// [`makima()`]: HermiteDirector::makima()
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct HermiteDirector<K, E, T> {
+    knots: K,
+    elements: E,
+    tangents: T,
+}
+
+/// Builder for hermite interpolation.
+///
+/// This struct helps create hermite interpolations. Its only fallible method is [`build()`].
+/// Usually one creates an instance by using the [`builder()`] method on the interpolation itself.
+///
+/// Before building, one has to give information for:
+/// - The elements the interpolation should use with [`elements()`].
+/// - The knots the interpolation uses. This can be seen as the spacing between those elements.
+///   Either by giving them directly with [`knots()`] or by using equidistant knots with [`equidistant()`].
+/// - The tangents at the elements. Either by giving them directly with [`tangents()`] or by calculating them
+///   with [`catmull_rom()`], [`cardinal()`], [`centripetal()`], [`chordal()`], [`alpha()`],
+///   [`monotone()`], [`akima()`] or [`makima()`].
+///
+/// ```rust
+/// # use enterpolation::{hermite::{Hermite, HermiteError}, Generator, Curve};
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// #
+/// # fn main() -> Result<(), HermiteError> {
+/// let hermite = Hermite::builder()
+///                 .elements([1.0,5.0,1.0])
+///                 .equidistant::<f64>()
+///                 .normalized()
+///                 .catmull_rom()
+///                 .build()?;
+/// let results = [1.0,3.5,5.0,3.5,1.0];
+/// for (value,result) in hermite.take(5).zip(results.iter().copied()){
+///     assert_f64_near!(value, result);
+/// }
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`build()`]: HermiteBuilder::build()
+/// [`builder()`]: super::Hermite::builder()
+/// [`elements()`]: HermiteBuilder::elements()
+/// [`knots()`]: HermiteBuilder::knots()
+/// [`equidistant()`]: HermiteBuilder::equidistant()
+/// [`tangents()`]: HermiteBuilder::tangents()
+/// [`catmull_rom()`]: HermiteBuilder::catmull_rom()
+/// [`cardinal()`]: HermiteBuilder::cardinal()
+/// [`centripetal()`]: HermiteBuilder::centripetal()
+/// [`chordal()`]: HermiteBuilder::chordal()
+/// [`alpha()`]: HermiteBuilder::alpha()
+/// [`monotone()`]: HermiteBuilder::monotone()
+/// [`akima()`]: HermiteBuilder::akima()
+/// [`makima()`]: HermiteBuilder::makima()
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct HermiteBuilder<K, E, T> {
+    inner: Result<HermiteDirector<K, E, T>, HermiteError>,
+}
+
+impl Default for HermiteDirector<Unknown, Unknown, Unknown> {
+    fn default() -> Self {
+        HermiteDirector::new()
+    }
+}
+
+impl Default for HermiteBuilder<Unknown, Unknown, Unknown> {
+    fn default() -> Self {
+        HermiteBuilder::new()
+    }
+}
+
+impl HermiteDirector<Unknown, Unknown, Unknown> {
+    /// Create a new hermite interpolation builder.
+    pub const fn new() -> Self {
+        HermiteDirector {
+            knots: Unknown,
+            elements: Unknown,
+            tangents: Unknown,
+        }
+    }
+}
+
+impl HermiteBuilder<Unknown, Unknown, Unknown> {
+    /// Create a new hermite interpolation builder.
+    pub const fn new() -> Self {
+        HermiteBuilder {
+            inner: Ok(HermiteDirector::new()),
+        }
+    }
+}
+
+impl HermiteDirector<Unknown, Unknown, Unknown> {
+    /// Set the elements of the hermite interpolation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TooFewElements`] if not at least 2 elements are given.
+    ///
+    /// [`TooFewElements`]: super::error::HermiteError
+    pub fn elements<E>(
+        self,
+        elements: E,
+    ) -> Result<HermiteDirector<Unknown, E, Unknown>, TooFewElements>
+    where
+        E: DiscreteGenerator,
+    {
+        if elements.len() < 2 {
+            return Err(TooFewElements::new(elements.len()));
+        }
+        Ok(HermiteDirector {
+            knots: self.knots,
+            elements,
+            tangents: self.tangents,
+        })
+    }
+}
+
+impl HermiteBuilder<Unknown, Unknown, Unknown> {
+    /// Set the elements of the hermite interpolation.
+    pub fn elements<E>(self, elements: E) -> HermiteBuilder<Unknown, E, Unknown>
+    where
+        E: DiscreteGenerator,
+    {
+        HermiteBuilder {
+            inner: self
+                .inner
+                .and_then(|director| director.elements(elements).map_err(|err| err.into())),
+        }
+    }
+}
+
+impl<E> HermiteDirector<Unknown, E, Unknown> {
+    /// Set the knots of the interpolation.
+    ///
+    /// The amount of knots must be equal to the amount of elements.
+    ///
+    /// # Performance
+    ///
+    /// If you have equidistant knots, near equidistant knots are you do not really care about
+    /// knots, consider using [`equidistant()`] instead.
+    ///
+    /// [`equidistant()`]: HermiteDirector::equidistant()
+    ///
+    /// # Errors
+    ///
+    /// Returns [`KnotElementInequality`] if the number of knots is not equal to the number of elements.
+    /// Returns [`NotSorted`] if the knots are not sorted such that they are increasing.
+    ///
+    /// [`KnotElementInequality`]: super::error::HermiteError
+    /// [`NotSorted`]:  super::error::HermiteError
+    pub fn knots<K>(self, knots: K) -> Result<HermiteDirector<Sorted<K>, E, Unknown>, HermiteError>
+    where
+        E: DiscreteGenerator,
+        K: DiscreteGenerator,
+        K::Output: PartialOrd,
+    {
+        if self.elements.len() != knots.len() {
+            return Err(KnotElementInequality::new(self.elements.len(), knots.len()).into());
+        }
+        Ok(HermiteDirector {
+            knots: Sorted::new(knots)?,
+            elements: self.elements,
+            tangents: self.tangents,
+        })
+    }
+
+    /// Build an interpolation with equidistant knots.
+    ///
+    /// This method takes `R` as a generic parameter. `R` has to be the type you want the knots to be.
+    /// Often this is just `f32` or `f64`.
+    ///
+    /// After this call, you also have to call either of
+    /// - [`domain()`],
+    /// - [`normalized()`] or
+    /// - [`distance()`],
+    ///
+    /// which all define the domain of the interpolation and the spacing of the knots.
+    ///
+    /// # Performance
+    ///
+    /// This may drastically increase performance, as one does not have to use binary search to find
+    /// the relevant knots in an interpolation.
+    ///
+    /// [`domain()`]: HermiteDirector::domain()
+    /// [`normalized()`]: HermiteDirector::normalized()
+    /// [`distance()`]: HermiteDirector::distance()
+    pub fn equidistant<R>(self) -> HermiteDirector<Type<R>, E, Unknown> {
+        HermiteDirector {
+            knots: Type::new(),
+            elements: self.elements,
+            tangents: self.tangents,
+        }
+    }
+}
+
+impl<E> HermiteBuilder<Unknown, E, Unknown> {
+    /// Set the knots of the interpolation.
+    ///
+    /// The amount of knots must be equal to the amount of elements.
+    ///
+    /// # Performance
+    ///
+    /// If you have equidistant knots, near equidistant knots are you do not really care about
+    /// knots, consider using [`equidistant()`] instead.
+    ///
+    /// [`equidistant()`]: HermiteBuilder::equidistant()
+    pub fn knots<K>(self, knots: K) -> HermiteBuilder<Sorted<K>, E, Unknown>
+    where
+        E: DiscreteGenerator,
+        K: DiscreteGenerator,
+        K::Output: PartialOrd,
+    {
+        HermiteBuilder {
+            inner: self.inner.and_then(|director| director.knots(knots)),
+        }
+    }
+
+    /// Build an interpolation with equidistant knots.
+    ///
+    /// This method takes `R` as a generic parameter. `R` has to be the type you want the knots to be.
+    /// Often this is just `f32` or `f64`.
+    ///
+    /// After this call, you also have to call either of
+    /// - [`domain()`],
+    /// - [`normalized()`] or
+    /// - [`distance()`],
+    ///
+    /// which all define the domain of the interpolation and the spacing of the knots.
+    ///
+    /// # Performance
+    ///
+    /// This may drastically increase performance, as one does not have to use binary search to find
+    /// the relevant knots in an interpolation.
+    ///
+    /// [`domain()`]: HermiteBuilder::domain()
+    /// [`normalized()`]: HermiteBuilder::normalized()
+    /// [`distance()`]: HermiteBuilder::distance()
+    pub fn equidistant<R>(self) -> HermiteBuilder<Type<R>, E, Unknown> {
+        HermiteBuilder {
+            inner: self.inner.map(|director| director.equidistant()),
+        }
+    }
+}
+
+impl<R, E> HermiteDirector<Type<R>, E, Unknown>
+where
+    E: DiscreteGenerator,
+    R: Real + FromPrimitive,
+{
+    /// Set the domain of the interpolation.
+    pub fn domain(self, start: R, end: R) -> HermiteDirector<Equidistant<R>, E, Unknown> {
+        HermiteDirector {
+            knots: Equidistant::new(self.elements.len(), start, end),
+            elements: self.elements,
+            tangents: self.tangents,
+        }
+    }
+
+    /// Set the domain of the interpolation to be [0.0,1.0].
+    pub fn normalized(self) -> HermiteDirector<Equidistant<R>, E, Unknown> {
+        HermiteDirector {
+            knots: Equidistant::normalized(self.elements.len()),
+            elements: self.elements,
+            tangents: self.tangents,
+        }
+    }
+    /// Set the domain of the interpolation by defining the distance between the knots
+    pub fn distance(self, start: R, step: R) -> HermiteDirector<Equidistant<R>, E, Unknown> {
+        HermiteDirector {
+            knots: Equidistant::step(self.elements.len(), start, step),
+            elements: self.elements,
+            tangents: self.tangents,
+        }
+    }
+}
+
+impl<R, E> HermiteBuilder<Type<R>, E, Unknown>
+where
+    E: DiscreteGenerator,
+    R: Real + FromPrimitive,
+{
+    /// Set the domain of the interpolation.
+    pub fn domain(self, start: R, end: R) -> HermiteBuilder<Equidistant<R>, E, Unknown> {
+        HermiteBuilder {
+            inner: self.inner.map(|director| director.domain(start, end)),
+        }
+    }
+
+    /// Set the domain of the interpolation to be [0.0,1.0].
+    pub fn normalized(self) -> HermiteBuilder<Equidistant<R>, E, Unknown> {
+        HermiteBuilder {
+            inner: self.inner.map(|director| director.normalized()),
+        }
+    }
+    /// Set the domain of the interpolation by defining the distance between the knots
+    pub fn distance(self, start: R, step: R) -> HermiteBuilder<Equidistant<R>, E, Unknown> {
+        HermiteBuilder {
+            inner: self.inner.map(|director| director.distance(start, step)),
+        }
+    }
+}
+
+impl<K, E> HermiteDirector<K, E, Unknown>
+where
+    K: SortedGenerator,
+    K::Output: Real,
+    E: DiscreteGenerator,
+{
+    /// Set the tangents of the elements.
+    ///
+    /// The tangents are the derivatives of the curve at the knots, such
+    /// the amount of tangents must be equal to the amount of elements.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TangentElementInequality`] if the number of tangents is not equal to the number of elements.
+    ///
+    /// [`TangentElementInequality`]: super::error::HermiteError
+    pub fn tangents<T>(
+        self,
+        tangents: T,
+    ) -> Result<HermiteDirector<K, E, T>, TangentElementInequality>
+    where
+        T: DiscreteGenerator,
+    {
+        if self.elements.len() != tangents.len() {
+            return Err(TangentElementInequality::new(
+                self.elements.len(),
+                tangents.len(),
+            ));
+        }
+        Ok(HermiteDirector {
+            knots: self.knots,
+            elements: self.elements,
+            tangents,
+        })
+    }
+
+    /// Calculate the tangents such that the interpolation is a Catmull-Rom spline.
+    ///
+    /// The tangent at an element is given by the difference of its neighbours,
+    /// divided by the difference of their knots.
+    /// The tension of the spline may be changed afterwards with [`tension()`].
+    ///
+    /// [`tension()`]: HermiteDirector::tension()
+    pub fn catmull_rom(self) -> HermiteDirector<K, E, CatmullRom<K::Output, KnotSpacing>> {
+        self.cardinal(K::Output::zero())
+    }
+
+    /// Calculate the tangents such that the interpolation is a cardinal spline with the given tension.
+    ///
+    /// A tension of zero results in a Catmull-Rom spline. The higher the tension, the shorter the tangents.
+    /// A tension of one results in tangents of length zero.
+    pub fn cardinal(
+        self,
+        tension: K::Output,
+    ) -> HermiteDirector<K, E, CatmullRom<K::Output, KnotSpacing>> {
+        HermiteDirector {
+            knots: self.knots,
+            elements: self.elements,
+            tangents: CatmullRom::new(tension, KnotSpacing),
+        }
+    }
+
+    /// Calculate the tangents of a Catmull-Rom spline, parameterized by the distance of elements to the power of `alpha`.
+    ///
+    /// Instead of the knots, the distance between two neighbouring elements is used to calculate the tangents.
+    /// The function `distance` is used to measure the distance between two elements.
+    /// The tension of the spline may be changed afterwards with [`tension()`].
+    ///
+    /// The knots are still used to define the input domain of each segment.
+    ///
+    /// [`tension()`]: HermiteDirector::tension()
+    pub fn alpha<F>(
+        self,
+        alpha: K::Output,
+        distance: F,
+    ) -> HermiteDirector<K, E, AlphaCatmullRom<K::Output, F>>
+    where
+        F: Fn(E::Output, E::Output) -> K::Output,
+    {
+        HermiteDirector {
+            knots: self.knots,
+            elements: self.elements,
+            tangents: CatmullRom::new(K::Output::zero(), Alpha::new(alpha, distance)),
+        }
+    }
+
+    /// Calculate the tangents of a centripetal Catmull-Rom spline.
+    ///
+    /// This is the same as calling [`alpha()`] with an alpha of 0.5.
+    /// Centripetal Catmull-Rom splines neither contain cusps nor self-intersections within a segment.
+    ///
+    /// [`alpha()`]: HermiteDirector::alpha()
+    pub fn centripetal<F>(self, distance: F) -> HermiteDirector<K, E, AlphaCatmullRom<K::Output, F>>
+    where
+        F: Fn(E::Output, E::Output) -> K::Output,
+    {
+        let half = K::Output::one() / (K::Output::one() + K::Output::one());
+        self.alpha(half, distance)
+    }
+
+    /// Calculate the tangents of a chordal Catmull-Rom spline.
+    ///
+    /// This is the same as calling [`alpha()`] with an alpha of 1.0.
+    ///
+    /// [`alpha()`]: HermiteDirector::alpha()
+    pub fn chordal<F>(self, distance: F) -> HermiteDirector<K, E, AlphaCatmullRom<K::Output, F>>
+    where
+        F: Fn(E::Output, E::Output) -> K::Output,
+    {
+        self.alpha(K::Output::one(), distance)
+    }
+
+    /// Calculate the tangents such that the interpolation is monotone wherever the elements are monotone.
+    ///
+    /// The tangents are calculated with the method of Fritsch and Butland (PCHIP), such that
+    /// the curve never overshoots the elements. This is only available for real-valued elements.
+    /// The tangents are calculated once when building the interpolation.
+    #[cfg(feature = "std")]
+    pub fn monotone(self) -> HermiteDirector<K, E, Monotone>
+    where
+        E: DiscreteGenerator<Output = K::Output>,
+    {
+        HermiteDirector {
+            knots: self.knots,
+            elements: self.elements,
+            tangents: Monotone,
+        }
+    }
+
+    /// Calculate the tangents such that the interpolation is an Akima spline.
+    ///
+    /// The tangent at an element is a weighted mean of the slopes of its neighbouring segments,
+    /// where the weights are the distances between the slopes further away, measured with the function `distance`.
+    /// This only takes the elements close by into account and prevents ringing around outliers.
+    /// The tangents are calculated once when building the interpolation.
+    #[cfg(feature = "std")]
+    pub fn akima<F>(self, distance: F) -> HermiteDirector<K, E, Akima<F>>
+    where
+        F: Fn(E::Output, E::Output) -> K::Output,
+    {
+        HermiteDirector {
+            knots: self.knots,
+            elements: self.elements,
+            tangents: Akima::new(distance),
+        }
+    }
+
+    /// Calculate the tangents such that the interpolation is a modified Akima spline (makima).
+    ///
+    /// In contrast to [`akima()`], the weights also take the mean of the slopes into account,
+    /// which avoids overshoots if flat segments meet.
+    ///
+    /// [`akima()`]: HermiteDirector::akima()
+    #[cfg(feature = "std")]
+    pub fn makima<F>(self, distance: F) -> HermiteDirector<K, E, Akima<F>>
+    where
+        F: Fn(E::Output, E::Output) -> K::Output,
+    {
+        HermiteDirector {
+            knots: self.knots,
+            elements: self.elements,
+            tangents: Akima::modified(distance),
+        }
+    }
+}
+
+impl<K, E> HermiteBuilder<K, E, Unknown>
+where
+    K: SortedGenerator,
+    K::Output: Real,
+    E: DiscreteGenerator,
+{
+    /// Set the tangents of the elements.
+    ///
+    /// The tangents are the derivatives of the curve at the knots, such
+    /// the amount of tangents must be equal to the amount of elements.
+    pub fn tangents<T>(self, tangents: T) -> HermiteBuilder<K, E, T>
+    where
+        T: DiscreteGenerator,
+    {
+        HermiteBuilder {
+            inner: self
+                .inner
+                .and_then(|director| director.tangents(tangents).map_err(|err| err.into())),
+        }
+    }
+
+    /// Calculate the tangents such that the interpolation is a Catmull-Rom spline.
+    ///
+    /// The tangent at an element is given by the difference of its neighbours,
+    /// divided by the difference of their knots.
+    /// The tension of the spline may be changed afterwards with [`tension()`].
+    ///
+    /// [`tension()`]: HermiteBuilder::tension()
+    pub fn catmull_rom(self) -> HermiteBuilder<K, E, CatmullRom<K::Output, KnotSpacing>> {
+        HermiteBuilder {
+            inner: self.inner.map(|director| director.catmull_rom()),
+        }
+    }
+
+    /// Calculate the tangents such that the interpolation is a cardinal spline with the given tension.
+    ///
+    /// A tension of zero results in a Catmull-Rom spline. The higher the tension, the shorter the tangents.
+    /// A tension of one results in tangents of length zero.
+    pub fn cardinal(
+        self,
+        tension: K::Output,
+    ) -> HermiteBuilder<K, E, CatmullRom<K::Output, KnotSpacing>> {
+        HermiteBuilder {
+            inner: self.inner.map(|director| director.cardinal(tension)),
+        }
+    }
+
+    /// Calculate the tangents of a Catmull-Rom spline, parameterized by the distance of elements to the power of `alpha`.
+    ///
+    /// Instead of the knots, the distance between two neighbouring elements is used to calculate the tangents.
+    /// The function `distance` is used to measure the distance between two elements.
+    /// The tension of the spline may be changed afterwards with [`tension()`].
+    ///
+    /// The knots are still used to define the input domain of each segment.
+    ///
+    /// [`tension()`]: HermiteBuilder::tension()
+    pub fn alpha<F>(
+        self,
+        alpha: K::Output,
+        distance: F,
+    ) -> HermiteBuilder<K, E, AlphaCatmullRom<K::Output, F>>
+    where
+        F: Fn(E::Output, E::Output) -> K::Output,
+    {
+        HermiteBuilder {
+            inner: self.inner.map(|director| director.alpha(alpha, distance)),
+        }
+    }
+
+    /// Calculate the tangents of a centripetal Catmull-Rom spline.
+    ///
+    /// This is the same as calling [`alpha()`] with an alpha of 0.5.
+    /// Centripetal Catmull-Rom splines neither contain cusps nor self-intersections within a segment.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{hermite::{Hermite, HermiteError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), HermiteError> {
+    /// let hermite = Hermite::builder()
+    ///                 .elements([0.0,1.0,1.1,5.0])
+    ///                 .equidistant::<f64>()
+    ///                 .normalized()
+    ///                 .centripetal(|a: f64, b: f64| (a-b).abs())
+    ///                 .build()?;
+    /// // the curve does not overshoot between 1.0 and 1.1
+    /// for value in hermite.slice(1.0/3.0..2.0/3.0).take(10) {
+    ///     assert!(value > 0.9999 && value < 1.1001);
+    /// }
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`alpha()`]: HermiteBuilder::alpha()
+    pub fn centripetal<F>(self, distance: F) -> HermiteBuilder<K, E, AlphaCatmullRom<K::Output, F>>
+    where
+        F: Fn(E::Output, E::Output) -> K::Output,
+    {
+        HermiteBuilder {
+            inner: self.inner.map(|director| director.centripetal(distance)),
+        }
+    }
+
+    /// Calculate the tangents of a chordal Catmull-Rom spline.
+    ///
+    /// This is the same as calling [`alpha()`] with an alpha of 1.0.
+    ///
+    /// [`alpha()`]: HermiteBuilder::alpha()
+    pub fn chordal<F>(self, distance: F) -> HermiteBuilder<K, E, AlphaCatmullRom<K::Output, F>>
+    where
+        F: Fn(E::Output, E::Output) -> K::Output,
+    {
+        HermiteBuilder {
+            inner: self.inner.map(|director| director.chordal(distance)),
+        }
+    }
+
+    /// Calculate the tangents such that the interpolation is monotone wherever the elements are monotone.
+    ///
+    /// The tangents are calculated with the method of Fritsch and Butland (PCHIP), such that
+    /// the curve never overshoots the elements. This is only available for real-valued elements.
+    /// The tangents are calculated once when building the interpolation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{hermite::{Hermite, HermiteError}, Generator, Curve};
+    /// #
+    /// # fn main() -> Result<(), HermiteError> {
+    /// let monotone = Hermite::builder()
+    ///                 .elements([0.0,0.1,0.1,5.0])
+    ///                 .knots([0.0,1.0,2.0,3.0])
+    ///                 .monotone()
+    ///                 .build()?;
+    /// // the curve does not overshoot and stays flat between 0.1 and 0.1
+    /// let mut last = 0.0;
+    /// for value in monotone.take(31) {
+    ///     assert!(value >= last && value <= 5.0);
+    ///     last = value;
+    /// }
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn monotone(self) -> HermiteBuilder<K, E, Monotone>
+    where
+        E: DiscreteGenerator<Output = K::Output>,
+    {
+        HermiteBuilder {
+            inner: self.inner.map(|director| director.monotone()),
+        }
+    }
+
+    /// Calculate the tangents such that the interpolation is an Akima spline.
+    ///
+    /// The tangent at an element is a weighted mean of the slopes of its neighbouring segments,
+    /// where the weights are the distances between the slopes further away, measured with the function `distance`.
+    /// This only takes the elements close by into account and prevents ringing around outliers.
+    /// The tangents are calculated once when building the interpolation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{hermite::{Hermite, HermiteError}, Generator, Curve};
+    /// #
+    /// # fn main() -> Result<(), HermiteError> {
+    /// let akima = Hermite::builder()
+    ///                 .elements([0.0,0.0,0.0,5.0,0.0,0.0,0.0,0.0])
+    ///                 .equidistant::<f64>()
+    ///                 .distance(0.0,1.0)
+    ///                 .akima(|a: f64, b: f64| (a-b).abs())
+    ///                 .build()?;
+    /// // the outlier does not influence elements further away
+    /// for value in akima.slice(5.0..7.0).take(10) {
+    ///     assert_eq!(value, 0.0);
+    /// }
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn akima<F>(self, distance: F) -> HermiteBuilder<K, E, Akima<F>>
+    where
+        F: Fn(E::Output, E::Output) -> K::Output,
+    {
+        HermiteBuilder {
+            inner: self.inner.map(|director| director.akima(distance)),
+        }
+    }
+
+    /// Calculate the tangents such that the interpolation is a modified Akima spline (makima).
+    ///
+    /// In contrast to [`akima()`], the weights also take the mean of the slopes into account,
+    /// which avoids overshoots if flat segments meet.
+    ///
+    /// [`akima()`]: HermiteBuilder::akima()
+    #[cfg(feature = "std")]
+    pub fn makima<F>(self, distance: F) -> HermiteBuilder<K, E, Akima<F>>
+    where
+        F: Fn(E::Output, E::Output) -> K::Output,
+    {
+        HermiteBuilder {
+            inner: self.inner.map(|director| director.makima(distance)),
+        }
+    }
+}
+
+impl<K, E, R, P> HermiteDirector<K, E, CatmullRom<R, P>> {
+    /// Set the tension of the calculated tangents.
+    ///
+    /// A tension of zero results in a Catmull-Rom spline. The higher the tension, the shorter the tangents.
+    /// A tension of one results in tangents of length zero.
+    pub fn tension(self, tension: R) -> Self {
+        HermiteDirector {
+            knots: self.knots,
+            elements: self.elements,
+            tangents: CatmullRom::new(tension, self.tangents.parameterization),
+        }
+    }
+}
+
+impl<K, E, R, P> HermiteBuilder<K, E, CatmullRom<R, P>> {
+    /// Set the tension of the calculated tangents.
+    ///
+    /// A tension of zero results in a Catmull-Rom spline. The higher the tension, the shorter the tangents.
+    /// A tension of one results in tangents of length zero.
+    pub fn tension(self, tension: R) -> Self {
+        HermiteBuilder {
+            inner: self.inner.map(|director| director.tension(tension)),
+        }
+    }
+}
+
+impl<K, E, T> HermiteDirector<K, E, T>
+where
+    K: SortedGenerator,
+    E: DiscreteGenerator,
+    T: DiscreteGenerator,
+{
+    /// Build a hermite interpolation.
+    pub fn build(self) -> Hermite<K, E, T> {
+        Hermite::new_unchecked(self.elements, self.knots, self.tangents)
+    }
+}
+
+impl<K, E, T> HermiteBuilder<K, E, T>
+where
+    K: SortedGenerator,
+    E: DiscreteGenerator,
+    T: DiscreteGenerator,
+{
+    /// Build a hermite interpolation.
+    pub fn build(self) -> Result<Hermite<K, E, T>, HermiteError> {
+        match self.inner {
+            Err(err) => Err(err),
+            Ok(director) => Ok(director.build()),
+        }
+    }
+}
+
+impl<K, E, R, P> HermiteDirector<K, E, CatmullRom<R, P>>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+{
+    /// Build a hermite interpolation.
+    pub fn build(self) -> Hermite<K, E, CatmullRom<R, P>> {
+        Hermite::new_unchecked(self.elements, self.knots, self.tangents)
+    }
+}
+
+impl<K, E, R, P> HermiteBuilder<K, E, CatmullRom<R, P>>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+{
+    /// Build a hermite interpolation.
+    pub fn build(self) -> Result<Hermite<K, E, CatmullRom<R, P>>, HermiteError> {
+        match self.inner {
+            Err(err) => Err(err),
+            Ok(director) => Ok(director.build()),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, E> HermiteDirector<K, E, Monotone>
+where
+    K: SortedGenerator,
+    K::Output: Real,
+    E: DiscreteGenerator<Output = K::Output>,
+{
+    /// Build a hermite interpolation.
+    pub fn build(self) -> Hermite<K, E, Vec<K::Output>> {
+        let tangents = self.tangents.tangents(&self.knots, &self.elements);
+        Hermite::new_unchecked(self.elements, self.knots, tangents)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, E> HermiteBuilder<K, E, Monotone>
+where
+    K: SortedGenerator,
+    K::Output: Real,
+    E: DiscreteGenerator<Output = K::Output>,
+{
+    /// Build a hermite interpolation.
+    pub fn build(self) -> Result<Hermite<K, E, Vec<K::Output>>, HermiteError> {
+        match self.inner {
+            Err(err) => Err(err),
+            Ok(director) => Ok(director.build()),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, E, F> HermiteDirector<K, E, Akima<F>>
+where
+    K: SortedGenerator,
+    K::Output: Real,
+    E: DiscreteGenerator,
+    E::Output: Add<Output = E::Output>
+        + Sub<Output = E::Output>
+        + Mul<K::Output, Output = E::Output>
+        + Copy,
+    F: Fn(E::Output, E::Output) -> K::Output,
+{
+    /// Build a hermite interpolation.
+    pub fn build(self) -> Hermite<K, E, Vec<E::Output>> {
+        let tangents = self.tangents.tangents(&self.knots, &self.elements);
+        Hermite::new_unchecked(self.elements, self.knots, tangents)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, E, F> HermiteBuilder<K, E, Akima<F>>
+where
+    K: SortedGenerator,
+    K::Output: Real,
+    E: DiscreteGenerator,
+    E::Output: Add<Output = E::Output>
+        + Sub<Output = E::Output>
+        + Mul<K::Output, Output = E::Output>
+        + Copy,
+    F: Fn(E::Output, E::Output) -> K::Output,
+{
+    /// Build a hermite interpolation.
+    pub fn build(self) -> Result<Hermite<K, E, Vec<E::Output>>, HermiteError> {
+        match self.inner {
+            Err(err) => Err(err),
+            Ok(director) => Ok(director.build()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{HermiteBuilder, HermiteDirector};
+
+    #[test]
+    fn builder_errors() {
+        assert!(HermiteBuilder::new()
+            .elements([1.0])
+            .knots([1.0])
+            .catmull_rom()
+            .build()
+            .is_err());
+        assert!(HermiteBuilder::new()
+            .elements([1.0, 2.0])
+            .knots([1.0, 2.0, 3.0])
+            .catmull_rom()
+            .build()
+            .is_err());
+        assert!(HermiteBuilder::new()
+            .elements([1.0, 2.0])
+            .knots([2.0, 1.0])
+            .catmull_rom()
+            .build()
+            .is_err());
+        assert!(HermiteBuilder::new()
+            .elements([1.0, 2.0])
+            .knots([1.0, 2.0])
+            .tangents([1.0, 2.0, 3.0])
+            .build()
+            .is_err());
+        assert!(HermiteBuilder::new()
+            .elements([1.0, 2.0])
+            .knots([1.0, 2.0])
+            .tangents([1.0, 2.0])
+            .build()
+            .is_ok());
+    }
+
+    #[test]
+    fn director_errors() {
+        assert!(HermiteDirector::new().elements([0.0]).is_err());
+        assert!(HermiteDirector::new()
+            .elements([0.0, 1.0])
+            .unwrap()
+            .knots([1.0])
+            .is_err());
+        assert!(HermiteDirector::new()
+            .elements([0.0, 1.0])
+            .unwrap()
+            .knots([1.0, 2.0])
+            .unwrap()
+            .tangents([1.0])
+            .is_err());
+        assert!(HermiteDirector::new()
+            .elements([0.0, 1.0])
+            .unwrap()
+            .equidistant::<f64>()
+            .normalized()
+            .cardinal(0.5)
+            .tension(0.2)
+            .build()
+            .tangents
+            .tension()
+            .eq(&0.2));
+    }
+}
@@ -0,0 +1,718 @@
+//! Cubic hermite interpolations like Catmull-Rom splines.
+//!
+//! The easiest way to create a hermite interpolation is by using the builder pattern of [`HermiteBuilder`].
+//!
+//! ```rust
+//! # use enterpolation::{hermite::{Hermite, HermiteError}, Generator, Curve};
+//! # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+//! #
+//! # fn main() -> Result<(), HermiteError> {
+//! let catmull_rom = Hermite::builder()
+//!                 .elements([0.0,1.0,4.0,9.0])
+//!                 .knots([0.0,1.0,2.0,3.0])
+//!                 .catmull_rom()
+//!                 .build()?;
+//! // the curve passes through every element
+//! assert_f64_near!(catmull_rom.gen(1.0), 1.0);
+//! assert_f64_near!(catmull_rom.gen(2.0), 4.0);
+//! // and reproduces the parabola in the inner segment
+//! assert_f64_near!(catmull_rom.gen(1.5), 2.25);
+//! #
+//! #     Ok(())
+//! # }
+//! ```
+//!
+//! Hermite interpolations are piecewise cubic curves which pass through every element.
+//! Between two elements, the curve is defined by the two elements and the tangents at these elements.
+//! As the tangent of an element is shared by both adjacent segments, the curve is continuously differentiable.
+//! This makes them a good choice for camera paths and animation keyframes.
+//!
+//! The tangents may either be given explicitly with [`tangents()`] or be calculated
+//! from the neighbouring elements. The latter creates a Catmull-Rom spline with [`catmull_rom()`]
+//! or a cardinal spline with [`cardinal()`], whose tension scales the calculated tangents.
+//! To avoid cusps and self-intersections, the calculation of the tangents may also use
+//! the distances between elements instead of the knots with [`centripetal()`], [`chordal()`] or [`alpha()`].
+//!
+//! For real-valued elements, [`monotone()`] calculates the tangents such that the curve never overshoots
+//! and is monotone wherever the elements are monotone. This is useful for calibration tables or
+//! cumulative distributions. As these tangents are calculated once and stored, this needs the `std` feature.
+//!
+//! To prevent ringing around outliers, [`akima()`] and [`makima()`] calculate the tangents with
+//! the Akima method, which only takes the elements close by into account. See the [`akima`] module,
+//! which needs the `std` feature.
+//!
+//! [`HermiteBuilder`]: HermiteBuilder
+//! [`tangents()`]: HermiteBuilder::tangents()
+//! [`catmull_rom()`]: HermiteBuilder::catmull_rom()
+//! [`cardinal()`]: HermiteBuilder::cardinal()
+//! [`centripetal()`]: HermiteBuilder::centripetal()
+//! [`chordal()`]: HermiteBuilder::chordal()
+//! [`alpha()`]: HermiteBuilder::alpha()
+//! [`monotone()`]: HermiteBuilder::monotone()
+//! [`akima()`]: HermiteBuilder::akima()
+//! [`makima()`]: HermiteBuilder::makima()
+
+use crate::builder::Unknown;
+use crate::{Curve, DiscreteGenerator, Generator, SortedGenerator};
+use core::ops::{Add, Mul, Sub};
+use num_traits::real::Real;
+use topology_traits::Merge;
+
+use core::fmt::Debug;
+
+mod builder;
+pub use builder::{HermiteBuilder, HermiteDirector};
+
+#[cfg(feature = "std")]
+pub mod akima;
+#[cfg(feature = "std")]
+pub use akima::Akima;
+
+pub mod error;
+pub use error::{
+    HermiteError, KnotElementInequality, NotSorted, TangentElementInequality, TooFewElements,
+};
+
+/// Cubic hermite interpolation.
+///
+/// See [hermite module] for more information.
+///
+/// [hermite module]: self
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Hermite<K, E, T> {
+    elements: E,
+    knots: K,
+    tangents: T,
+}
+
+impl Hermite<Unknown, Unknown, Unknown> {
+    /// Get the builder for a hermite interpolation.
+    ///
+    /// The builder takes:
+    /// - elements with [`elements()`]
+    /// - knots with either [`knots()`] or [`equidistant()`]
+    /// - tangents with either [`tangents()`] or a calculation method like [`catmull_rom()`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{hermite::{Hermite, HermiteError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), HermiteError> {
+    /// let hermite = Hermite::builder()
+    ///                 .elements([0.0,1.0])
+    ///                 .equidistant::<f64>()
+    ///                 .normalized()
+    ///                 .tangents([0.0,0.0])
+    ///                 .build()?;
+    /// // smoothstep
+    /// let results = [0.0,0.15625,0.5,0.84375,1.0];
+    /// for (value,result) in hermite.take(5).zip(results.iter().copied()){
+    ///     assert_f64_near!(value, result);
+    /// }
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`elements()`]: HermiteBuilder::elements()
+    /// [`knots()`]: HermiteBuilder::knots()
+    /// [`equidistant()`]: HermiteBuilder::equidistant()
+    /// [`tangents()`]: HermiteBuilder::tangents()
+    /// [`catmull_rom()`]: HermiteBuilder::catmull_rom()
+    pub fn builder() -> HermiteBuilder<Unknown, Unknown, Unknown> {
+        HermiteBuilder::new()
+    }
+}
+
+impl<K, E, T> Hermite<K, E, T>
+where
+    K: SortedGenerator,
+    E: DiscreteGenerator,
+{
+    /// Create a hermite interpolation with slice-like collections of elements and knots.
+    ///
+    /// Knots have to be sorted, there should be as many knots as elements
+    /// and there has to be at least 2 elements.
+    /// If tangents are given explicitly, use [`new_with_tangents()`] instead,
+    /// which also checks the number of tangents.
+    ///
+    /// [`new_with_tangents()`]: Hermite::new_with_tangents()
+    pub fn new(elements: E, knots: K, tangents: T) -> Result<Self, HermiteError> {
+        if elements.len() < 2 {
+            return Err(TooFewElements::new(elements.len()).into());
+        }
+        if knots.len() != elements.len() {
+            return Err(KnotElementInequality::new(elements.len(), knots.len()).into());
+        }
+        Ok(Hermite {
+            elements,
+            knots,
+            tangents,
+        })
+    }
+
+    /// Create a hermite interpolation with slice-like collections of elements, knots and tangents.
+    ///
+    /// Knots have to be sorted, there should be as many knots and tangents as elements
+    /// and there has to be at least 2 elements.
+    pub fn new_with_tangents(elements: E, knots: K, tangents: T) -> Result<Self, HermiteError>
+    where
+        T: DiscreteGenerator,
+    {
+        if tangents.len() != elements.len() {
+            return Err(TangentElementInequality::new(elements.len(), tangents.len()).into());
+        }
+        Hermite::new(elements, knots, tangents)
+    }
+
+    /// Create a hermite interpolation with slice-like collections of elements and knots.
+    ///
+    /// # Panics
+    ///
+    /// Knots should be in increasing order, there should be as many knots (and tangents) as elements
+    /// and there has to be at least *two* elements.
+    /// If any of these requirements are not uphold, the library may panic at any time.
+    pub fn new_unchecked(elements: E, knots: K, tangents: T) -> Self {
+        Hermite {
+            elements,
+            knots,
+            tangents,
+        }
+    }
+}
+
+impl<R, K, E, T> Generator<R> for Hermite<K, E, T>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    E::Output:
+        Merge<R> + Add<T::Output, Output = E::Output> + Sub<T::Output, Output = E::Output> + Copy,
+    T: DiscreteGenerator,
+    T::Output: Mul<R, Output = T::Output>,
+    R: Real + Debug,
+{
+    type Output = E::Output;
+    /// # Panics
+    ///
+    /// Panics if `scalar` is NaN or similar.
+    fn gen(&self, scalar: R) -> Self::Output {
+        let (min_index, max_index, factor) = self.knots.upper_border(scalar);
+        let start = self.elements.gen(min_index);
+        let end = self.elements.gen(max_index);
+        let third = (self.knots.gen(max_index) - self.knots.gen(min_index))
+            / R::from(3.0).expect("Could not convert 3.0 to a real number");
+        let start_control = start + self.tangents.gen(min_index) * third;
+        let end_control = end - self.tangents.gen(max_index) * third;
+        cubic_bezier(start, start_control, end_control, end, factor)
+    }
+}
+
+impl<R, K, E, T> Curve<R> for Hermite<K, E, T>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    E::Output:
+        Merge<R> + Add<T::Output, Output = E::Output> + Sub<T::Output, Output = E::Output> + Copy,
+    T: DiscreteGenerator,
+    T::Output: Mul<R, Output = T::Output>,
+    R: Real + Debug,
+{
+    fn domain(&self) -> [R; 2] {
+        [self.knots.first().unwrap(), self.knots.last().unwrap()]
+    }
+}
+
+impl<R, K, E, P> Generator<R> for Hermite<K, E, CatmullRom<R, P>>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    E::Output: Merge<R> + Copy,
+    P: Parameterization<E::Output, R>,
+    R: Real + Debug,
+{
+    type Output = E::Output;
+    /// # Panics
+    ///
+    /// Panics if `scalar` is NaN or similar.
+    fn gen(&self, scalar: R) -> Self::Output {
+        let (min_index, max_index, factor) = self.knots.upper_border(scalar);
+        let start = self.elements.gen(min_index);
+        let end = self.elements.gen(max_index);
+        let start_knot = self.knots.gen(min_index);
+        let end_knot = self.knots.gen(max_index);
+        let parameterization = &self.tangents.parameterization;
+        let interval = parameterization.interval(start, end, start_knot, end_knot);
+        let scale = self.tangents.scale();
+        let start_control = if min_index == 0 {
+            start.merge(end, scale)
+        } else {
+            let before = self.elements.gen(min_index - 1);
+            let other =
+                parameterization.interval(before, start, self.knots.gen(min_index - 1), start_knot);
+            control_point(start, end, before, interval, other, scale)
+        };
+        let end_control = if max_index + 1 == self.elements.len() {
+            end.merge(start, scale)
+        } else {
+            let after = self.elements.gen(max_index + 1);
+            let other =
+                parameterization.interval(end, after, end_knot, self.knots.gen(max_index + 1));
+            control_point(end, start, after, interval, other, scale)
+        };
+        cubic_bezier(start, start_control, end_control, end, factor)
+    }
+}
+
+impl<R, K, E, P> Curve<R> for Hermite<K, E, CatmullRom<R, P>>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    E::Output: Merge<R> + Copy,
+    P: Parameterization<E::Output, R>,
+    R: Real + Debug,
+{
+    fn domain(&self) -> [R; 2] {
+        [self.knots.first().unwrap(), self.knots.last().unwrap()]
+    }
+}
+
+#[cfg(feature = "std")]
+impl Monotone {
+    /// Calculate the tangents at all elements with the method of Fritsch and Butland.
+    ///
+    /// Inner tangents are a weighted harmonic mean of the slopes of the adjacent segments
+    /// and zero if these slopes have different signs.
+    /// The tangents at the borders are calculated with a three-point formula,
+    /// which gets limited to keep the curve monotone.
+    pub(crate) fn tangents<K, E, R>(&self, knots: &K, elements: &E) -> Vec<R>
+    where
+        K: SortedGenerator<Output = R>,
+        E: DiscreteGenerator<Output = R>,
+        R: Real,
+    {
+        let len = elements.len();
+        let intervals: Vec<R> = (0..len - 1)
+            .map(|i| knots.gen(i + 1) - knots.gen(i))
+            .collect();
+        let slopes: Vec<R> = (0..len - 1)
+            .map(|i| {
+                if intervals[i].is_zero() {
+                    return R::zero();
+                }
+                (elements.gen(i + 1) - elements.gen(i)) / intervals[i]
+            })
+            .collect();
+        (0..len)
+            .map(|index| monotone_tangent(&slopes, &intervals, index))
+            .collect()
+    }
+}
+
+/// Calculate the tangent at the given index from the slopes and lengths of all segments.
+#[cfg(feature = "std")]
+fn monotone_tangent<R>(slopes: &[R], intervals: &[R], index: usize) -> R
+where
+    R: Real,
+{
+    let len = slopes.len() + 1;
+    if len == 2 {
+        return slopes[0];
+    }
+    let three = R::from(3.0).expect("Could not convert 3.0 to a real number");
+    if index == 0 || index + 1 == len {
+        // (near, far) segments as seen from the border
+        let (near, far) = if index == 0 {
+            (0, 1)
+        } else {
+            (len - 2, len - 3)
+        };
+        let near_slope = slopes[near];
+        let far_slope = slopes[far];
+        let near_interval = intervals[near];
+        let far_interval = intervals[far];
+        let sum = near_interval + far_interval;
+        if sum.is_zero() {
+            return R::zero();
+        }
+        let tangent = ((near_interval + near_interval + far_interval) * near_slope
+            - near_interval * far_slope)
+            / sum;
+        if tangent * near_slope <= R::zero() {
+            return R::zero();
+        }
+        if near_slope * far_slope < R::zero() && tangent.abs() > (three * near_slope).abs() {
+            return three * near_slope;
+        }
+        return tangent;
+    }
+    let before = slopes[index - 1];
+    let after = slopes[index];
+    if before * after <= R::zero() {
+        return R::zero();
+    }
+    let before_interval = intervals[index - 1];
+    let after_interval = intervals[index];
+    let before_weight = after_interval + after_interval + before_interval;
+    let after_weight = after_interval + before_interval + before_interval;
+    (before_weight + after_weight) / (before_weight / before + after_weight / after)
+}
+
+/// Evaluate the cubic bezier curve given by its four control points with de Casteljau's algorithm.
+fn cubic_bezier<T, R>(first: T, second: T, third: T, fourth: T, factor: R) -> T
+where
+    T: Merge<R> + Copy,
+    R: Real,
+{
+    let first = first.merge(second, factor);
+    let second = second.merge(third, factor);
+    let third = third.merge(fourth, factor);
+    let first = first.merge(second, factor);
+    let second = second.merge(third, factor);
+    first.merge(second, factor)
+}
+
+/// Calculate the inner bezier control point next to `center` of the segment between `center` and `across`.
+///
+/// The tangent at `center` is calculated with the non-uniform Catmull-Rom formula
+/// from `center`, `across` and `other`, the element on the other side of `center`.
+/// `interval` is the length of the segment, `other_interval` the length between `other` and `center`.
+/// The control point is an affine combination of the three elements, such we only need to merge.
+fn control_point<T, R>(
+    center: T,
+    across: T,
+    other: T,
+    interval: R,
+    other_interval: R,
+    scale: R,
+) -> T
+where
+    T: Merge<R> + Copy,
+    R: Real,
+{
+    if other_interval.is_zero() {
+        return center.merge(across, scale);
+    }
+    let sum = interval + other_interval;
+    let across_weight = scale * other_interval / sum;
+    let other_weight = -scale * interval * interval / (other_interval * sum);
+    let center_weight = R::one() - across_weight - other_weight;
+    center
+        .merge(across, across_weight / (center_weight + across_weight))
+        .merge(other, other_weight)
+}
+
+/// Tangent calculation of Catmull-Rom and cardinal splines.
+///
+/// The tangent at each element is calculated from the neighbouring elements
+/// and scaled by `1 - tension`. A tension of zero results in a Catmull-Rom spline,
+/// a tension of one in zero tangents.
+///
+/// How far away the neighbouring elements are, is decided by the [`Parameterization`].
+/// Tangents of the first and last element are the (scaled) difference to their only neighbour.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct CatmullRom<R, P> {
+    tension: R,
+    parameterization: P,
+}
+
+impl<R, P> CatmullRom<R, P> {
+    /// Create a new tangent calculation with the given tension and parameterization.
+    pub const fn new(tension: R, parameterization: P) -> Self {
+        CatmullRom {
+            tension,
+            parameterization,
+        }
+    }
+
+    /// Return the tension used.
+    pub fn tension(&self) -> R
+    where
+        R: Copy,
+    {
+        self.tension
+    }
+}
+
+impl<R, P> CatmullRom<R, P>
+where
+    R: Real,
+{
+    /// The factor with which the difference of elements is multiplied to get an inner control point.
+    fn scale(&self) -> R {
+        (R::one() - self.tension) / R::from(3.0).expect("Could not convert 3.0 to a real number")
+    }
+}
+
+/// Tangent calculation of monotone cubic interpolations, also known as PCHIP.
+///
+/// The tangents are calculated from the slopes of the neighbouring segments with the method of
+/// Fritsch and Butland, such that the curve is monotone wherever the elements are monotone
+/// and never overshoots. This tangent calculation is only available for real-valued elements.
+#[derive(Debug, Copy, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Monotone;
+
+/// Tangent calculation of Catmull-Rom splines parameterized by the distance of elements.
+///
+/// **Because this is an alias, not all its methods are listed here. See the [`CatmullRom`](crate::hermite::CatmullRom) type too.**
+pub type AlphaCatmullRom<R, F> = CatmullRom<R, Alpha<R, F>>;
+
+/// Trait to define the distance between two neighbouring elements, used to calculate tangents.
+pub trait Parameterization<T, R> {
+    /// Return the length of the interval between `start` and `end`,
+    /// which are located at `start_knot` and `end_knot` respectively.
+    fn interval(&self, start: T, end: T, start_knot: R, end_knot: R) -> R;
+}
+
+/// Parameterization using the distances between knots.
+///
+/// With equidistant knots, this results in an uniform Catmull-Rom spline.
+#[derive(Debug, Copy, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct KnotSpacing;
+
+impl<T, R> Parameterization<T, R> for KnotSpacing
+where
+    R: Real,
+{
+    fn interval(&self, _start: T, _end: T, start_knot: R, end_knot: R) -> R {
+        end_knot - start_knot
+    }
+}
+
+/// Parameterization using the distances between elements to the power of `alpha`.
+///
+/// An `alpha` of 0.5 results in a centripetal, an `alpha` of 1.0 in a chordal Catmull-Rom spline.
+/// The distance between elements is calculated with the given function.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Alpha<R, F> {
+    alpha: R,
+    distance: F,
+}
+
+impl<R, F> Alpha<R, F> {
+    /// Create a new parameterization with the given `alpha` and distance function.
+    pub const fn new(alpha: R, distance: F) -> Self {
+        Alpha { alpha, distance }
+    }
+}
+
+impl<T, R, F> Parameterization<T, R> for Alpha<R, F>
+where
+    F: Fn(T, T) -> R,
+    R: Real,
+{
+    fn interval(&self, start: T, end: T, _start_knot: R, _end_knot: R) -> R {
+        (self.distance)(start, end).powf(self.alpha)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Sorted;
+
+    #[test]
+    fn explicit_tangents() {
+        let hermite = Hermite::builder()
+            .elements([0.0, 1.0, 0.0])
+            .knots([0.0, 1.0, 3.0])
+            .tangents([1.0, 0.0, -1.0])
+            .build()
+            .unwrap();
+        assert_f64_near!(hermite.gen(0.0), 0.0);
+        assert_f64_near!(hermite.gen(1.0), 1.0);
+        assert_f64_near!(hermite.gen(3.0), 0.0);
+        // h00*p0 + h10*h*m0 + h01*p1 + h11*h*m1 with t = 0.5 and h = 1
+        assert_f64_near!(hermite.gen(0.5), 0.625);
+        // second segment with h = 2 and t = 0.5
+        assert_f64_near!(hermite.gen(2.0), 0.75);
+        let eps = 1e-6;
+        let tangent = (hermite.gen(3.0) - hermite.gen(3.0 - eps)) / eps;
+        assert!((tangent + 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn catmull_rom() {
+        let catmull_rom = Hermite::builder()
+            .elements([0.0, 1.0, 8.0, 27.0, 64.0])
+            .equidistant::<f64>()
+            .distance(0.0, 1.0)
+            .catmull_rom()
+            .build()
+            .unwrap();
+        for (index, value) in [0.0, 1.0, 8.0, 27.0, 64.0].iter().enumerate() {
+            assert_f64_near!(catmull_rom.gen(index as f64), value);
+        }
+        // tangent at 1.0 is (8-0)/2 = 4 and at 2.0 is (27-1)/2 = 13
+        let expected = 0.5 + 0.125 * 4.0 + 0.5 * 8.0 - 0.125 * 13.0;
+        assert_f64_near!(catmull_rom.gen(1.5), expected);
+        // the curve is continuously differentiable
+        let eps = 1e-7;
+        for knot in [1.0, 2.0, 3.0] {
+            let left = (catmull_rom.gen(knot) - catmull_rom.gen(knot - eps)) / eps;
+            let right = (catmull_rom.gen(knot + eps) - catmull_rom.gen(knot)) / eps;
+            assert!((left - right).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn cardinal() {
+        let cardinal = Hermite::builder()
+            .elements([0.0, 1.0, 3.0, 2.0])
+            .equidistant::<f64>()
+            .normalized()
+            .cardinal(1.0)
+            .build()
+            .unwrap();
+        let hermite = Hermite::builder()
+            .elements([0.0, 1.0, 3.0, 2.0])
+            .equidistant::<f64>()
+            .normalized()
+            .tangents([0.0; 4])
+            .build()
+            .unwrap();
+        for (a, b) in cardinal.take(20).zip(hermite.take(20)) {
+            assert_f64_near!(a, b);
+        }
+    }
+
+    #[test]
+    fn non_uniform_knots() {
+        // non-uniform catmull-rom splines reproduce quadratic polynomials
+        let knots = [0.0, 0.5, 2.0, 2.5, 4.0];
+        let elements = knots.map(|x: f64| x * x - x);
+        let catmull_rom = Hermite::builder()
+            .elements(elements)
+            .knots(knots)
+            .catmull_rom()
+            .build()
+            .unwrap();
+        for x in [0.7, 1.0, 1.5, 2.2, 2.4] {
+            assert!((catmull_rom.gen(x) - (x * x - x)).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn centripetal() {
+        let distance = |a: f64, b: f64| (a - b).abs();
+        let centripetal = Hermite::builder()
+            .elements([0.0, 1.0, 1.1, 5.0])
+            .equidistant::<f64>()
+            .normalized()
+            .centripetal(distance)
+            .build()
+            .unwrap();
+        let chordal = Hermite::builder()
+            .elements([0.0, 1.0, 1.1, 5.0])
+            .equidistant::<f64>()
+            .normalized()
+            .chordal(distance)
+            .build()
+            .unwrap();
+        let uniform = Hermite::builder()
+            .elements([0.0, 1.0, 1.1, 5.0])
+            .equidistant::<f64>()
+            .normalized()
+            .alpha(0.0, distance)
+            .build()
+            .unwrap();
+        let catmull_rom = Hermite::builder()
+            .elements([0.0, 1.0, 1.1, 5.0])
+            .equidistant::<f64>()
+            .normalized()
+            .catmull_rom()
+            .build()
+            .unwrap();
+        for (a, b) in uniform.take(20).zip(catmull_rom.take(20)) {
+            assert_f64_near!(a, b);
+        }
+        // uniform catmull-rom overshoots in the short middle segment, centripetal and chordal do not.
+        let third = 1.0 / 3.0;
+        assert!(catmull_rom
+            .slice(third..2.0 * third)
+            .take(20)
+            .any(|value| value < 1.0));
+        for value in centripetal.slice(third..2.0 * third).take(20) {
+            assert!((1.0 - 1e-10..=1.1 + 1e-10).contains(&value));
+        }
+        for value in chordal.slice(third..2.0 * third).take(20) {
+            assert!((1.0 - 1e-10..=1.1 + 1e-10).contains(&value));
+        }
+    }
+
+    #[test]
+    fn new() {
+        let tangents = CatmullRom::new(0.0, KnotSpacing);
+        let knots = Sorted::new([1.0, 2.0]).unwrap();
+        assert!(Hermite::new([1.0], Sorted::new([1.0]).unwrap(), tangents).is_err());
+        assert!(Hermite::new([1.0, 2.0], Sorted::new([1.0, 2.0, 3.0]).unwrap(), tangents).is_err());
+        assert!(Hermite::new([1.0, 2.0], knots, tangents).is_ok());
+        assert!(Hermite::new_with_tangents([1.0, 2.0], knots, [0.0]).is_err());
+        assert!(Hermite::new_with_tangents([1.0, 2.0], knots, [0.0, 0.0]).is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn monotone() {
+        let knots = [0.0, 1.0, 1.5, 4.0, 5.0, 6.0];
+        let elements = [0.0, 0.1, 0.1, 5.0, 5.2, 9.0];
+        let monotone = Hermite::builder()
+            .elements(elements)
+            .knots(knots)
+            .monotone()
+            .build()
+            .unwrap();
+        for (knot, element) in knots.iter().zip(elements.iter()) {
+            assert_f64_near!(monotone.gen(knot), element);
+        }
+        let mut last = monotone.gen(0.0);
+        for value in monotone.by_ref().take(601) {
+            assert!(value >= last - 1e-12);
+            last = value;
+        }
+        // a flat segment has stored tangents of zero and stays flat
+        assert_f64_near!(monotone.tangents[1], 0.0);
+        assert_f64_near!(monotone.tangents[2], 0.0);
+        for value in monotone.by_ref().slice(1.0..1.5).take(10) {
+            assert!((value - 0.1).abs() < 1e-12);
+        }
+        // the curve is continuously differentiable
+        let eps = 1e-7;
+        for knot in [1.0, 4.0, 5.0] {
+            let left = (monotone.gen(knot) - monotone.gen(knot - eps)) / eps;
+            let right = (monotone.gen(knot + eps) - monotone.gen(knot)) / eps;
+            assert!((left - right).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn monotone_descending() {
+        let monotone = Hermite::builder()
+            .elements([10.0, 9.0, 2.0, 1.9, 0.0])
+            .equidistant::<f64>()
+            .normalized()
+            .monotone()
+            .build()
+            .unwrap();
+        let mut last = monotone.gen(0.0);
+        for value in monotone.take(401) {
+            assert!(value <= last + 1e-12);
+            last = value;
+        }
+        // linear elements are reproduced
+        let linear = Hermite::builder()
+            .elements([0.0, 2.0])
+            .knots([0.0, 1.0])
+            .monotone()
+            .build()
+            .unwrap();
+        assert_f64_near!(linear.gen(0.25), 0.5);
+    }
+}
@@ -16,6 +16,7 @@
     variant_size_differences
 )]
 
+#[cfg(test)]
 #[macro_use]
 extern crate assert_float_eq;
 
@@ -29,22 +30,37 @@ pub mod bezier;
 #[cfg(feature = "bspline")]
 pub mod bspline;
 pub mod easing;
+pub mod frames;
+#[cfg(feature = "hermite")]
+pub mod hermite;
 #[cfg(feature = "linear")]
 pub mod linear;
+#[cfg(feature = "polynomial")]
+pub mod polynomial;
+#[cfg(feature = "step")]
+pub mod step;
 pub mod utils;
 pub mod weights;
 
 mod base;
 mod builder;
+#[cfg(all(
+    test,
+    any(feature = "bezier", all(feature = "std", feature = "bspline"))
+))]
+pub(crate) mod test_util;
 
-pub use topology_traits::Merge;
+pub use topology_traits::{Merge, QuasiMetric};
 
 #[cfg(feature = "std")]
-pub use base::DynSpace;
+pub use base::{ArcLength, DynSpace};
 pub use base::{
-    Clamp, Composite, ConstDiscreteGenerator, ConstEquidistant, ConstSpace, Curve,
-    DiscreteGenerator, Equidistant, Extract, Generator, NotSorted, Repeat, Slice, Sorted,
-    SortedGenerator, Space, Stack, Stepper, TransformInput, Wrap,
+    Chebyshev, Clamp, Composite, ConstDiscreteGenerator, ConstEquidistant, ConstSpace, Curve,
+    DiscreteGenerator, Equidistant, Extract, Generator, Inverse, InverseError, NotMonotonic,
+    NotSorted, OutOfRange, Repeat, Slice, Sorted, SortedGenerator, Space, Stack, Stepper, Take,
+    TransformInput, Wrap,
 };
+#[cfg(all(feature = "std", any(feature = "bezier", feature = "bspline")))]
+pub use base::{Components, Extrema};
 pub use easing::Identity;
 // pub use weights::{Homogeneous, Weighted, Weights, IntoWeight};
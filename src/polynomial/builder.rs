@@ -0,0 +1,552 @@
+//! Builder module for polynomial interpolations.
+
+use super::error::{Empty, PolynomialError};
+use super::{ChebyshevWeights, KnotElementInequality, Polynomial, RepeatedKnot};
+use crate::builder::{Type, Unknown};
+use crate::{Chebyshev, DiscreteGenerator, Equidistant, Generator, Sorted};
+use num_traits::real::Real;
+use num_traits::FromPrimitive;
+
+#[cfg(feature = "std")]
+use super::barycentric_weights;
+
+/// Builder for polynomial interpolation.
+///
+/// This struct helps create polynomial interpolations. The difference between this struct and [`PolynomialBuilder`]
+/// is that this struct may have other fallible methods and not only the [`build()`] method.
+///
+/// Before building, one has to give information for:
+/// - The elements the interpolation should use with [`elements()`].
+/// - The knots the interpolation uses. Either by giving them directly with [`knots()`],
+///   by using equidistant knots with [`equidistant()`] or by using Chebyshev points with [`chebyshev()`].
+///
+/// ```rust
+/// # use enterpolation::{polynomial::{PolynomialDirector, PolynomialError}, Generator, Curve};
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// #
+/// # fn main() -> Result<(), PolynomialError> {
+/// let polynomial = PolynomialDirector::new()
+///                 .elements([1.0,0.0,1.0])?
+///                 .chebyshev::<f64>()
+///                 .domain(-1.0,1.0)
+///                 .build();
+/// let results = [1.0,0.25,0.0,0.25,1.0];
+/// for (value,result) in polynomial.take(5).zip(results.iter().copied()){
+///     assert_f64_near!(value, result);
+/// }
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`PolynomialBuilder`]: PolynomialBuilder
+/// [`build()`]: PolynomialDirector::build()
+/// [`elements()`]: PolynomialDirector::elements()
+/// [`knots()`]: PolynomialDirector::knots()
+/// [`equidistant()`]: PolynomialDirector::equidistant()
+/// [`chebyshev()`]: PolynomialDirector::chebyshev()
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct PolynomialDirector<K, E> {
+    knots: K,
+    elements: E,
+}
+
+/// Builder for polynomial interpolation.
+///
+/// This struct helps create polynomial interpolations. Its only fallible method is [`build()`].
+/// Usually one creates an instance by using the [`builder()`] method on the interpolation itself.
+///
+/// Before building, one has to give information for:
+/// - The elements the interpolation should use with [`elements()`].
+/// - The knots the interpolation uses. Either by giving them directly with [`knots()`],
+///   by using equidistant knots with [`equidistant()`] or by using Chebyshev points with [`chebyshev()`].
+///
+/// ```rust
+/// # use enterpolation::{polynomial::{Polynomial, PolynomialError}, Generator, Curve};
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// #
+/// # fn main() -> Result<(), PolynomialError> {
+/// let polynomial = Polynomial::builder()
+///                 .elements([1.0,0.0,1.0])
+///                 .chebyshev::<f64>()
+///                 .domain(-1.0,1.0)
+///                 .build()?;
+/// let results = [1.0,0.25,0.0,0.25,1.0];
+/// for (value,result) in polynomial.take(5).zip(results.iter().copied()){
+///     assert_f64_near!(value, result);
+/// }
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`build()`]: PolynomialBuilder::build()
+/// [`builder()`]: super::Polynomial::builder()
+/// [`elements()`]: PolynomialBuilder::elements()
+/// [`knots()`]: PolynomialBuilder::knots()
+/// [`equidistant()`]: PolynomialBuilder::equidistant()
+/// [`chebyshev()`]: PolynomialBuilder::chebyshev()
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct PolynomialBuilder<K, E> {
+    inner: Result<PolynomialDirector<K, E>, PolynomialError>,
+}
+
+impl Default for PolynomialDirector<Unknown, Unknown> {
+    fn default() -> Self {
+        PolynomialDirector::new()
+    }
+}
+
+impl Default for PolynomialBuilder<Unknown, Unknown> {
+    fn default() -> Self {
+        PolynomialBuilder::new()
+    }
+}
+
+impl PolynomialDirector<Unknown, Unknown> {
+    /// Create a new polynomial interpolation builder.
+    pub const fn new() -> Self {
+        PolynomialDirector {
+            knots: Unknown,
+            elements: Unknown,
+        }
+    }
+}
+
+impl PolynomialBuilder<Unknown, Unknown> {
+    /// Create a new polynomial interpolation builder.
+    pub const fn new() -> Self {
+        PolynomialBuilder {
+            inner: Ok(PolynomialDirector::new()),
+        }
+    }
+}
+
+impl PolynomialDirector<Unknown, Unknown> {
+    /// Set the elements of the polynomial interpolation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Empty`] if no elements are given.
+    ///
+    /// [`Empty`]: super::error::PolynomialError
+    pub fn elements<E>(self, elements: E) -> Result<PolynomialDirector<Unknown, E>, Empty>
+    where
+        E: DiscreteGenerator,
+    {
+        if elements.is_empty() {
+            return Err(Empty::new());
+        }
+        Ok(PolynomialDirector {
+            knots: self.knots,
+            elements,
+        })
+    }
+}
+
+impl PolynomialBuilder<Unknown, Unknown> {
+    /// Set the elements of the polynomial interpolation.
+    pub fn elements<E>(self, elements: E) -> PolynomialBuilder<Unknown, E>
+    where
+        E: DiscreteGenerator,
+    {
+        PolynomialBuilder {
+            inner: self
+                .inner
+                .and_then(|director| director.elements(elements).map_err(|err| err.into())),
+        }
+    }
+}
+
+impl<E> PolynomialDirector<Unknown, E> {
+    /// Set the knots of the interpolation.
+    ///
+    /// The amount of knots must be equal to the amount of elements.
+    ///
+    /// # Performance
+    ///
+    /// Interpolations with arbitrary knots have to store their barycentric weights.
+    /// If you are able to choose the knots, consider using [`chebyshev()`] instead.
+    ///
+    /// [`chebyshev()`]: PolynomialDirector::chebyshev()
+    ///
+    /// # Errors
+    ///
+    /// Returns [`KnotElementInequality`] if the number of knots is not equal to the number of elements.
+    /// Returns [`NotSorted`] if the knots are not sorted such that they are increasing.
+    /// Returns [`RepeatedKnot`] if two knots are equal.
+    ///
+    /// [`KnotElementInequality`]: super::error::PolynomialError
+    /// [`NotSorted`]:  super::error::PolynomialError
+    /// [`RepeatedKnot`]:  super::error::PolynomialError
+    pub fn knots<K>(self, knots: K) -> Result<PolynomialDirector<Sorted<K>, E>, PolynomialError>
+    where
+        E: DiscreteGenerator,
+        K: DiscreteGenerator,
+        K::Output: PartialOrd,
+    {
+        if self.elements.len() != knots.len() {
+            return Err(KnotElementInequality::new(self.elements.len(), knots.len()).into());
+        }
+        let knots = Sorted::new(knots)?;
+        for i in 1..knots.len() {
+            if knots.gen(i - 1) == knots.gen(i) {
+                return Err(RepeatedKnot::new(i - 1, i).into());
+            }
+        }
+        Ok(PolynomialDirector {
+            knots,
+            elements: self.elements,
+        })
+    }
+
+    /// Build an interpolation with equidistant knots.
+    ///
+    /// This method takes `R` as a generic parameter. `R` has to be the type you want the knots to be.
+    /// Often this is just `f32` or `f64`.
+    ///
+    /// After this call, you also have to call either of
+    /// - [`domain()`],
+    /// - [`normalized()`] or
+    /// - [`distance()`],
+    ///
+    /// which all define the domain of the interpolation and the spacing of the knots.
+    ///
+    /// # Remark
+    ///
+    /// Polynomials with many equidistant knots tend to oscillate at the borders of their domain.
+    ///
+    /// [`domain()`]: PolynomialDirector::domain()
+    /// [`normalized()`]: PolynomialDirector::normalized()
+    /// [`distance()`]: PolynomialDirector::distance()
+    pub fn equidistant<R>(self) -> PolynomialDirector<Type<R>, E> {
+        PolynomialDirector {
+            knots: Type::new(),
+            elements: self.elements,
+        }
+    }
+
+    /// Build an interpolation with Chebyshev points as knots.
+    ///
+    /// This method takes `R` as a generic parameter. `R` has to be the type you want the knots to be.
+    /// Often this is just `f32` or `f64`.
+    ///
+    /// After this call, you also have to call either [`domain()`] or [`normalized()`],
+    /// which define the domain of the interpolation.
+    ///
+    /// # Performance
+    ///
+    /// The barycentric weights of Chebyshev points are known, such they do not have to be calculated and stored.
+    ///
+    /// [`domain()`]: PolynomialDirector::domain()
+    /// [`normalized()`]: PolynomialDirector::normalized()
+    pub fn chebyshev<R>(self) -> PolynomialDirector<Type<Chebyshev<R>>, E> {
+        PolynomialDirector {
+            knots: Type::new(),
+            elements: self.elements,
+        }
+    }
+}
+
+impl<E> PolynomialBuilder<Unknown, E> {
+    /// Set the knots of the interpolation.
+    ///
+    /// The amount of knots must be equal to the amount of elements.
+    ///
+    /// # Performance
+    ///
+    /// Interpolations with arbitrary knots have to store their barycentric weights.
+    /// If you are able to choose the knots, consider using [`chebyshev()`] instead.
+    ///
+    /// [`chebyshev()`]: PolynomialBuilder::chebyshev()
+    pub fn knots<K>(self, knots: K) -> PolynomialBuilder<Sorted<K>, E>
+    where
+        E: DiscreteGenerator,
+        K: DiscreteGenerator,
+        K::Output: PartialOrd,
+    {
+        PolynomialBuilder {
+            inner: self.inner.and_then(|director| director.knots(knots)),
+        }
+    }
+
+    /// Build an interpolation with equidistant knots.
+    ///
+    /// This method takes `R` as a generic parameter. `R` has to be the type you want the knots to be.
+    /// Often this is just `f32` or `f64`.
+    ///
+    /// After this call, you also have to call either of
+    /// - [`domain()`],
+    /// - [`normalized()`] or
+    /// - [`distance()`],
+    ///
+    /// which all define the domain of the interpolation and the spacing of the knots.
+    ///
+    /// # Remark
+    ///
+    /// Polynomials with many equidistant knots tend to oscillate at the borders of their domain.
+    ///
+    /// [`domain()`]: PolynomialBuilder::domain()
+    /// [`normalized()`]: PolynomialBuilder::normalized()
+    /// [`distance()`]: PolynomialBuilder::distance()
+    pub fn equidistant<R>(self) -> PolynomialBuilder<Type<R>, E> {
+        PolynomialBuilder {
+            inner: self.inner.map(|director| director.equidistant()),
+        }
+    }
+
+    /// Build an interpolation with Chebyshev points as knots.
+    ///
+    /// This method takes `R` as a generic parameter. `R` has to be the type you want the knots to be.
+    /// Often this is just `f32` or `f64`.
+    ///
+    /// After this call, you also have to call either [`domain()`] or [`normalized()`],
+    /// which define the domain of the interpolation.
+    ///
+    /// # Performance
+    ///
+    /// The barycentric weights of Chebyshev points are known, such they do not have to be calculated and stored.
+    ///
+    /// [`domain()`]: PolynomialBuilder::domain()
+    /// [`normalized()`]: PolynomialBuilder::normalized()
+    pub fn chebyshev<R>(self) -> PolynomialBuilder<Type<Chebyshev<R>>, E> {
+        PolynomialBuilder {
+            inner: self.inner.map(|director| director.chebyshev()),
+        }
+    }
+}
+
+impl<R, E> PolynomialDirector<Type<R>, E>
+where
+    E: DiscreteGenerator,
+    R: Real + FromPrimitive,
+{
+    /// Set the domain of the interpolation.
+    pub fn domain(self, start: R, end: R) -> PolynomialDirector<Equidistant<R>, E> {
+        PolynomialDirector {
+            knots: Equidistant::new(self.elements.len(), start, end),
+            elements: self.elements,
+        }
+    }
+
+    /// Set the domain of the interpolation to be [0.0,1.0].
+    pub fn normalized(self) -> PolynomialDirector<Equidistant<R>, E> {
+        PolynomialDirector {
+            knots: Equidistant::normalized(self.elements.len()),
+            elements: self.elements,
+        }
+    }
+    /// Set the domain of the interpolation by defining the distance between the knots
+    pub fn distance(self, start: R, step: R) -> PolynomialDirector<Equidistant<R>, E> {
+        PolynomialDirector {
+            knots: Equidistant::step(self.elements.len(), start, step),
+            elements: self.elements,
+        }
+    }
+}
+
+impl<R, E> PolynomialBuilder<Type<R>, E>
+where
+    E: DiscreteGenerator,
+    R: Real + FromPrimitive,
+{
+    /// Set the domain of the interpolation.
+    pub fn domain(self, start: R, end: R) -> PolynomialBuilder<Equidistant<R>, E> {
+        PolynomialBuilder {
+            inner: self.inner.map(|director| director.domain(start, end)),
+        }
+    }
+
+    /// Set the domain of the interpolation to be [0.0,1.0].
+    pub fn normalized(self) -> PolynomialBuilder<Equidistant<R>, E> {
+        PolynomialBuilder {
+            inner: self.inner.map(|director| director.normalized()),
+        }
+    }
+    /// Set the domain of the interpolation by defining the distance between the knots
+    pub fn distance(self, start: R, step: R) -> PolynomialBuilder<Equidistant<R>, E> {
+        PolynomialBuilder {
+            inner: self.inner.map(|director| director.distance(start, step)),
+        }
+    }
+}
+
+impl<R, E> PolynomialDirector<Type<Chebyshev<R>>, E>
+where
+    E: DiscreteGenerator,
+    R: Real + FromPrimitive,
+{
+    /// Set the domain of the interpolation.
+    pub fn domain(self, start: R, end: R) -> PolynomialDirector<Chebyshev<R>, E> {
+        PolynomialDirector {
+            knots: Chebyshev::new(self.elements.len(), start, end),
+            elements: self.elements,
+        }
+    }
+
+    /// Set the domain of the interpolation to be [0.0,1.0].
+    pub fn normalized(self) -> PolynomialDirector<Chebyshev<R>, E> {
+        PolynomialDirector {
+            knots: Chebyshev::normalized(self.elements.len()),
+            elements: self.elements,
+        }
+    }
+}
+
+impl<R, E> PolynomialBuilder<Type<Chebyshev<R>>, E>
+where
+    E: DiscreteGenerator,
+    R: Real + FromPrimitive,
+{
+    /// Set the domain of the interpolation.
+    pub fn domain(self, start: R, end: R) -> PolynomialBuilder<Chebyshev<R>, E> {
+        PolynomialBuilder {
+            inner: self.inner.map(|director| director.domain(start, end)),
+        }
+    }
+
+    /// Set the domain of the interpolation to be [0.0,1.0].
+    pub fn normalized(self) -> PolynomialBuilder<Chebyshev<R>, E> {
+        PolynomialBuilder {
+            inner: self.inner.map(|director| director.normalized()),
+        }
+    }
+}
+
+impl<R, E> PolynomialDirector<Chebyshev<R>, E>
+where
+    E: DiscreteGenerator,
+    R: Real + FromPrimitive,
+{
+    /// Build a polynomial interpolation.
+    pub fn build(self) -> Polynomial<Chebyshev<R>, E, ChebyshevWeights<R>> {
+        Polynomial::new_unchecked(
+            self.elements,
+            self.knots,
+            ChebyshevWeights::new(self.knots.len()),
+        )
+    }
+}
+
+impl<R, E> PolynomialBuilder<Chebyshev<R>, E>
+where
+    E: DiscreteGenerator,
+    R: Real + FromPrimitive,
+{
+    /// Build a polynomial interpolation.
+    pub fn build(
+        self,
+    ) -> Result<Polynomial<Chebyshev<R>, E, ChebyshevWeights<R>>, PolynomialError> {
+        match self.inner {
+            Err(err) => Err(err),
+            Ok(director) => Ok(director.build()),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R, E> PolynomialDirector<Equidistant<R>, E>
+where
+    E: DiscreteGenerator,
+    R: Real + FromPrimitive,
+{
+    /// Build a polynomial interpolation.
+    pub fn build(self) -> Polynomial<Equidistant<R>, E, Vec<R>> {
+        let weights = barycentric_weights(&self.knots);
+        Polynomial::new_unchecked(self.elements, self.knots, weights)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R, E> PolynomialBuilder<Equidistant<R>, E>
+where
+    E: DiscreteGenerator,
+    R: Real + FromPrimitive,
+{
+    /// Build a polynomial interpolation.
+    pub fn build(self) -> Result<Polynomial<Equidistant<R>, E, Vec<R>>, PolynomialError> {
+        match self.inner {
+            Err(err) => Err(err),
+            Ok(director) => Ok(director.build()),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R, K, E> PolynomialDirector<Sorted<K>, E>
+where
+    E: DiscreteGenerator,
+    K: DiscreteGenerator<Output = R>,
+    R: Real,
+{
+    /// Build a polynomial interpolation.
+    pub fn build(self) -> Polynomial<Sorted<K>, E, Vec<R>> {
+        let weights = barycentric_weights(&self.knots);
+        Polynomial::new_unchecked(self.elements, self.knots, weights)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R, K, E> PolynomialBuilder<Sorted<K>, E>
+where
+    E: DiscreteGenerator,
+    K: DiscreteGenerator<Output = R>,
+    R: Real,
+{
+    /// Build a polynomial interpolation.
+    pub fn build(self) -> Result<Polynomial<Sorted<K>, E, Vec<R>>, PolynomialError> {
+        match self.inner {
+            Err(err) => Err(err),
+            Ok(director) => Ok(director.build()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::PolynomialBuilder;
+
+    #[test]
+    fn builder_errors() {
+        assert!(PolynomialBuilder::new()
+            .elements::<[f64; 0]>([])
+            .chebyshev::<f64>()
+            .normalized()
+            .build()
+            .is_err());
+        assert!(PolynomialBuilder::new()
+            .elements([1.0])
+            .chebyshev::<f64>()
+            .normalized()
+            .build()
+            .is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn knot_errors() {
+        assert!(PolynomialBuilder::new()
+            .elements([1.0, 2.0])
+            .knots([1.0, 2.0, 3.0])
+            .build()
+            .is_err());
+        assert!(PolynomialBuilder::new()
+            .elements([1.0, 2.0, 3.0])
+            .knots([1.0, 3.0, 2.0])
+            .build()
+            .is_err());
+        assert!(PolynomialBuilder::new()
+            .elements([1.0, 2.0, 3.0])
+            .knots([1.0, 2.0, 2.0])
+            .build()
+            .is_err());
+        assert!(PolynomialBuilder::new()
+            .elements([1.0, 2.0, 3.0])
+            .equidistant::<f64>()
+            .normalized()
+            .build()
+            .is_ok());
+    }
+}
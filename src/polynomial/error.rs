@@ -0,0 +1,122 @@
+//! All error types for polynomial interpolation.
+
+pub use crate::builder::Empty;
+pub use crate::NotSorted;
+use core::{convert::From, fmt};
+
+#[cfg(feature = "std")]
+use std::error::Error;
+
+/// Errors which could occur when using or creating a polynomial interpolation.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum PolynomialError {
+    /// Error returned if the generator does not contain any elements.
+    Empty(Empty),
+    /// Error returned if the number of knots and elements are not equal.
+    KnotElementInequality(KnotElementInequality),
+    /// Error returned if knots are not sorted.
+    NotSorted(NotSorted),
+    /// Error returned if two knots are equal.
+    RepeatedKnot(RepeatedKnot),
+}
+
+impl fmt::Display for PolynomialError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PolynomialError::Empty(inner) => inner.fmt(f),
+            PolynomialError::KnotElementInequality(inner) => inner.fmt(f),
+            PolynomialError::NotSorted(inner) => inner.fmt(f),
+            PolynomialError::RepeatedKnot(inner) => inner.fmt(f),
+        }
+    }
+}
+
+impl From<Empty> for PolynomialError {
+    fn from(from: Empty) -> Self {
+        PolynomialError::Empty(from)
+    }
+}
+
+impl From<KnotElementInequality> for PolynomialError {
+    fn from(from: KnotElementInequality) -> Self {
+        PolynomialError::KnotElementInequality(from)
+    }
+}
+
+impl From<NotSorted> for PolynomialError {
+    fn from(from: NotSorted) -> Self {
+        PolynomialError::NotSorted(from)
+    }
+}
+
+impl From<RepeatedKnot> for PolynomialError {
+    fn from(from: RepeatedKnot) -> Self {
+        PolynomialError::RepeatedKnot(from)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for PolynomialError {}
+
+/// Error returned if the number of elements and the number of knots are not matching.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct KnotElementInequality {
+    /// The number of elements found.
+    elements: usize,
+    /// The number of knots found.
+    knots: usize,
+}
+
+impl fmt::Display for KnotElementInequality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "There has to be as many knots as elements, however we found {} elements and {} knots.",
+            self.elements, self.knots
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for KnotElementInequality {}
+
+impl KnotElementInequality {
+    /// Create a new error with the number of elements and knots found.
+    pub fn new(elements: usize, knots: usize) -> Self {
+        KnotElementInequality { elements, knots }
+    }
+}
+
+/// Error returned if two knots are equal.
+///
+/// A polynomial can only interpolate one element per knot, such all knots have to be distinct.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct RepeatedKnot {
+    /// The indices of the equal knots.
+    indices: [usize; 2],
+}
+
+impl fmt::Display for RepeatedKnot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "All knots have to be distinct, however the knots at index {} and {} are equal.",
+            self.indices[0], self.indices[1]
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for RepeatedKnot {}
+
+impl RepeatedKnot {
+    /// Create a new error with the indices of two equal knots.
+    pub fn new(first: usize, second: usize) -> Self {
+        RepeatedKnot {
+            indices: [first, second],
+        }
+    }
+}
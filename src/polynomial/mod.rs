@@ -0,0 +1,461 @@
+//! Polynomial interpolations.
+//!
+//! The easiest way to create a polynomial interpolation is by using the builder pattern of [`PolynomialBuilder`].
+//!
+//! ```rust
+//! # use enterpolation::{polynomial::{Polynomial, PolynomialError}, Generator, Curve};
+//! # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+//! #
+//! # fn main() -> Result<(), PolynomialError> {
+//! let polynomial = Polynomial::builder()
+//!                 .elements([1.0,0.0,1.0])
+//!                 .chebyshev::<f64>()
+//!                 .domain(-1.0,1.0)
+//!                 .build()?;
+//! // the polynomial x^2
+//! let results = [1.0,0.25,0.0,0.25,1.0];
+//! for (value,result) in polynomial.take(5).zip(results.iter().copied()){
+//!     assert_f64_near!(value, result);
+//! }
+//! #
+//! #     Ok(())
+//! # }
+//! ```
+//!
+//! A polynomial interpolation is the unique polynomial of minimal degree which goes through all elements
+//! at their knots. The polynomial is evaluated with the barycentric form of the Lagrange interpolation,
+//! which is numerically stable and only needs linear time for every evaluation.
+//!
+//! Polynomials of high degree tend to oscillate at the borders of their domain if their knots are
+//! equidistant (Runge's phenomenon). For this reason [`chebyshev()`] knots, which cluster at the
+//! borders, should be preferred whenever one is able to choose the knots.
+//!
+//! With the *std* feature, polynomials can also be constructed incrementally in the Newton form
+//! with [`Newton`] and converted into bezier curves with [`to_bezier()`] if the *bezier* feature
+//! is enabled as well.
+//!
+//! Any curve can be approximated by a polynomial with [`Curve::approximate_chebyshev()`],
+//! which returns a [`ChebyshevSeries`] being cheap to evaluate.
+//!
+//! [`PolynomialBuilder`]: PolynomialBuilder
+//! [`chebyshev()`]: PolynomialBuilder::chebyshev()
+//! [`Newton`]: Newton
+//! [`to_bezier()`]: Polynomial::to_bezier()
+//! [`Curve::approximate_chebyshev()`]: crate::Curve::approximate_chebyshev()
+//! [`ChebyshevSeries`]: ChebyshevSeries
+
+use crate::builder::Unknown;
+use crate::{Curve, DiscreteGenerator, Generator, SortedGenerator};
+use core::marker::PhantomData;
+use core::ops::{Add, Mul};
+use num_traits::real::Real;
+use num_traits::FromPrimitive;
+
+#[cfg(all(feature = "std", feature = "bezier"))]
+use crate::{bezier::Bezier, DynSpace};
+#[cfg(feature = "std")]
+use core::ops::Sub;
+
+mod builder;
+pub use builder::{PolynomialBuilder, PolynomialDirector};
+
+pub mod error;
+pub use error::{Empty, KnotElementInequality, NotSorted, PolynomialError, RepeatedKnot};
+
+#[cfg(feature = "std")]
+mod newton;
+#[cfg(feature = "std")]
+pub use newton::Newton;
+
+#[cfg(feature = "std")]
+mod series;
+#[cfg(feature = "std")]
+pub use series::ChebyshevSeries;
+
+/// Polynomial interpolation in barycentric form.
+///
+/// See [polynomial module] for more information.
+///
+/// [polynomial module]: self
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Polynomial<K, E, W> {
+    elements: E,
+    knots: K,
+    weights: W,
+}
+
+impl Polynomial<Unknown, Unknown, Unknown> {
+    /// Get the builder for a polynomial interpolation.
+    ///
+    /// The builder takes:
+    /// - elements with [`elements()`]
+    /// - knots with either [`knots()`], [`equidistant()`] or [`chebyshev()`]
+    ///
+    /// Building polynomials with given or equidistant knots needs the *std* feature,
+    /// as their barycentric weights are calculated once and stored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{polynomial::{Polynomial, PolynomialError}, Generator, Curve};
+    /// #
+    /// # fn main() -> Result<(), PolynomialError> {
+    /// // the Chebyshev points in [-1.0,1.0] are -1.0, -0.5, 0.5 and 1.0
+    /// let polynomial = Polynomial::builder()
+    ///                 .elements([-1.0,-0.125,0.125,1.0])
+    ///                 .chebyshev::<f64>()
+    ///                 .domain(-1.0,1.0)
+    ///                 .build()?;
+    /// // the polynomial x^3
+    /// assert!((polynomial.gen(0.3) - 0.027).abs() < 1e-10);
+    /// assert!((polynomial.gen(2.0) - 8.0).abs() < 1e-10);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`elements()`]: PolynomialBuilder::elements()
+    /// [`knots()`]: PolynomialBuilder::knots()
+    /// [`equidistant()`]: PolynomialBuilder::equidistant()
+    /// [`chebyshev()`]: PolynomialBuilder::chebyshev()
+    pub fn builder() -> PolynomialBuilder<Unknown, Unknown> {
+        PolynomialBuilder::new()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R, K, E> Polynomial<K, E, Vec<R>>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    R: Real,
+{
+    /// Create a polynomial interpolation with slice-like collections of elements and knots.
+    ///
+    /// Knots have to be sorted and distinct, there should be as many knots as elements
+    /// and there has to be at least one element. The barycentric weights are calculated
+    /// and stored in a vector.
+    pub fn new(elements: E, knots: K) -> Result<Self, PolynomialError> {
+        if elements.is_empty() {
+            return Err(Empty::new().into());
+        }
+        if knots.len() != elements.len() {
+            return Err(KnotElementInequality::new(elements.len(), knots.len()).into());
+        }
+        for i in 1..knots.len() {
+            if knots.gen(i - 1) == knots.gen(i) {
+                return Err(RepeatedKnot::new(i - 1, i).into());
+            }
+        }
+        let weights = barycentric_weights(&knots);
+        Ok(Polynomial {
+            elements,
+            knots,
+            weights,
+        })
+    }
+}
+
+impl<K, E, W> Polynomial<K, E, W> {
+    /// Create a polynomial interpolation with slice-like collections of elements, knots and barycentric weights.
+    ///
+    /// # Panics
+    ///
+    /// Knots should be distinct and in increasing order, there should be as many knots and weights as elements
+    /// and there has to be at least *one* element. The weights have to be the barycentric weights of the knots,
+    /// otherwise the resulting curve may not interpolate the elements.
+    /// If any of these requirements are not uphold, the library may panic at any time.
+    pub fn new_unchecked(elements: E, knots: K, weights: W) -> Self {
+        Polynomial {
+            elements,
+            knots,
+            weights,
+        }
+    }
+}
+
+impl<R, K, E, W> Generator<R> for Polynomial<K, E, W>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    E::Output: Add<Output = E::Output> + Mul<R, Output = E::Output> + Copy,
+    W: DiscreteGenerator<Output = R>,
+    R: Real,
+{
+    type Output = E::Output;
+    fn gen(&self, scalar: R) -> Self::Output {
+        if self.elements.len() == 1 {
+            return self.elements.gen(0);
+        }
+        let mut numerator = self.elements.gen(0) * R::zero();
+        let mut denominator = R::zero();
+        for i in 0..self.elements.len() {
+            let difference = scalar - self.knots.gen(i);
+            if difference.is_zero() {
+                return self.elements.gen(i);
+            }
+            let factor = self.weights.gen(i) / difference;
+            numerator = numerator + self.elements.gen(i) * factor;
+            denominator = denominator + factor;
+        }
+        numerator * denominator.recip()
+    }
+}
+
+impl<R, K, E, W> Curve<R> for Polynomial<K, E, W>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    E::Output: Add<Output = E::Output> + Mul<R, Output = E::Output> + Copy,
+    W: DiscreteGenerator<Output = R>,
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        [self.knots.first().unwrap(), self.knots.last().unwrap()]
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R, K, E, W> Polynomial<K, E, W>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    E::Output:
+        Add<Output = E::Output> + Sub<Output = E::Output> + Mul<R, Output = E::Output> + Copy,
+    W: DiscreteGenerator<Output = R>,
+    R: Real,
+{
+    /// Returns the same polynomial in the Newton form.
+    pub fn newton(&self) -> Newton<R, E::Output> {
+        Newton::new(&self.elements, &self.knots)
+            .expect("The knots of a polynomial are always distinct.")
+    }
+
+    /// Returns the same polynomial as bezier curve.
+    ///
+    /// As bezier curves always have the domain [0.0,1.0], the domain of the polynomial gets
+    /// mapped onto it. The bezier curve has as many elements as the polynomial.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{polynomial::{Polynomial, PolynomialError}, Generator, Curve};
+    /// #
+    /// # fn main() -> Result<(), PolynomialError> {
+    /// let polynomial = Polynomial::builder()
+    ///                 .elements([3.0,-1.0,2.0,0.5])
+    ///                 .equidistant::<f64>()
+    ///                 .domain(2.0,5.0)
+    ///                 .build()?;
+    /// let bezier = polynomial.to_bezier();
+    /// assert!((bezier.gen(0.5) - polynomial.gen(3.5)).abs() < 1e-10);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "bezier")]
+    pub fn to_bezier(&self) -> Bezier<R, Vec<E::Output>, DynSpace<E::Output>>
+    where
+        E::Output: Default,
+    {
+        self.newton().to_bezier()
+    }
+}
+
+/// Barycentric weights of Chebyshev points.
+///
+/// The barycentric weights of Chebyshev points of the second kind have a closed form,
+/// such they can be generated without storing them.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ChebyshevWeights<R = f64> {
+    len: usize,
+    _phantom: PhantomData<*const R>,
+}
+
+impl<R> ChebyshevWeights<R> {
+    /// Create the barycentric weights of `len` Chebyshev points.
+    pub const fn new(len: usize) -> Self {
+        ChebyshevWeights {
+            len,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<R> Generator<usize> for ChebyshevWeights<R>
+where
+    R: Real + FromPrimitive,
+{
+    type Output = R;
+    fn gen(&self, input: usize) -> R {
+        let weight = if input == 0 || input + 1 == self.len {
+            R::one() / (R::one() + R::one())
+        } else {
+            R::one()
+        };
+        if input % 2 == 0 {
+            weight
+        } else {
+            -weight
+        }
+    }
+}
+
+impl<R> DiscreteGenerator for ChebyshevWeights<R>
+where
+    R: Real + FromPrimitive,
+{
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+/// Calculates the barycentric weights of the given distinct knots.
+///
+/// All differences of knots are scaled by a quarter of the length of the domain,
+/// which prevents the weights from overflowing or underflowing for many knots.
+#[cfg(feature = "std")]
+fn barycentric_weights<K, R>(knots: &K) -> Vec<R>
+where
+    K: SortedGenerator<Output = R>,
+    R: Real,
+{
+    let four = R::one() + R::one() + R::one() + R::one();
+    let length = knots.last().unwrap() - knots.first().unwrap();
+    let scale = if length.is_zero() {
+        R::one()
+    } else {
+        four / length
+    };
+    (0..knots.len())
+        .map(|i| {
+            let knot = knots.gen(i);
+            let product = (0..knots.len())
+                .filter(|&j| j != i)
+                .fold(R::one(), |acc, j| acc * (knot - knots.gen(j)) * scale);
+            product.recip()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Chebyshev;
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn interpolates() {
+        let knots = [-1.0, 0.5, 2.0, 2.5, 4.0];
+        let elements = [3.0, -1.0, 0.0, 2.0, 1.0];
+        let polynomial = Polynomial::builder()
+            .elements(elements)
+            .knots(knots)
+            .build()
+            .unwrap();
+        assert_eq!(polynomial.domain(), [-1.0, 4.0]);
+        for (knot, element) in knots.iter().zip(elements.iter()) {
+            assert_f64_near!(polynomial.gen(knot), element);
+            assert!((polynomial.gen(knot + 1e-9) - element).abs() < 1e-6);
+        }
+        let constant = Polynomial::builder()
+            .elements([2.0])
+            .chebyshev::<f64>()
+            .normalized()
+            .build()
+            .unwrap();
+        assert_f64_near!(constant.gen(0.5), 2.0);
+        assert_f64_near!(constant.gen(3.0), 2.0);
+    }
+
+    #[test]
+    fn reproduces_polynomials() {
+        let func = |x: f64| 0.5 * x.powi(4) - x.powi(3) + 2.0 * x - 1.0;
+        let chebyshev = Chebyshev::new(5, -2.0, 3.0);
+        let mut elements = [0.0; 5];
+        for (i, element) in elements.iter_mut().enumerate() {
+            *element = func(chebyshev.gen(i));
+        }
+        let polynomial = Polynomial::builder()
+            .elements(elements)
+            .chebyshev::<f64>()
+            .domain(-2.0, 3.0)
+            .build()
+            .unwrap();
+        for i in 0..=20 {
+            let x = -3.0 + i as f64 * 0.35;
+            assert!((polynomial.gen(x) - func(x)).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn runge() {
+        let runge = |x: f64| 1.0 / (1.0 + 25.0 * x * x);
+        let chebyshev = Chebyshev::new(65, -1.0, 1.0);
+        let elements: Vec<f64> = chebyshev.iter().map(runge).collect();
+        let polynomial = Polynomial::builder()
+            .elements(elements)
+            .chebyshev::<f64>()
+            .domain(-1.0, 1.0)
+            .build()
+            .unwrap();
+        for i in 0..=200 {
+            let x = -1.0 + i as f64 / 100.0;
+            assert!((polynomial.gen(x) - runge(x)).abs() < 1e-4);
+        }
+        // weights calculated for arbitrary knots have to give the same result
+        let general = Polynomial::new(polynomial.elements.clone(), chebyshev).unwrap();
+        for i in 0..=200 {
+            let x = -1.0 + i as f64 / 100.0;
+            assert!((polynomial.gen(x) - general.gen(x)).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn newton() {
+        let polynomial = Polynomial::builder()
+            .elements([1.0, 0.0, -1.0, 3.0, 2.0])
+            .knots([0.0, 1.0, 1.5, 4.0, 4.5])
+            .build()
+            .unwrap();
+        let newton = polynomial.newton();
+        assert_eq!(newton.domain(), [0.0, 4.5]);
+        for i in 0..=20 {
+            let x = -1.0 + i as f64 * 0.3;
+            assert!((newton.gen(x) - polynomial.gen(x)).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", feature = "bezier"))]
+    fn bezier() {
+        let polynomial = Polynomial::builder()
+            .elements([3.0, -1.0, 2.0, 0.5, 1.0])
+            .knots([-1.0, 0.0, 0.5, 2.0, 3.0])
+            .build()
+            .unwrap();
+        let bezier = polynomial.to_bezier();
+        for i in 0..=10 {
+            let t = i as f64 / 10.0;
+            assert!((bezier.gen(t) - polynomial.gen(-1.0 + 4.0 * t)).abs() < 1e-9);
+        }
+        let back = bezier.to_polynomial();
+        for i in 0..=10 {
+            let t = i as f64 / 10.0;
+            assert!((back.gen(t) - bezier.gen(t)).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn new() {
+        assert!(Polynomial::new(Vec::<f64>::new(), Chebyshev::<f64>::normalized(0)).is_err());
+        assert!(Polynomial::new([1.0, 2.0], Chebyshev::<f64>::normalized(3)).is_err());
+        assert!(Polynomial::new([1.0, 2.0], crate::Sorted::new([1.0, 1.0]).unwrap()).is_err());
+        assert!(Polynomial::new([1.0, 2.0], Chebyshev::<f64>::normalized(2)).is_ok());
+    }
+}
@@ -0,0 +1,200 @@
+//! Polynomial interpolation in Newton form.
+
+use super::error::{Empty, KnotElementInequality, PolynomialError, RepeatedKnot};
+use crate::{Curve, DiscreteGenerator, Generator};
+use core::ops::{Add, Mul, Sub};
+use num_traits::real::Real;
+
+#[cfg(feature = "bezier")]
+use crate::{bezier::Bezier, DynSpace};
+
+/// Polynomial interpolation in Newton form.
+///
+/// The coefficients of the Newton form are the divided differences of the elements.
+/// In contrast to the barycentric form of [`Polynomial`], knots do not have to be sorted
+/// and new elements can be added with [`push()`] without recalculating the other coefficients.
+///
+/// # Examples
+///
+/// ```rust
+/// # use enterpolation::{polynomial::{Newton, PolynomialError}, Generator, Curve};
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// #
+/// # fn main() -> Result<(), PolynomialError> {
+/// let mut newton = Newton::new([1.0,2.0], [0.0,1.0])?;
+/// // the line x + 1
+/// assert_f64_near!(newton.gen(3.0), 4.0);
+/// newton.push(-1.0, 2.0)?;
+/// // the parabola x^2 + 1
+/// assert_f64_near!(newton.gen(3.0), 10.0);
+/// assert_eq!(newton.domain(), [-1.0,1.0]);
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`Polynomial`]: super::Polynomial
+/// [`push()`]: Newton::push()
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Newton<R, T> {
+    knots: Vec<R>,
+    coefficients: Vec<T>,
+    /// The divided differences ending with the last knot, from lowest to highest order.
+    differences: Vec<T>,
+}
+
+impl<R, T> Newton<R, T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<R, Output = T> + Copy,
+    R: Real,
+{
+    /// Create a polynomial interpolation in Newton form with the given elements and knots.
+    ///
+    /// Knots have to be distinct but do not have to be sorted. There should be as many knots as elements
+    /// and there has to be at least one element.
+    pub fn new<E, K>(elements: E, knots: K) -> Result<Self, PolynomialError>
+    where
+        E: DiscreteGenerator<Output = T>,
+        K: DiscreteGenerator<Output = R>,
+    {
+        if elements.is_empty() {
+            return Err(Empty::new().into());
+        }
+        if knots.len() != elements.len() {
+            return Err(KnotElementInequality::new(elements.len(), knots.len()).into());
+        }
+        let mut newton = Newton {
+            knots: Vec::with_capacity(knots.len()),
+            coefficients: Vec::with_capacity(knots.len()),
+            differences: Vec::with_capacity(knots.len()),
+        };
+        for (knot, element) in knots.iter().zip(elements.iter()) {
+            newton.push(knot, element)?;
+        }
+        Ok(newton)
+    }
+
+    /// Add an element at the given knot to the interpolation, increasing its degree by one.
+    ///
+    /// # Errors
+    ///
+    /// [`RepeatedKnot`] if the knot is already used by the interpolation.
+    pub fn push(&mut self, knot: R, element: T) -> Result<(), RepeatedKnot> {
+        let len = self.knots.len();
+        if let Some(index) = self.knots.iter().position(|other| other == knot) {
+            return Err(RepeatedKnot::new(index, len));
+        }
+        let mut difference = element;
+        for k in 0..len {
+            let next =
+                (difference - self.differences[k]) * (knot - self.knots[len - 1 - k]).recip();
+            self.differences[k] = difference;
+            difference = next;
+        }
+        self.differences.push(difference);
+        self.coefficients.push(difference);
+        self.knots.push(knot);
+        Ok(())
+    }
+
+    /// Returns the coefficients of the Newton form, which are the divided differences of the elements.
+    pub fn coefficients(&self) -> &[T] {
+        &self.coefficients
+    }
+
+    /// Returns the knots in the order they were added.
+    pub fn knots(&self) -> &[R] {
+        &self.knots
+    }
+
+    /// Returns the same polynomial as bezier curve.
+    ///
+    /// As bezier curves always have the domain [0.0,1.0], the domain of the polynomial gets
+    /// mapped onto it. The bezier curve has as many elements as the polynomial.
+    #[cfg(feature = "bezier")]
+    pub fn to_bezier(&self) -> Bezier<R, Vec<T>, DynSpace<T>> {
+        let [start, end] = self.domain();
+        let length = end - start;
+        let scale = if length.is_zero() {
+            R::one()
+        } else {
+            length.recip()
+        };
+        let last = self.coefficients.len() - 1;
+        let mut elements = vec![self.coefficients[last]];
+        // Horner's method with bernstein polynomials: multiply by (t - knot) and add the next coefficient
+        for k in (0..last).rev() {
+            let knot = (self.knots[k] - start) * scale;
+            let degree = R::from(elements.len()).unwrap();
+            let mut next = Vec::with_capacity(elements.len() + 1);
+            for i in 0..=elements.len() {
+                let ratio = R::from(i).unwrap() / degree;
+                let value = if i == 0 {
+                    elements[0] * -knot
+                } else if i == elements.len() {
+                    elements[i - 1] * (R::one() - knot)
+                } else {
+                    elements[i - 1] * ((R::one() - knot) * ratio)
+                        + elements[i] * (-knot * (R::one() - ratio))
+                };
+                next.push(value * length + self.coefficients[k]);
+            }
+            elements = next;
+        }
+        let len = elements.len();
+        Bezier::new_unchecked(elements, DynSpace::new(len))
+    }
+}
+
+impl<R, T> Generator<R> for Newton<R, T>
+where
+    T: Add<Output = T> + Mul<R, Output = T> + Copy,
+    R: Real,
+{
+    type Output = T;
+    fn gen(&self, scalar: R) -> T {
+        let last = self.coefficients.len() - 1;
+        let mut result = self.coefficients[last];
+        for k in (0..last).rev() {
+            result = result * (scalar - self.knots[k]) + self.coefficients[k];
+        }
+        result
+    }
+}
+
+impl<R, T> Curve<R> for Newton<R, T>
+where
+    T: Add<Output = T> + Mul<R, Output = T> + Copy,
+    R: Real,
+{
+    /// Return the smallest and biggest knot as domain.
+    fn domain(&self) -> [R; 2] {
+        let first = self.knots[0];
+        self.knots.iter().fold([first, first], |[min, max], knot| {
+            [min.min(knot), max.max(knot)]
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn push() {
+        let mut newton = Newton::new([1.0], [2.0]).unwrap();
+        assert_f64_near!(newton.gen(5.0), 1.0);
+        newton.push(0.0, 3.0).unwrap();
+        newton.push(3.0, 4.0).unwrap();
+        assert!(newton.push(0.0, 1.0).is_err());
+        let expected = Newton::new([1.0, 3.0, 4.0], [2.0, 0.0, 3.0]).unwrap();
+        assert_eq!(newton.coefficients(), expected.coefficients());
+        assert_eq!(newton.knots(), &[2.0, 0.0, 3.0]);
+        for (knot, element) in [(2.0, 1.0), (0.0, 3.0), (3.0, 4.0)] {
+            assert_f64_near!(newton.gen(knot), element);
+        }
+        assert!(Newton::<f64, f64>::new([1.0, 2.0], [1.0]).is_err());
+        assert!(Newton::new([1.0, 2.0], [1.0, 1.0]).is_err());
+    }
+}
@@ -0,0 +1,258 @@
+//! Approximation of curves with Chebyshev series.
+
+use crate::{Curve, Generator};
+use core::ops::{Add, Mul, Sub};
+use num_traits::real::Real;
+use num_traits::FromPrimitive;
+
+/// Degree with which the approximation up to a tolerance starts.
+const MIN_DEGREE: usize = 8;
+/// Maximal degree used to approximate a curve up to a tolerance.
+const MAX_DEGREE: usize = 1024;
+
+/// Approximation of a curve by a Chebyshev series.
+///
+/// The series is the polynomial interpolating the curve at the Chebyshev points of its domain,
+/// represented in the basis of Chebyshev polynomials. It is evaluated with the Clenshaw recurrence,
+/// which only needs linear time in the degree and is numerically stable. Such an approximation
+/// is useful to replace curves which are expensive to evaluate, like long chains of adaptors.
+///
+/// Together with the series an estimate of its maximal error is calculated, which is the maximal
+/// distance of the series to the curve at the points in between the Chebyshev points.
+///
+/// This struct is created by [`Curve::approximate_chebyshev()`] and similar methods.
+/// See their documentation for more information.
+///
+/// [`Curve::approximate_chebyshev()`]: crate::Curve::approximate_chebyshev()
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ChebyshevSeries<R, T> {
+    coefficients: Vec<T>,
+    domain: [R; 2],
+    error: R,
+}
+
+impl<R, T> ChebyshevSeries<R, T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<R, Output = T> + Copy,
+    R: Real + FromPrimitive,
+{
+    /// Approximate the given curve by a Chebyshev series of the given degree.
+    ///
+    /// The error of the approximation is measured with the given metric.
+    pub fn new<C, F>(curve: &C, degree: usize, metric: F) -> Self
+    where
+        C: Curve<R, Output = T> + ?Sized,
+        F: Fn(T, T) -> R,
+    {
+        let domain = curve.domain();
+        let mut series = ChebyshevSeries {
+            coefficients: coefficients(curve, degree),
+            domain,
+            error: R::zero(),
+        };
+        series.error = series.estimate_error(curve, metric);
+        series
+    }
+
+    /// Approximate the given curve by a Chebyshev series with an estimated error of at most the given tolerance.
+    ///
+    /// The degree of the series gets doubled until the estimated error is small enough or
+    /// a degree of 1024 is reached, in which case the last series is returned regardless of its error.
+    pub fn with_tolerance<C, F>(curve: &C, tolerance: R, metric: F) -> Self
+    where
+        C: Curve<R, Output = T> + ?Sized,
+        F: Fn(T, T) -> R,
+    {
+        let mut degree = MIN_DEGREE;
+        loop {
+            let series = Self::new(curve, degree, &metric);
+            if series.error <= tolerance || degree >= MAX_DEGREE {
+                return series;
+            }
+            degree *= 2;
+        }
+    }
+
+    /// Returns the estimated maximal error of the approximation.
+    pub fn error(&self) -> R {
+        self.error
+    }
+
+    /// Returns the degree of the series.
+    pub fn degree(&self) -> usize {
+        self.coefficients.len() - 1
+    }
+
+    /// Returns the coefficients of the Chebyshev polynomials, beginning with the constant one.
+    ///
+    /// The Chebyshev polynomials are defined on [-1.0,1.0], which gets mapped onto the domain of the series.
+    pub fn coefficients(&self) -> &[T] {
+        &self.coefficients
+    }
+
+    /// Maps the given scalar from the domain of the series to [-1.0,1.0].
+    fn normalize(&self, scalar: R) -> R {
+        let [start, end] = self.domain;
+        let length = end - start;
+        if length.is_zero() {
+            return R::zero();
+        }
+        (scalar + scalar - start - end) / length
+    }
+
+    /// Estimates the maximal error by comparing the series with the curve at the points
+    /// in between the points the series interpolates.
+    fn estimate_error<C, F>(&self, curve: &C, metric: F) -> R
+    where
+        C: Curve<R, Output = T> + ?Sized,
+        F: Fn(T, T) -> R,
+    {
+        let [start, end] = self.domain;
+        let degree = self.degree();
+        if degree == 0 {
+            return metric(self.gen(start), curve.gen(start))
+                .max(metric(self.gen(end), curve.gen(end)));
+        }
+        let two = R::one() + R::one();
+        let middle = (start + end) / two;
+        let half = (end - start) / two;
+        let pi = R::from_f64(core::f64::consts::PI).unwrap();
+        let degree = R::from_usize(degree).unwrap();
+        (0..self.degree())
+            .map(|j| {
+                let angle = (R::from_usize(j).unwrap() + two.recip()) * pi / degree;
+                let scalar = middle - angle.cos() * half;
+                metric(self.gen(scalar), curve.gen(scalar))
+            })
+            .fold(R::zero(), |max, error| max.max(error))
+    }
+}
+
+impl<R, T> Generator<R> for ChebyshevSeries<R, T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<R, Output = T> + Copy,
+    R: Real + FromPrimitive,
+{
+    type Output = T;
+    fn gen(&self, scalar: R) -> T {
+        let x = self.normalize(scalar);
+        let double = x + x;
+        let zero = self.coefficients[0] * R::zero();
+        // Clenshaw recurrence
+        let mut next = zero;
+        let mut after_next = zero;
+        for &coefficient in self.coefficients[1..].iter().rev() {
+            let current = coefficient + next * double - after_next;
+            after_next = next;
+            next = current;
+        }
+        self.coefficients[0] + next * x - after_next
+    }
+}
+
+impl<R, T> Curve<R> for ChebyshevSeries<R, T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<R, Output = T> + Copy,
+    R: Real + FromPrimitive,
+{
+    fn domain(&self) -> [R; 2] {
+        self.domain
+    }
+}
+
+/// Calculates the coefficients of the Chebyshev series interpolating the curve at the Chebyshev points.
+fn coefficients<C, R, T>(curve: &C, degree: usize) -> Vec<T>
+where
+    C: Curve<R, Output = T> + ?Sized,
+    T: Add<Output = T> + Mul<R, Output = T> + Copy,
+    R: Real + FromPrimitive,
+{
+    let [start, end] = curve.domain();
+    let two = R::one() + R::one();
+    let middle = (start + end) / two;
+    if degree == 0 {
+        return vec![curve.gen(middle)];
+    }
+    let half = (end - start) / two;
+    let pi = R::from_f64(core::f64::consts::PI).unwrap();
+    let len = R::from_usize(degree).unwrap();
+    // the samples at the points cos(j*pi/degree)
+    let samples: Vec<T> = (0..=degree)
+        .map(|j| {
+            let angle = R::from_usize(j).unwrap() * pi / len;
+            curve.gen(middle + angle.cos() * half)
+        })
+        .collect();
+    (0..=degree)
+        .map(|k| {
+            let mut sum = samples[0] * two.recip();
+            for (j, &sample) in samples.iter().enumerate().skip(1) {
+                // reduce the product modulo 2 * degree to keep the angle small
+                let index = (k * j) % (2 * degree);
+                let cosine = (R::from_usize(index).unwrap() * pi / len).cos();
+                let weight = if j == degree { cosine / two } else { cosine };
+                sum = sum + sample * weight;
+            }
+            let coefficient = sum * (two / len);
+            if k == 0 || k == degree {
+                coefficient * two.recip()
+            } else {
+                coefficient
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::ChebyshevSeries;
+    use crate::easing::FuncEase;
+    use crate::{Curve, Generator};
+
+    fn distance(a: f64, b: f64) -> f64 {
+        (a - b).abs()
+    }
+
+    #[test]
+    fn polynomial() {
+        let curve = FuncEase::new(|x: f64| 2.0 * x * x * x - x + 1.0);
+        let series = ChebyshevSeries::new(&curve, 3, distance);
+        assert_eq!(series.degree(), 3);
+        assert!(series.error() < 1e-12);
+        for i in 0..=20 {
+            let x = i as f64 / 20.0;
+            assert!((series.gen(x) - curve.gen(x)).abs() < 1e-12);
+        }
+        let constant = ChebyshevSeries::new(&curve, 0, distance);
+        assert_f64_near!(constant.gen(0.0), curve.gen(0.5));
+        assert_f64_near!(constant.error(), 1.25);
+    }
+
+    #[test]
+    fn smooth() {
+        let curve = FuncEase::new(|x: f64| (6.0 * x).sin() * (x * x).exp());
+        let series = ChebyshevSeries::with_tolerance(&curve, 1e-10, distance);
+        assert!(series.error() <= 1e-10);
+        assert!(series.degree() <= 64);
+        for i in 0..=100 {
+            let x = i as f64 / 100.0;
+            assert!((series.gen(x) - curve.gen(x)).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn error_estimate() {
+        // a kink is approximated badly, which should be reported
+        let curve = FuncEase::new(|x: f64| (x - 0.3).abs());
+        let series = ChebyshevSeries::new(&curve, 16, distance);
+        let actual = (0..=1000)
+            .map(|i| i as f64 / 1000.0)
+            .map(|x| (series.gen(x) - curve.gen(x)).abs())
+            .fold(0.0, f64::max);
+        assert!(series.error() > 1e-3);
+        assert!(series.error() <= actual + 1e-12);
+        assert!(series.error() > actual / 4.0);
+        assert_eq!(series.domain(), [0.0, 1.0]);
+    }
+}
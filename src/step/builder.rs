@@ -0,0 +1,527 @@
+//! Builder module for step interpolations.
+
+use super::error::StepError;
+use super::{KnotElementInequality, Step, TooFewElements};
+use crate::builder::{Type, Unknown};
+use crate::{DiscreteGenerator, Equidistant, Sorted, SortedGenerator};
+use num_traits::real::Real;
+use num_traits::{FromPrimitive, One, Zero};
+
+/// Builder for step interpolation.
+///
+/// This struct helps create step interpolations. The difference between this struct and [`StepBuilder`]
+/// is that this struct may have other fallible methods and not only the [`build()`] method.
+///
+/// Before building, one has to give information for:
+/// - The elements the interpolation should use with [`elements()`].
+/// - The knots the interpolation uses. This can be seen as the spacing between those elements.
+///   Either by giving them directly with [`knots()`] or by using equidistant knots with [`equidistant()`].
+/// - Which element is held in between two knots. Either with [`previous()`], [`next()`] and [`nearest()`]
+///   or by giving the threshold directly with [`threshold()`].
+///
+/// ```rust
+/// # use enterpolation::{step::{StepDirector, StepError}, Generator, Curve};
+/// #
+/// # fn main() -> Result<(), StepError> {
+/// let step = StepDirector::new()
+///                 .elements([1.0,5.0,3.0])?
+///                 .equidistant::<f64>()
+///                 .normalized()
+///                 .previous()
+///                 .build();
+/// let results = [1.0,1.0,5.0,5.0,3.0];
+/// for (value,result) in step.take(5).zip(results.iter().copied()){
+///     assert_eq!(value, result);
+/// }
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`StepBuilder`]: StepBuilder
+/// [`build()`]: StepDirector::build()
+/// [`elements()`]: StepDirector::elements()
+/// [`knots()`]: StepDirector::knots()
+/// [`equidistant()`]: StepDirector::equidistant()
+/// [`previous()`]: StepDirector::previous()
+/// [`next()`]: StepDirector::next()
+/// [`nearest()`]: StepDirector::nearest()
+/// [`threshold()`]: StepDirector::threshold()
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct StepDirector<K, E, T> {
+    knots: K,
+    elements: E,
+    threshold: T,
+}
+
+/// Builder for step interpolation.
+///
+/// This struct helps create step interpolations. Its only fallible method is [`build()`].
+/// Usually one creates an instance by using the [`builder()`] method on the interpolation itself.
+///
+/// Before building, one has to give information for:
+/// - The elements the interpolation should use with [`elements()`].
+/// - The knots the interpolation uses. This can be seen as the spacing between those elements.
+///   Either by giving them directly with [`knots()`] or by using equidistant knots with [`equidistant()`].
+/// - Which element is held in between two knots. Either with [`previous()`], [`next()`] and [`nearest()`]
+///   or by giving the threshold directly with [`threshold()`].
+///
+/// ```rust
+/// # use enterpolation::{step::{Step, StepError}, Generator, Curve};
+/// #
+/// # fn main() -> Result<(), StepError> {
+/// let step = Step::builder()
+///                 .elements([1.0,5.0,3.0])
+///                 .equidistant::<f64>()
+///                 .normalized()
+///                 .previous()
+///                 .build()?;
+/// let results = [1.0,1.0,5.0,5.0,3.0];
+/// for (value,result) in step.take(5).zip(results.iter().copied()){
+///     assert_eq!(value, result);
+/// }
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`build()`]: StepBuilder::build()
+/// [`builder()`]: super::Step::builder()
+/// [`elements()`]: StepBuilder::elements()
+/// [`knots()`]: StepBuilder::knots()
+/// [`equidistant()`]: StepBuilder::equidistant()
+/// [`previous()`]: StepBuilder::previous()
+/// [`next()`]: StepBuilder::next()
+/// [`nearest()`]: StepBuilder::nearest()
+/// [`threshold()`]: StepBuilder::threshold()
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct StepBuilder<K, E, T> {
+    inner: Result<StepDirector<K, E, T>, StepError>,
+}
+
+impl Default for StepDirector<Unknown, Unknown, Unknown> {
+    fn default() -> Self {
+        StepDirector::new()
+    }
+}
+
+impl Default for StepBuilder<Unknown, Unknown, Unknown> {
+    fn default() -> Self {
+        StepBuilder::new()
+    }
+}
+
+impl StepDirector<Unknown, Unknown, Unknown> {
+    /// Create a new step interpolation builder.
+    pub const fn new() -> Self {
+        StepDirector {
+            knots: Unknown,
+            elements: Unknown,
+            threshold: Unknown,
+        }
+    }
+}
+
+impl StepBuilder<Unknown, Unknown, Unknown> {
+    /// Create a new step interpolation builder.
+    pub const fn new() -> Self {
+        StepBuilder {
+            inner: Ok(StepDirector::new()),
+        }
+    }
+}
+
+impl StepDirector<Unknown, Unknown, Unknown> {
+    /// Set the elements of the step interpolation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TooFewElements`] if not at least 2 elements are given.
+    ///
+    /// [`TooFewElements`]: super::error::StepError
+    pub fn elements<E>(
+        self,
+        elements: E,
+    ) -> Result<StepDirector<Unknown, E, Unknown>, TooFewElements>
+    where
+        E: DiscreteGenerator,
+    {
+        if elements.len() < 2 {
+            return Err(TooFewElements::new(elements.len()));
+        }
+        Ok(StepDirector {
+            knots: self.knots,
+            elements,
+            threshold: self.threshold,
+        })
+    }
+}
+
+impl StepBuilder<Unknown, Unknown, Unknown> {
+    /// Set the elements of the step interpolation.
+    pub fn elements<E>(self, elements: E) -> StepBuilder<Unknown, E, Unknown>
+    where
+        E: DiscreteGenerator,
+    {
+        StepBuilder {
+            inner: self
+                .inner
+                .and_then(|director| director.elements(elements).map_err(|err| err.into())),
+        }
+    }
+}
+
+impl<E> StepDirector<Unknown, E, Unknown> {
+    /// Set the knots of the interpolation.
+    ///
+    /// The amount of knots must be equal to the amount of elements.
+    ///
+    /// # Performance
+    ///
+    /// If you have equidistant knots, near equidistant knots are you do not really care about
+    /// knots, consider using [`equidistant()`] instead.
+    ///
+    /// [`equidistant()`]: StepDirector::equidistant()
+    ///
+    /// # Errors
+    ///
+    /// Returns [`KnotElementInequality`] if the number of knots is not equal to the number of elements.
+    /// Returns [`NotSorted`] if the knots are not sorted such that they are increasing.
+    ///
+    /// [`KnotElementInequality`]: super::error::StepError
+    /// [`NotSorted`]:  super::error::StepError
+    pub fn knots<K>(self, knots: K) -> Result<StepDirector<Sorted<K>, E, Unknown>, StepError>
+    where
+        E: DiscreteGenerator,
+        K: DiscreteGenerator,
+        K::Output: PartialOrd,
+    {
+        if self.elements.len() != knots.len() {
+            return Err(KnotElementInequality::new(self.elements.len(), knots.len()).into());
+        }
+        Ok(StepDirector {
+            knots: Sorted::new(knots)?,
+            elements: self.elements,
+            threshold: self.threshold,
+        })
+    }
+
+    /// Build an interpolation with equidistant knots.
+    ///
+    /// This method takes `R` as a generic parameter. `R` has to be the type you want the knots to be.
+    /// Often this is just `f32` or `f64`.
+    ///
+    /// After this call, you also have to call either of
+    /// - [`domain()`],
+    /// - [`normalized()`] or
+    /// - [`distance()`],
+    ///
+    /// which all define the domain of the interpolation and the spacing of the knots.
+    ///
+    /// # Performance
+    ///
+    /// This may drastically increase performance, as one does not have to use binary search to find
+    /// the relevant knots in an interpolation.
+    ///
+    /// [`domain()`]: StepDirector::domain()
+    /// [`normalized()`]: StepDirector::normalized()
+    /// [`distance()`]: StepDirector::distance()
+    pub fn equidistant<R>(self) -> StepDirector<Type<R>, E, Unknown> {
+        StepDirector {
+            knots: Type::new(),
+            elements: self.elements,
+            threshold: self.threshold,
+        }
+    }
+}
+
+impl<E> StepBuilder<Unknown, E, Unknown> {
+    /// Set the knots of the interpolation.
+    ///
+    /// The amount of knots must be equal to the amount of elements.
+    ///
+    /// # Performance
+    ///
+    /// If you have equidistant knots, near equidistant knots are you do not really care about
+    /// knots, consider using [`equidistant()`] instead.
+    ///
+    /// [`equidistant()`]: StepBuilder::equidistant()
+    pub fn knots<K>(self, knots: K) -> StepBuilder<Sorted<K>, E, Unknown>
+    where
+        E: DiscreteGenerator,
+        K: DiscreteGenerator,
+        K::Output: PartialOrd,
+    {
+        StepBuilder {
+            inner: self.inner.and_then(|director| director.knots(knots)),
+        }
+    }
+
+    /// Build an interpolation with equidistant knots.
+    ///
+    /// This method takes `R` as a generic parameter. `R` has to be the type you want the knots to be.
+    /// Often this is just `f32` or `f64`.
+    ///
+    /// After this call, you also have to call either of
+    /// - [`domain()`],
+    /// - [`normalized()`] or
+    /// - [`distance()`],
+    ///
+    /// which all define the domain of the interpolation and the spacing of the knots.
+    ///
+    /// # Performance
+    ///
+    /// This may drastically increase performance, as one does not have to use binary search to find
+    /// the relevant knots in an interpolation.
+    ///
+    /// [`domain()`]: StepBuilder::domain()
+    /// [`normalized()`]: StepBuilder::normalized()
+    /// [`distance()`]: StepBuilder::distance()
+    pub fn equidistant<R>(self) -> StepBuilder<Type<R>, E, Unknown> {
+        StepBuilder {
+            inner: self.inner.map(|director| director.equidistant()),
+        }
+    }
+}
+
+impl<R, E> StepDirector<Type<R>, E, Unknown>
+where
+    E: DiscreteGenerator,
+    R: Real + FromPrimitive,
+{
+    /// Set the domain of the interpolation.
+    pub fn domain(self, start: R, end: R) -> StepDirector<Equidistant<R>, E, Unknown> {
+        StepDirector {
+            knots: Equidistant::new(self.elements.len(), start, end),
+            elements: self.elements,
+            threshold: self.threshold,
+        }
+    }
+
+    /// Set the domain of the interpolation to be [0.0,1.0].
+    pub fn normalized(self) -> StepDirector<Equidistant<R>, E, Unknown> {
+        StepDirector {
+            knots: Equidistant::normalized(self.elements.len()),
+            elements: self.elements,
+            threshold: self.threshold,
+        }
+    }
+    /// Set the domain of the interpolation by defining the distance between the knots
+    pub fn distance(self, start: R, step: R) -> StepDirector<Equidistant<R>, E, Unknown> {
+        StepDirector {
+            knots: Equidistant::step(self.elements.len(), start, step),
+            elements: self.elements,
+            threshold: self.threshold,
+        }
+    }
+}
+
+impl<R, E> StepBuilder<Type<R>, E, Unknown>
+where
+    E: DiscreteGenerator,
+    R: Real + FromPrimitive,
+{
+    /// Set the domain of the interpolation.
+    pub fn domain(self, start: R, end: R) -> StepBuilder<Equidistant<R>, E, Unknown> {
+        StepBuilder {
+            inner: self.inner.map(|director| director.domain(start, end)),
+        }
+    }
+
+    /// Set the domain of the interpolation to be [0.0,1.0].
+    pub fn normalized(self) -> StepBuilder<Equidistant<R>, E, Unknown> {
+        StepBuilder {
+            inner: self.inner.map(|director| director.normalized()),
+        }
+    }
+    /// Set the domain of the interpolation by defining the distance between the knots
+    pub fn distance(self, start: R, step: R) -> StepBuilder<Equidistant<R>, E, Unknown> {
+        StepBuilder {
+            inner: self.inner.map(|director| director.distance(start, step)),
+        }
+    }
+}
+
+impl<K, E> StepDirector<K, E, Unknown>
+where
+    K: SortedGenerator,
+    K::Output: Real,
+    E: DiscreteGenerator,
+{
+    /// Hold the previous element until the next knot is reached.
+    ///
+    /// This is the same as a threshold of 1.0.
+    pub fn previous(self) -> StepDirector<K, E, K::Output> {
+        self.threshold(K::Output::one())
+    }
+
+    /// Hold the next element directly after a knot is passed.
+    ///
+    /// This is the same as a threshold of 0.0.
+    pub fn next(self) -> StepDirector<K, E, K::Output> {
+        self.threshold(K::Output::zero())
+    }
+
+    /// Hold the element of the nearest knot.
+    ///
+    /// This is the same as a threshold of 0.5.
+    pub fn nearest(self) -> StepDirector<K, E, K::Output> {
+        let half = K::Output::one() / (K::Output::one() + K::Output::one());
+        self.threshold(half)
+    }
+
+    /// Set the threshold at which the next element is held instead of the previous one.
+    ///
+    /// The threshold is the relative position in between two knots and should be in [0.0,1.0].
+    /// If the position is above the threshold, the next element is held.
+    pub fn threshold(self, threshold: K::Output) -> StepDirector<K, E, K::Output> {
+        StepDirector {
+            knots: self.knots,
+            elements: self.elements,
+            threshold,
+        }
+    }
+}
+
+impl<K, E> StepBuilder<K, E, Unknown>
+where
+    K: SortedGenerator,
+    K::Output: Real,
+    E: DiscreteGenerator,
+{
+    /// Hold the previous element until the next knot is reached.
+    ///
+    /// This is the same as a threshold of 1.0.
+    pub fn previous(self) -> StepBuilder<K, E, K::Output> {
+        StepBuilder {
+            inner: self.inner.map(|director| director.previous()),
+        }
+    }
+
+    /// Hold the next element directly after a knot is passed.
+    ///
+    /// This is the same as a threshold of 0.0.
+    pub fn next(self) -> StepBuilder<K, E, K::Output> {
+        StepBuilder {
+            inner: self.inner.map(|director| director.next()),
+        }
+    }
+
+    /// Hold the element of the nearest knot.
+    ///
+    /// This is the same as a threshold of 0.5.
+    pub fn nearest(self) -> StepBuilder<K, E, K::Output> {
+        StepBuilder {
+            inner: self.inner.map(|director| director.nearest()),
+        }
+    }
+
+    /// Set the threshold at which the next element is held instead of the previous one.
+    ///
+    /// The threshold is the relative position in between two knots and should be in [0.0,1.0].
+    /// If the position is above the threshold, the next element is held.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{step::{Step, StepError}, Generator, Curve};
+    /// #
+    /// # fn main() -> Result<(), StepError> {
+    /// let step = Step::builder()
+    ///                 .elements([1,2,3])
+    ///                 .knots([0.0,1.0,2.0])
+    ///                 .threshold(0.75)
+    ///                 .build()?;
+    /// assert_eq!(step.gen(0.7), 1);
+    /// assert_eq!(step.gen(0.8), 2);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn threshold(self, threshold: K::Output) -> StepBuilder<K, E, K::Output> {
+        StepBuilder {
+            inner: self.inner.map(|director| director.threshold(threshold)),
+        }
+    }
+}
+
+impl<K, E> StepDirector<K, E, K::Output>
+where
+    K: SortedGenerator,
+    E: DiscreteGenerator,
+{
+    /// Build a step interpolation.
+    pub fn build(self) -> Step<K, E, K::Output> {
+        Step::new_unchecked(self.elements, self.knots, self.threshold)
+    }
+}
+
+impl<K, E> StepBuilder<K, E, K::Output>
+where
+    K: SortedGenerator,
+    E: DiscreteGenerator,
+{
+    /// Build a step interpolation.
+    pub fn build(self) -> Result<Step<K, E, K::Output>, StepError> {
+        match self.inner {
+            Err(err) => Err(err),
+            Ok(director) => Ok(director.build()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{StepBuilder, StepDirector};
+
+    #[test]
+    fn builder_errors() {
+        assert!(StepBuilder::new()
+            .elements([1.0])
+            .knots([1.0])
+            .previous()
+            .build()
+            .is_err());
+        assert!(StepBuilder::new()
+            .elements([1.0, 2.0])
+            .knots([1.0, 2.0, 3.0])
+            .previous()
+            .build()
+            .is_err());
+        assert!(StepBuilder::new()
+            .elements([1.0, 2.0])
+            .knots([2.0, 1.0])
+            .previous()
+            .build()
+            .is_err());
+        assert!(StepBuilder::new()
+            .elements([1.0, 2.0])
+            .knots([1.0, 2.0])
+            .nearest()
+            .build()
+            .is_ok());
+    }
+
+    #[test]
+    fn director_errors() {
+        assert!(StepDirector::new().elements([0.0]).is_err());
+        assert!(StepDirector::new()
+            .elements([0.0, 1.0])
+            .unwrap()
+            .knots([1.0])
+            .is_err());
+        assert_eq!(
+            StepDirector::new()
+                .elements([0.0, 1.0])
+                .unwrap()
+                .equidistant::<f64>()
+                .normalized()
+                .threshold(0.3)
+                .build()
+                .threshold,
+            0.3
+        );
+    }
+}
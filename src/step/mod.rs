@@ -0,0 +1,260 @@
+//! Piecewise constant interpolations, which hold their elements.
+//!
+//! The easiest way to create a step interpolation is by using the builder pattern of [`StepBuilder`].
+//!
+//! ```rust
+//! # use enterpolation::{step::{Step, StepError}, Generator, Curve};
+//! #
+//! # fn main() -> Result<(), StepError> {
+//! let step = Step::builder()
+//!                 .elements([0.0,5.0,3.0])
+//!                 .knots([0.0,1.0,2.0])
+//!                 .previous()
+//!                 .build()?;
+//! let results = [0.0,0.0,5.0,5.0,3.0];
+//! for (value,result) in step.take(5).zip(results.iter().copied()){
+//!     assert_eq!(value, result);
+//! }
+//! #
+//! #     Ok(())
+//! # }
+//! ```
+//!
+//! Step interpolations do not blend elements together, instead they hold the value of an element
+//! until another element is reached. This is useful for discrete states, quantized controls or
+//! animation samplers with a step interpolation, like the `STEP` sampler of glTF.
+//! As elements are never merged, any copyable type may be used as element.
+//!
+//! Which element is held in between two knots is decided by a threshold in [0.0,1.0].
+//! If the position in between the two knots is above the threshold, the next element is returned,
+//! otherwise the previous one. The builder offers [`previous()`], [`next()`] and [`nearest()`]
+//! for the most common thresholds and [`threshold()`] for all others.
+//!
+//! [`StepBuilder`]: StepBuilder
+//! [`previous()`]: StepBuilder::previous()
+//! [`next()`]: StepBuilder::next()
+//! [`nearest()`]: StepBuilder::nearest()
+//! [`threshold()`]: StepBuilder::threshold()
+
+use crate::builder::Unknown;
+use crate::{Curve, DiscreteGenerator, Generator, SortedGenerator};
+use num_traits::real::Real;
+
+use core::fmt::Debug;
+
+mod builder;
+pub use builder::{StepBuilder, StepDirector};
+
+pub mod error;
+pub use error::{KnotElementInequality, NotSorted, StepError, TooFewElements};
+
+/// Piecewise constant interpolation.
+///
+/// See [step module] for more information.
+///
+/// [step module]: self
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Step<K, E, R> {
+    elements: E,
+    knots: K,
+    threshold: R,
+}
+
+impl Step<Unknown, Unknown, Unknown> {
+    /// Get the builder for a step interpolation.
+    ///
+    /// The builder takes:
+    /// - elements with [`elements()`]
+    /// - knots with either [`knots()`] or [`equidistant()`]
+    /// - which element to hold with either [`previous()`], [`next()`], [`nearest()`] or [`threshold()`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{step::{Step, StepError}, Generator, Curve};
+    /// #
+    /// # fn main() -> Result<(), StepError> {
+    /// let step = Step::builder()
+    ///                 .elements(['a','b','c'])
+    ///                 .equidistant::<f64>()
+    ///                 .normalized()
+    ///                 .nearest()
+    ///                 .build()?;
+    /// let results = ['a','a','b','b','c'];
+    /// for (value,result) in step.take(5).zip(results.iter().copied()){
+    ///     assert_eq!(value, result);
+    /// }
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`elements()`]: StepBuilder::elements()
+    /// [`knots()`]: StepBuilder::knots()
+    /// [`equidistant()`]: StepBuilder::equidistant()
+    /// [`previous()`]: StepBuilder::previous()
+    /// [`next()`]: StepBuilder::next()
+    /// [`nearest()`]: StepBuilder::nearest()
+    /// [`threshold()`]: StepBuilder::threshold()
+    pub fn builder() -> StepBuilder<Unknown, Unknown, Unknown> {
+        StepBuilder::new()
+    }
+}
+
+impl<K, E, R> Step<K, E, R>
+where
+    K: SortedGenerator,
+    E: DiscreteGenerator,
+{
+    /// Create a step interpolation with slice-like collections of elements and knots.
+    ///
+    /// Knots have to be sorted, there should be as many knots as elements
+    /// and there has to be at least 2 elements.
+    /// The threshold decides at which position in between two knots the next element is held.
+    pub fn new(elements: E, knots: K, threshold: R) -> Result<Self, StepError> {
+        if elements.len() < 2 {
+            return Err(TooFewElements::new(elements.len()).into());
+        }
+        if knots.len() != elements.len() {
+            return Err(KnotElementInequality::new(elements.len(), knots.len()).into());
+        }
+        Ok(Step {
+            elements,
+            knots,
+            threshold,
+        })
+    }
+
+    /// Create a step interpolation with slice-like collections of elements and knots.
+    ///
+    /// # Panics
+    ///
+    /// Knots should be in increasing order, there should be as many knots as elements
+    /// and there has to be at least *two* elements.
+    /// If any of these requirements are not uphold, the library may panic at any time.
+    pub fn new_unchecked(elements: E, knots: K, threshold: R) -> Self {
+        Step {
+            elements,
+            knots,
+            threshold,
+        }
+    }
+}
+
+impl<R, K, E> Generator<R> for Step<K, E, R>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    R: Real + Debug,
+{
+    type Output = E::Output;
+    /// # Panics
+    ///
+    /// Panics if `scalar` is NaN or similar.
+    fn gen(&self, scalar: R) -> Self::Output {
+        let (min_index, max_index, factor) = self.knots.upper_border(scalar);
+        // the last element is held from the last knot onwards, regardless of the threshold
+        if factor > self.threshold || factor >= R::one() {
+            self.elements.gen(max_index)
+        } else {
+            self.elements.gen(min_index)
+        }
+    }
+}
+
+impl<R, K, E> Curve<R> for Step<K, E, R>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    R: Real + Debug,
+{
+    fn domain(&self) -> [R; 2] {
+        [self.knots.first().unwrap(), self.knots.last().unwrap()]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Sorted;
+
+    #[test]
+    fn previous() {
+        let step = Step::builder()
+            .elements([1, 2, 3])
+            .knots([0.0, 1.0, 3.0])
+            .previous()
+            .build()
+            .unwrap();
+        let expected = [
+            (-1.0, 1),
+            (0.0, 1),
+            (0.99, 1),
+            (1.0, 2),
+            (2.9, 2),
+            (3.0, 3),
+            (4.0, 3),
+        ];
+        for (input, output) in expected {
+            assert_eq!(step.gen(input), output);
+        }
+    }
+
+    #[test]
+    fn next() {
+        let step = Step::builder()
+            .elements([1, 2, 3])
+            .knots([0.0, 1.0, 3.0])
+            .next()
+            .build()
+            .unwrap();
+        let expected = [
+            (-1.0, 1),
+            (0.0, 1),
+            (0.01, 2),
+            (1.0, 2),
+            (1.1, 3),
+            (3.0, 3),
+            (4.0, 3),
+        ];
+        for (input, output) in expected {
+            assert_eq!(step.gen(input), output);
+        }
+    }
+
+    #[test]
+    fn threshold() {
+        let nearest = Step::builder()
+            .elements([1, 2, 3])
+            .equidistant::<f64>()
+            .distance(0.0, 2.0)
+            .nearest()
+            .build()
+            .unwrap();
+        assert_eq!(nearest.domain(), [0.0, 4.0]);
+        let expected = [(0.9, 1), (1.1, 2), (2.9, 2), (3.1, 3)];
+        for (input, output) in expected {
+            assert_eq!(nearest.gen(input), output);
+        }
+        let threshold = Step::builder()
+            .elements([1, 2, 3])
+            .equidistant::<f64>()
+            .distance(0.0, 2.0)
+            .threshold(0.25)
+            .build()
+            .unwrap();
+        let expected = [(0.4, 1), (0.6, 2), (2.4, 2), (2.6, 3)];
+        for (input, output) in expected {
+            assert_eq!(threshold.gen(input), output);
+        }
+    }
+
+    #[test]
+    fn new() {
+        let knots = Sorted::new([1.0, 2.0]).unwrap();
+        assert!(Step::new([1.0], Sorted::new([1.0]).unwrap(), 1.0).is_err());
+        assert!(Step::new([1.0, 2.0], Sorted::new([1.0, 2.0, 3.0]).unwrap(), 1.0).is_err());
+        assert!(Step::new([1.0, 2.0], knots, 1.0).is_ok());
+    }
+}
@@ -0,0 +1,133 @@
+//! Points in two and three dimensions shared by the tests of several modules.
+//!
+//! The points are only compiled with the features of the modules whose tests use them.
+
+#[cfg(feature = "std")]
+use crate::Components;
+use core::ops::{Add, Div, Mul, Sub};
+#[cfg(feature = "std")]
+use topology_traits::QuasiMetric;
+
+/// A point in two dimensions.
+#[cfg(feature = "std")]
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub(crate) struct Point(pub(crate) f64, pub(crate) f64);
+
+#[cfg(feature = "std")]
+impl Add for Point {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Point(self.0 + other.0, self.1 + other.1)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Sub for Point {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        Point(self.0 - other.0, self.1 - other.1)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Mul<f64> for Point {
+    type Output = Self;
+    fn mul(self, scalar: f64) -> Self {
+        Point(self.0 * scalar, self.1 * scalar)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Div<f64> for Point {
+    type Output = Self;
+    fn div(self, scalar: f64) -> Self {
+        Point(self.0 / scalar, self.1 / scalar)
+    }
+}
+
+#[cfg(feature = "std")]
+impl QuasiMetric<f64> for Point {
+    fn distance(self, other: Self) -> f64 {
+        (self.0 - other.0).hypot(self.1 - other.1)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Components<f64> for Point {
+    fn dimension(&self) -> usize {
+        2
+    }
+    fn component(&self, index: usize) -> f64 {
+        [self.0, self.1][index]
+    }
+    fn set_component(&mut self, index: usize, value: f64) {
+        match index {
+            0 => self.0 = value,
+            1 => self.1 = value,
+            _ => panic!("a point in two dimensions has no component {}", index),
+        }
+    }
+}
+
+/// Returns the distance between the given points.
+#[cfg(feature = "std")]
+pub(crate) fn distance(a: Point, b: Point) -> f64 {
+    a.distance(b)
+}
+
+/// A point in three dimensions.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub(crate) struct Point3(pub(crate) f64, pub(crate) f64, pub(crate) f64);
+
+impl Point3 {
+    /// Returns the distance to the origin.
+    pub(crate) fn norm(self) -> f64 {
+        (self.0 * self.0 + self.1 * self.1 + self.2 * self.2).sqrt()
+    }
+}
+
+impl Add for Point3 {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Point3(self.0 + other.0, self.1 + other.1, self.2 + other.2)
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        Point3(self.0 - other.0, self.1 - other.1, self.2 - other.2)
+    }
+}
+
+impl Mul<f64> for Point3 {
+    type Output = Self;
+    fn mul(self, scalar: f64) -> Self {
+        Point3(self.0 * scalar, self.1 * scalar, self.2 * scalar)
+    }
+}
+
+impl Div<f64> for Point3 {
+    type Output = Self;
+    fn div(self, scalar: f64) -> Self {
+        Point3(self.0 / scalar, self.1 / scalar, self.2 / scalar)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Components<f64> for Point3 {
+    fn dimension(&self) -> usize {
+        3
+    }
+    fn component(&self, index: usize) -> f64 {
+        [self.0, self.1, self.2][index]
+    }
+    fn set_component(&mut self, index: usize, value: f64) {
+        match index {
+            0 => self.0 = value,
+            1 => self.1 = value,
+            2 => self.2 = value,
+            _ => panic!("a point in three dimensions has no component {}", index),
+        }
+    }
+}
@@ -9,7 +9,7 @@ use num_traits::identities::{One, Zero};
 /// Wrapper for elements to achieve weighted and rational curves.
 ///
 /// This wrapper allows for Homogeneous Coordinates.
-#[derive(Clone, Copy, Hash, Default, Debug)]
+#[derive(Clone, Copy, Hash, Default, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Homogeneous<E, R> {
     element: E,
@@ -62,6 +62,16 @@ where
     }
 }
 
+impl<E, R> Homogeneous<E, R>
+where
+    R: Copy,
+{
+    /// Return the rational, that is the weight, of the coordinate.
+    pub fn rational(&self) -> R {
+        self.rational
+    }
+}
+
 impl<E, R> Homogeneous<E, R>
 where
     E: Mul<R, Output = E>,
@@ -5,12 +5,15 @@ mod homogeneous;
 mod weighted;
 
 pub use homogeneous::Homogeneous;
+#[cfg(feature = "bspline")]
+pub(crate) use weighted::Project;
 pub use weighted::Weighted;
 
 use crate::{ConstDiscreteGenerator, Curve, DiscreteGenerator, Generator};
-use core::ops::Mul;
+use core::ops::{Add, Div, Mul, Sub};
 use num_traits::identities::Zero;
 use num_traits::real::Real;
+use num_traits::FromPrimitive;
 
 /// Generator adaptor to transform `(T,R)` to `Homogeneous<T,R>`.
 ///
@@ -103,3 +106,57 @@ where
         self
     }
 }
+
+/// Transforms the value and derivatives of a homogeneous curve to the value and derivatives of the projected curve.
+///
+/// The given array has to be ordered from value, then first derivative, then second and so on.
+/// The derivatives of the projected curve are calculated by the generalized quotient rule.
+/// If the value lies at infinity, the result contains infinite or NaN values.
+pub fn project_derivatives<T, R, const N: usize>(homogeneous: [Homogeneous<T, R>; N]) -> [T; N]
+where
+    T: Add<Output = T> + Mul<R, Output = T> + Sub<Output = T> + Div<R, Output = T> + Copy,
+    R: Real + FromPrimitive,
+{
+    let weight = homogeneous[0].rational();
+    let mut derivatives = [homogeneous[0].direction() * R::zero(); N];
+    for k in 0..N {
+        let mut value = homogeneous[k].direction();
+        let mut binomial = R::one();
+        for i in 1..=k {
+            // binomial coefficient of k over i
+            binomial = binomial * R::from_usize(k + 1 - i).unwrap() / R::from_usize(i).unwrap();
+            value = value - derivatives[k - i] * (binomial * homogeneous[i].rational());
+        }
+        derivatives[k] = value / weight;
+    }
+    derivatives
+}
+
+/// Returns the homogeneous elements of the quadratic rational bezier curve which traces the elliptic arc
+/// `center + x_axis * cos(angle) + y_axis * sin(angle)` exactly for all angles between start and end.
+///
+/// The angles given should differ by less than π, otherwise the weight of the shoulder is not positive.
+#[cfg(any(feature = "bezier", all(feature = "bspline", feature = "std")))]
+pub(crate) fn elliptic_arc<T, R>(
+    center: T,
+    x_axis: T,
+    y_axis: T,
+    start: R,
+    end: R,
+) -> [Homogeneous<T, R>; 3]
+where
+    T: Add<Output = T> + Mul<R, Output = T> + Copy,
+    R: Real,
+{
+    let half = (end - start) / (R::one() + R::one());
+    let middle = start + half;
+    let weight = half.cos();
+    let point = |angle: R| center + x_axis * angle.cos() + y_axis * angle.sin();
+    // the shoulder is the intersection of the tangents at both ends of the arc
+    let shoulder = center + (x_axis * middle.cos() + y_axis * middle.sin()) * weight.recip();
+    [
+        Homogeneous::new(point(start)),
+        Homogeneous::weighted_unchecked(shoulder, weight),
+        Homogeneous::new(point(end)),
+    ]
+}
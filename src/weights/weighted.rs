@@ -23,6 +23,10 @@ impl<G> Weighted<G> {
     pub fn inner(self) -> G {
         self.inner
     }
+    /// Return a reference to the inner interpolation.
+    pub fn inner_ref(&self) -> &G {
+        &self.inner
+    }
 }
 
 impl<G, I> Generator<I> for Weighted<G>